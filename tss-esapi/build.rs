@@ -45,5 +45,40 @@ fn main() {
         if has_esys_tr_get_tpm_handle_req.matches(&tss_version) {
             println!("cargo:rustc-cfg=has_esys_tr_get_tpm_handle")
         }
+
+        let has_esys_mac_req = VersionReq::parse(">=3.0.0").unwrap();
+        if has_esys_mac_req.matches(&tss_version) {
+            println!("cargo:rustc-cfg=has_esys_mac")
+        }
+
+        let has_esys_certify_x509_req = VersionReq::parse(">=3.0.0").unwrap();
+        if has_esys_certify_x509_req.matches(&tss_version) {
+            println!("cargo:rustc-cfg=has_esys_certify_x509")
+        }
+
+        let has_tpms_nv_digest_certify_info_req = VersionReq::parse(">=3.1.0").unwrap();
+        if has_tpms_nv_digest_certify_info_req.matches(&tss_version) {
+            println!("cargo:rustc-cfg=has_tpms_nv_digest_certify_info")
+        }
+
+        let has_tpm2b_label_req = VersionReq::parse(">=3.1.0").unwrap();
+        if has_tpm2b_label_req.matches(&tss_version) {
+            println!("cargo:rustc-cfg=has_tpm2b_label")
+        }
+
+        let has_esys_act_req = VersionReq::parse(">=3.0.0").unwrap();
+        if has_esys_act_req.matches(&tss_version) {
+            println!("cargo:rustc-cfg=has_esys_act")
+        }
+
+        let has_tpml_tagged_policy_req = VersionReq::parse(">=3.0.0").unwrap();
+        if has_tpml_tagged_policy_req.matches(&tss_version) {
+            println!("cargo:rustc-cfg=has_tpml_tagged_policy")
+        }
+
+        let has_esys_ac_req = VersionReq::parse(">=3.0.0").unwrap();
+        if has_esys_ac_req.matches(&tss_version) {
+            println!("cargo:rustc-cfg=has_esys_ac")
+        }
     }
 }
@@ -0,0 +1,125 @@
+// Copyright 2023 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Module for recomputing command and session audit digests
+//!
+//! The TPM extends the audit digest of a session, or the command audit digest,
+//! with the cpHash and rpHash of each audited command:
+//!
+//! `auditDigest_new := H(auditDigest_old || cpHash || rpHash)`
+//!
+//! where the audit digest starts out as a zero digest. Recomputing the digest
+//! from the recorded cpHash and rpHash values makes it possible to compare it
+//! with the digest contained in a signed
+//! [CommandAuditInfo](crate::structures::CommandAuditInfo) or
+//! [SessionAuditInfo](crate::structures::SessionAuditInfo).
+use std::convert::TryFrom;
+
+use crate::{
+    abstraction::hashing::Hasher,
+    constants::{tss::TPM2_RC_SUCCESS, CommandCode},
+    interface_types::algorithm::HashingAlgorithm,
+    structures::{Digest, Name},
+    tss2_esys::TPM2_CC,
+    Error, Result, WrapperErrorKind,
+};
+
+/// The command and response parameter hashes of an audited command.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuditEntry {
+    cp_hash: Digest,
+    rp_hash: Digest,
+}
+
+impl AuditEntry {
+    /// Creates a new audit entry from a recorded cpHash and rpHash.
+    pub fn new(cp_hash: Digest, rp_hash: Digest) -> Self {
+        AuditEntry { cp_hash, rp_hash }
+    }
+
+    /// Creates a new audit entry by computing the cpHash and rpHash
+    /// of a successfully executed command.
+    ///
+    /// # Arguments
+    /// * `hashing_algorithm` - The hashing algorithm used for the audit.
+    /// * `command_code` - The command code of the audited command.
+    /// * `handle_names` - The names of the handles of the command, in the order
+    ///                    they appear in the command.
+    /// * `command_parameters` - The marshalled command parameters.
+    /// * `response_parameters` - The marshalled response parameters.
+    pub fn compute(
+        hashing_algorithm: HashingAlgorithm,
+        command_code: CommandCode,
+        handle_names: &[Name],
+        command_parameters: &[u8],
+        response_parameters: &[u8],
+    ) -> Result<Self> {
+        let command_code = TPM2_CC::from(command_code).to_be_bytes();
+
+        // cpHash := H(commandCode || names || parameters)
+        let mut cp_hasher = Hasher::new(hashing_algorithm)?;
+        cp_hasher.update(&command_code);
+        handle_names
+            .iter()
+            .for_each(|name| cp_hasher.update(name.value()));
+        cp_hasher.update(command_parameters);
+
+        // rpHash := H(responseCode || commandCode || parameters)
+        let mut rp_hasher = Hasher::new(hashing_algorithm)?;
+        rp_hasher.update(&TPM2_RC_SUCCESS.to_be_bytes());
+        rp_hasher.update(&command_code);
+        rp_hasher.update(response_parameters);
+
+        Ok(AuditEntry {
+            cp_hash: cp_hasher.finish()?,
+            rp_hash: rp_hasher.finish()?,
+        })
+    }
+
+    /// Returns the command parameter hash.
+    pub fn cp_hash(&self) -> &Digest {
+        &self.cp_hash
+    }
+
+    /// Returns the response parameter hash.
+    pub fn rp_hash(&self) -> &Digest {
+        &self.rp_hash
+    }
+}
+
+/// Computes an audit digest in software.
+///
+/// # Arguments
+/// * `hashing_algorithm` - The hashing algorithm used for the audit. For a session
+///                         audit this is the hashing algorithm of the session and for
+///                         command audit it is the algorithm set with
+///                         [Context::set_command_code_audit_status](crate::Context::set_command_code_audit_status).
+/// * `audit_entries` - The audited commands in the order they were executed.
+///
+/// # Returns
+/// The resulting audit digest.
+///
+/// # Errors
+/// * if the size of a cpHash or rpHash does not match the digest size of
+///   the hashing algorithm, an `InconsistentParams` wrapper error will be returned.
+pub fn compute_audit_digest(
+    hashing_algorithm: HashingAlgorithm,
+    audit_entries: &[AuditEntry],
+) -> Result<Digest> {
+    let digest_size = Hasher::new(hashing_algorithm)?.output_size();
+    audit_entries
+        .iter()
+        .try_fold(vec![0u8; digest_size], |audit_digest, audit_entry| {
+            if audit_entry.cp_hash.len() != digest_size || audit_entry.rp_hash.len() != digest_size
+            {
+                log::error!("The size of the audited hashes does not match the audit digest size");
+                return Err(Error::local_error(WrapperErrorKind::InconsistentParams));
+            }
+            let mut hasher = Hasher::new(hashing_algorithm)?;
+            hasher.update(&audit_digest);
+            hasher.update(audit_entry.cp_hash.as_bytes());
+            hasher.update(audit_entry.rp_hash.as_bytes());
+            Ok(hasher.finish()?.to_vec())
+        })
+        .and_then(Digest::try_from)
+}
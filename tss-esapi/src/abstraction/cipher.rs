@@ -1,13 +1,17 @@
 // Copyright 2019 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
 use crate::{
-    constants::AlgorithmIdentifier,
+    constants::{AlgorithmIdentifier, CommandCode},
+    handles::KeyHandle,
     interface_types::{
         algorithm::{HashingAlgorithm, SymmetricAlgorithm, SymmetricMode, SymmetricObject},
         key_bits::{AesKeyBits, CamelliaKeyBits, Sm4KeyBits},
     },
-    structures::{SymmetricCipherParameters, SymmetricDefinition, SymmetricDefinitionObject},
-    Error, Result, WrapperErrorKind,
+    structures::{
+        InitialValue, MaxBuffer, SymmetricCipherParameters, SymmetricDefinition,
+        SymmetricDefinitionObject,
+    },
+    Context, Error, Result, WrapperErrorKind,
 };
 use std::convert::{TryFrom, TryInto};
 /// Block cipher identifiers
@@ -239,3 +243,100 @@ impl TryFrom<Cipher> for SymmetricCipherParameters {
         Ok(SymmetricCipherParameters::new(cipher.try_into()?))
     }
 }
+
+/// The command used for symmetric encryption or decryption.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum EncryptDecryptCommand {
+    /// TPM2_EncryptDecrypt, which cannot be used with a session that
+    /// has the `decrypt` attribute set.
+    EncryptDecrypt,
+    /// TPM2_EncryptDecrypt2, which is not supported by all TPMs.
+    EncryptDecrypt2,
+}
+
+impl EncryptDecryptCommand {
+    /// Selects TPM2_EncryptDecrypt2 if the TPM supports it,
+    /// otherwise TPM2_EncryptDecrypt.
+    ///
+    /// # Details
+    /// The support of the TPM for the command is cached by the `context`.
+    pub fn select(context: &mut Context) -> Result<Self> {
+        if context.is_command_supported(CommandCode::EncryptDecrypt2)? {
+            Ok(EncryptDecryptCommand::EncryptDecrypt2)
+        } else {
+            Ok(EncryptDecryptCommand::EncryptDecrypt)
+        }
+    }
+}
+
+/// Performs symmetric encryption or decryption of the data using
+/// the key associated with the `key_handle`.
+///
+/// # Details
+/// The command is chosen with [EncryptDecryptCommand::select], see
+/// [encrypt_decrypt_with_command] for the restrictions on the sessions.
+///
+/// # Arguments
+/// * `context` - The context used to encrypt or decrypt the data.
+/// * `key_handle` -  A [KeyHandle] to the key to be used.
+/// * `decrypt` - A boolean indicating if the data should be decrypted or encrypted.
+///               If set to true the data will be decrypted else encrypted.
+/// * `mode` - The [SymmetricMode] to be used.
+/// * `in_data` - The data that is going to be decrypted or encrypted.
+/// * `initial_value_in` - An initial value as required by the algorithm.
+pub fn encrypt_decrypt(
+    context: &mut Context,
+    key_handle: KeyHandle,
+    decrypt: bool,
+    mode: SymmetricMode,
+    in_data: MaxBuffer,
+    initial_value_in: InitialValue,
+) -> Result<(MaxBuffer, InitialValue)> {
+    let command = EncryptDecryptCommand::select(context)?;
+    encrypt_decrypt_with_command(
+        context,
+        command,
+        key_handle,
+        decrypt,
+        mode,
+        in_data,
+        initial_value_in,
+    )
+}
+
+/// Performs symmetric encryption or decryption of the data using
+/// the key associated with the `key_handle` and the given `command`.
+///
+/// # Details
+/// An authorization session for `key_handle` needs to be set in the
+/// first session slot of the `context`. If `command` is
+/// [EncryptDecryptCommand::EncryptDecrypt] the session must not have
+/// the `decrypt` attribute set.
+///
+/// # Arguments
+/// * `context` - The context used to encrypt or decrypt the data.
+/// * `command` - The [EncryptDecryptCommand] to be used.
+/// * `key_handle` -  A [KeyHandle] to the key to be used.
+/// * `decrypt` - A boolean indicating if the data should be decrypted or encrypted.
+///               If set to true the data will be decrypted else encrypted.
+/// * `mode` - The [SymmetricMode] to be used.
+/// * `in_data` - The data that is going to be decrypted or encrypted.
+/// * `initial_value_in` - An initial value as required by the algorithm.
+pub fn encrypt_decrypt_with_command(
+    context: &mut Context,
+    command: EncryptDecryptCommand,
+    key_handle: KeyHandle,
+    decrypt: bool,
+    mode: SymmetricMode,
+    in_data: MaxBuffer,
+    initial_value_in: InitialValue,
+) -> Result<(MaxBuffer, InitialValue)> {
+    match command {
+        EncryptDecryptCommand::EncryptDecrypt => {
+            context.encrypt_decrypt(key_handle, decrypt, mode, in_data, initial_value_in)
+        }
+        EncryptDecryptCommand::EncryptDecrypt2 => {
+            context.encrypt_decrypt_2(key_handle, decrypt, mode, in_data, initial_value_in)
+        }
+    }
+}
@@ -0,0 +1,234 @@
+// Copyright 2023 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Key provenance through certified creation data
+//!
+//! When an object is created, the TPM returns the [CreationData] describing
+//! the state of the TPM at creation time (e.g. the digest of the selected PCRs
+//! and the name of the parent) together with a creation hash and a ticket.
+//! The abstractions in this module use [Context::certify_creation] to bind
+//! this information to the created key in a signed attestation, and allow a
+//! verifier to check it against the expected values.
+use std::convert::TryFrom;
+
+use crate::{
+    abstraction::hashing::Hasher,
+    handles::KeyHandle,
+    interface_types::structure_tags::AttestationType,
+    structures::{
+        Attest, AttestInfo, CreateKeyResult, CreatePrimaryKeyResult, CreationData, CreationTicket,
+        Data, Digest, Name, Public, Signature, SignatureScheme,
+    },
+    traits::Marshall,
+    tss2_esys::TPMI_ALG_HASH,
+    Context, Error, Result, WrapperErrorKind,
+};
+
+/// The creation outputs of a newly created key
+///
+/// # Details
+/// This holds the parts of [CreateKeyResult] and [CreatePrimaryKeyResult]
+/// that are needed in order to certify the creation of a key.
+#[derive(Debug)]
+pub struct KeyCreation {
+    pub public: Public,
+    pub creation_data: CreationData,
+    pub creation_hash: Digest,
+    pub creation_ticket: CreationTicket,
+}
+
+impl From<CreateKeyResult> for KeyCreation {
+    fn from(create_key_result: CreateKeyResult) -> Self {
+        KeyCreation {
+            public: create_key_result.out_public,
+            creation_data: create_key_result.creation_data,
+            creation_hash: create_key_result.creation_hash,
+            creation_ticket: create_key_result.creation_ticket,
+        }
+    }
+}
+
+impl From<CreatePrimaryKeyResult> for KeyCreation {
+    fn from(create_primary_key_result: CreatePrimaryKeyResult) -> Self {
+        KeyCreation {
+            public: create_primary_key_result.out_public,
+            creation_data: create_primary_key_result.creation_data,
+            creation_hash: create_primary_key_result.creation_hash,
+            creation_ticket: create_primary_key_result.creation_ticket,
+        }
+    }
+}
+
+/// A key bundled with its creation data certified by the TPM
+///
+/// # Details
+/// The bundle contains everything a verifier needs in order to check that the key
+/// was created under a specific PCR state and parent: the public part of the key,
+/// its creation data and the attestation (with its signature) produced by
+/// [Context::certify_creation].
+#[derive(Debug, Clone)]
+pub struct CertifiedKeyCreation {
+    public: Public,
+    creation_data: CreationData,
+    attest: Attest,
+    signature: Signature,
+}
+
+impl CertifiedKeyCreation {
+    /// Certifies the creation of a loaded key.
+    ///
+    /// # Arguments
+    /// * `context` - The context used to certify the creation.
+    /// * `signing_key_handle` - Handle of the key used to sign the attestation.
+    /// * `key_handle` - Handle of the loaded key whose creation is certified.
+    /// * `key_creation` - The creation outputs returned when the key was created.
+    /// * `qualifying_data` - Qualifying data, e.g. a nonce provided by the verifier.
+    /// * `signing_scheme` - Signing scheme to use if the scheme for `signing_key_handle` is `Null`.
+    ///
+    /// An authorization session for `signing_key_handle` needs to be set
+    /// in the first session slot of the `context`.
+    pub fn certify(
+        context: &mut Context,
+        signing_key_handle: KeyHandle,
+        key_handle: KeyHandle,
+        key_creation: KeyCreation,
+        qualifying_data: Data,
+        signing_scheme: SignatureScheme,
+    ) -> Result<Self> {
+        let (attest, signature) = context.certify_creation(
+            signing_key_handle,
+            key_handle.into(),
+            qualifying_data,
+            key_creation.creation_hash,
+            signing_scheme,
+            key_creation.creation_ticket,
+        )?;
+        Ok(CertifiedKeyCreation {
+            public: key_creation.public,
+            creation_data: key_creation.creation_data,
+            attest,
+            signature,
+        })
+    }
+
+    /// Creates the bundle from its parts, e.g. after they have been
+    /// transferred to a verifier.
+    pub fn new(
+        public: Public,
+        creation_data: CreationData,
+        attest: Attest,
+        signature: Signature,
+    ) -> Self {
+        CertifiedKeyCreation {
+            public,
+            creation_data,
+            attest,
+            signature,
+        }
+    }
+
+    /// Returns the public part of the key
+    pub const fn public(&self) -> &Public {
+        &self.public
+    }
+
+    /// Returns the creation data of the key
+    pub const fn creation_data(&self) -> &CreationData {
+        &self.creation_data
+    }
+
+    /// Returns the attestation produced by the TPM
+    pub const fn attest(&self) -> &Attest {
+        &self.attest
+    }
+
+    /// Returns the signature over the attestation
+    pub const fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    /// Verifies that the key was created under the expected PCR state and parent.
+    ///
+    /// # Details
+    /// The attestation is checked to be of the creation type, to contain the
+    /// provided `qualifying_data`, the name of the key and the digest of the
+    /// creation data. The creation data is then checked to contain the
+    /// `expected_pcr_digest` and `expected_parent_name`.
+    ///
+    /// The signature over the attestation is not checked by this function and
+    /// has to be verified separately using the public part of the signing key.
+    ///
+    /// # Arguments
+    /// * `qualifying_data` - The qualifying data that was provided to the TPM.
+    /// * `expected_pcr_digest` - The expected digest of the PCRs selected at creation.
+    /// * `expected_parent_name` - The expected [Name] of the parent of the key.
+    ///
+    /// # Errors
+    /// * if the attestation or the creation data does not match the expected
+    ///   values, an `InconsistentParams` wrapper error will be returned.
+    /// * if the name algorithm of the key is not supported in software, an
+    ///   `UnsupportedParam` wrapper error will be returned.
+    pub fn verify(
+        &self,
+        qualifying_data: &Data,
+        expected_pcr_digest: &Digest,
+        expected_parent_name: &Name,
+    ) -> Result<()> {
+        if self.attest.attestation_type() != AttestationType::Creation {
+            log::error!(
+                "Attestation type {:?} does not certify creation data",
+                self.attest.attestation_type()
+            );
+            return Err(Error::local_error(WrapperErrorKind::InconsistentParams));
+        }
+
+        if self.attest.extra_data() != qualifying_data {
+            log::error!("Attestation does not contain the expected qualifying data");
+            return Err(Error::local_error(WrapperErrorKind::InconsistentParams));
+        }
+
+        let creation_info = match self.attest.attested() {
+            AttestInfo::Creation { info } => info,
+            _ => {
+                log::error!("Attestation does not contain creation information");
+                return Err(Error::local_error(WrapperErrorKind::InconsistentParams));
+            }
+        };
+
+        if creation_info.object_name() != &object_name(&self.public)? {
+            log::error!("Certified object name does not match the name of the key");
+            return Err(Error::local_error(WrapperErrorKind::InconsistentParams));
+        }
+
+        let mut hasher = Hasher::new(self.public.name_hashing_algorithm())?;
+        hasher.update(&self.creation_data.marshall()?);
+        if creation_info.creation_hash() != &hasher.finish()? {
+            log::error!("Certified creation hash does not match the creation data");
+            return Err(Error::local_error(WrapperErrorKind::InconsistentParams));
+        }
+
+        if self.creation_data.pcr_digest() != expected_pcr_digest {
+            log::error!("PCR digest in the creation data does not match the expected digest");
+            return Err(Error::local_error(WrapperErrorKind::InconsistentParams));
+        }
+
+        if self.creation_data.parent_name() != expected_parent_name {
+            log::error!("Parent name in the creation data does not match the expected name");
+            return Err(Error::local_error(WrapperErrorKind::InconsistentParams));
+        }
+
+        Ok(())
+    }
+}
+
+/// Computes the name of an object from its public area.
+fn object_name(public: &Public) -> Result<Name> {
+    let name_hashing_algorithm = public.name_hashing_algorithm();
+    let mut hasher = Hasher::new(name_hashing_algorithm)?;
+    hasher.update(&public.marshall()?);
+    let mut name = TPMI_ALG_HASH::from(name_hashing_algorithm)
+        .to_be_bytes()
+        .to_vec();
+    name.extend_from_slice(hasher.finish()?.as_bytes());
+    Name::try_from(name)
+}
@@ -0,0 +1,74 @@
+// Copyright 2023 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Dictionary attack protection status
+use std::time::Duration;
+
+use crate::{constants::PropertyTag, Context, Error, Result, WrapperErrorKind};
+
+/// The state of the dictionary attack protection of the TPM
+///
+/// # Details
+/// The values are read from the variable TPM properties. The
+/// parameters can be changed with [Context::dictionary_attack_parameters]
+/// and a lockout can be cancelled with [Context::dictionary_attack_lock_reset].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LockoutStatus {
+    lockout_counter: u32,
+    max_auth_fail: u32,
+    lockout_interval: Duration,
+    lockout_recovery: Duration,
+}
+
+impl LockoutStatus {
+    /// Reads the current lockout status from the TPM.
+    ///
+    /// # Errors
+    /// * if the TPM does not report one of the lockout properties,
+    ///   a `WrongValueFromTpm` wrapper error will be returned.
+    pub fn read(context: &mut Context) -> Result<Self> {
+        let mut read_property = |property: PropertyTag| {
+            context.get_tpm_property(property)?.ok_or_else(|| {
+                log::error!("The TPM did not report the {:?} property", property);
+                Error::local_error(WrapperErrorKind::WrongValueFromTpm)
+            })
+        };
+
+        Ok(LockoutStatus {
+            lockout_counter: read_property(PropertyTag::LockoutCounter)?,
+            max_auth_fail: read_property(PropertyTag::MaxAuthFail)?,
+            lockout_interval: Duration::from_secs(
+                read_property(PropertyTag::LockoutInterval)?.into(),
+            ),
+            lockout_recovery: Duration::from_secs(
+                read_property(PropertyTag::LockoutRecovery)?.into(),
+            ),
+        })
+    }
+
+    /// Returns the current number of authorization failures
+    pub const fn lockout_counter(&self) -> u32 {
+        self.lockout_counter
+    }
+
+    /// Returns the number of authorization failures before lockout is imposed
+    pub const fn max_auth_fail(&self) -> u32 {
+        self.max_auth_fail
+    }
+
+    /// Returns the time before the lockout counter is decremented
+    pub const fn lockout_interval(&self) -> Duration {
+        self.lockout_interval
+    }
+
+    /// Returns the time after a failed lockout authorization
+    /// before the lockout authorization can be used again
+    pub const fn lockout_recovery(&self) -> Duration {
+        self.lockout_recovery
+    }
+
+    /// Indicates if the TPM is currently in lockout
+    pub const fn is_locked_out(&self) -> bool {
+        self.lockout_counter >= self.max_auth_fail
+    }
+}
@@ -0,0 +1,235 @@
+// Copyright 2023 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Module for uploading firmware images to the TPM
+//!
+//! [FirmwareUpload] implements [std::io::Write] which makes it possible to
+//! stream a firmware image, of arbitrary size, to the TPM. The current
+//! firmware of the TPM can be retrieved with [read_firmware].
+use crate::{
+    abstraction::sequence::max_input_buffer_size,
+    handles::KeyHandle,
+    interface_types::resource_handles::Platform,
+    structures::{Digest, HashAgile, MaxBuffer, Signature},
+    Context, Error, Result, WrapperErrorKind,
+};
+
+/// Field upgrade in progress
+///
+/// Data written to the upload is sent to the TPM in blocks that fit the TPM
+/// input buffer. After each block the TPM reports the digest of the next
+/// block it expects and the digest of the first block of the upgrade.
+/// The image therefore needs to be prepared by the vendor for blocks of
+/// [FirmwareUpload::block_size] bytes, or be sent block by block using
+/// [FirmwareUpload::send_block].
+///
+/// # Example
+///
+/// ```rust, no_run
+/// # use tss_esapi::{Context, tcti_ldr::TctiNameConf,
+/// #     handles::KeyHandle,
+/// #     interface_types::{resource_handles::Platform, session_handles::AuthSession},
+/// #     structures::{Digest, Signature},
+/// # };
+/// use std::io::Write;
+/// use tss_esapi::abstraction::field_upgrade::FirmwareUpload;
+/// # let mut context =
+/// #     Context::new(
+/// #         TctiNameConf::from_environment_variable().expect("Failed to get TCTI"),
+/// #     ).expect("Failed to create Context");
+/// # let key_handle = KeyHandle::Null;
+/// # let (fu_digest, manifest_signature) = (Digest::default(), Signature::Null);
+/// # let image = vec![0xff; 1024];
+/// let complete = context
+///     .execute_with_session(
+///         Some(AuthSession::Password),
+///         |ctx| -> Result<bool, Box<dyn std::error::Error>> {
+///             let mut upload = FirmwareUpload::start(
+///                 ctx,
+///                 Platform::Platform,
+///                 key_handle,
+///                 fu_digest,
+///                 manifest_signature,
+///             )?;
+///             upload.write_all(&image)?;
+///             Ok(upload.is_complete())
+///         },
+///     )
+///     .expect("Failed to upload firmware image");
+/// assert!(complete);
+/// ```
+#[derive(Debug)]
+pub struct FirmwareUpload<'a, T: FieldUpgradeTpm = Context> {
+    tpm: &'a mut T,
+    buffer_size: usize,
+    blocks_sent: usize,
+    next_digest: Option<HashAgile>,
+    first_digest: Option<HashAgile>,
+}
+
+/// The TPM commands used to transfer firmware data.
+///
+/// The trait is implemented by [Context]. Other implementations make it
+/// possible to exercise a [FirmwareUpload] or [read_firmware] without a TPM
+/// that supports field upgrades.
+pub trait FieldUpgradeTpm {
+    /// Returns the maximum size of a block of firmware data.
+    fn max_block_size(&mut self) -> Result<usize>;
+
+    /// Sends a block of firmware data, see [Context::field_upgrade_data].
+    fn field_upgrade_data(&mut self, fu_data: MaxBuffer) -> Result<(Option<HashAgile>, HashAgile)>;
+
+    /// Reads a block of the current firmware, see [Context::firmware_read].
+    fn firmware_read(&mut self, sequence_number: u32) -> Result<MaxBuffer>;
+}
+
+impl FieldUpgradeTpm for Context {
+    fn max_block_size(&mut self) -> Result<usize> {
+        max_input_buffer_size(self)
+    }
+
+    fn field_upgrade_data(&mut self, fu_data: MaxBuffer) -> Result<(Option<HashAgile>, HashAgile)> {
+        Context::field_upgrade_data(self, fu_data)
+    }
+
+    fn firmware_read(&mut self, sequence_number: u32) -> Result<MaxBuffer> {
+        Context::firmware_read(self, sequence_number)
+    }
+}
+
+impl<'a> FirmwareUpload<'a> {
+    /// Starts a field upgrade.
+    ///
+    /// Starting the field upgrade requires an authorization session
+    /// for the platform hierarchy.
+    ///
+    /// # Arguments
+    /// * `context` - The context used for the upload.
+    /// * `authorization` - The [Platform] handle used for authorization.
+    /// * `key_handle` - A [KeyHandle] of the public key used to verify the manifest signature.
+    /// * `fu_digest` - The digest of the first block of the firmware image.
+    /// * `manifest_signature` - The signature of `fu_digest`.
+    pub fn start(
+        context: &'a mut Context,
+        authorization: Platform,
+        key_handle: KeyHandle,
+        fu_digest: Digest,
+        manifest_signature: Signature,
+    ) -> Result<Self> {
+        context.field_upgrade_start(authorization, key_handle, fu_digest, manifest_signature)?;
+        FirmwareUpload::new(context)
+    }
+}
+
+impl<'a, T: FieldUpgradeTpm> FirmwareUpload<'a, T> {
+    /// Creates an upload for a field upgrade that has already been started.
+    ///
+    /// # Arguments
+    /// * `tpm` - The TPM to which the firmware data is sent.
+    pub fn new(tpm: &'a mut T) -> Result<Self> {
+        let buffer_size = tpm.max_block_size()?;
+        Ok(FirmwareUpload {
+            tpm,
+            buffer_size,
+            blocks_sent: 0,
+            next_digest: None,
+            first_digest: None,
+        })
+    }
+
+    /// Sends a single block of the firmware image to the TPM.
+    ///
+    /// # Errors
+    /// * if the block does not fit the TPM input buffer a `WrongParamSize`
+    ///   wrapper error is returned.
+    /// * if the upgrade has already been completed an `InvalidHandleState`
+    ///   wrapper error is returned.
+    pub fn send_block(&mut self, block: &[u8]) -> Result<()> {
+        if block.len() > self.buffer_size {
+            log::error!(
+                "The firmware block is larger than the TPM input buffer (> {})",
+                self.buffer_size
+            );
+            return Err(Error::local_error(WrapperErrorKind::WrongParamSize));
+        }
+        if self.is_complete() {
+            log::error!("The field upgrade has already been completed");
+            return Err(Error::local_error(WrapperErrorKind::InvalidHandleState));
+        }
+        let (next_digest, first_digest) =
+            self.tpm.field_upgrade_data(MaxBuffer::from_bytes(block)?)?;
+        self.blocks_sent += 1;
+        self.next_digest = next_digest;
+        self.first_digest = Some(first_digest);
+        Ok(())
+    }
+
+    /// Returns the maximum size of a block sent to the TPM.
+    pub const fn block_size(&self) -> usize {
+        self.buffer_size
+    }
+
+    /// Returns the digest of the next block expected by the TPM.
+    ///
+    /// This is `None` before the first block has been sent and
+    /// after the upgrade has been completed.
+    pub const fn next_digest(&self) -> Option<&HashAgile> {
+        self.next_digest.as_ref()
+    }
+
+    /// Returns the digest of the first block of the upgrade, as
+    /// reported by the TPM.
+    ///
+    /// This is `None` before the first block has been sent.
+    pub const fn first_digest(&self) -> Option<&HashAgile> {
+        self.first_digest.as_ref()
+    }
+
+    /// Indicates if the TPM has received the last block of the upgrade.
+    pub const fn is_complete(&self) -> bool {
+        self.blocks_sent > 0 && self.next_digest.is_none()
+    }
+}
+
+impl<T: FieldUpgradeTpm> std::io::Write for FirmwareUpload<'_, T> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+        let size = std::cmp::min(self.buffer_size, buf.len());
+        self.send_block(&buf[..size])
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e))?;
+        Ok(size)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        // Data isn't buffered
+        Ok(())
+    }
+}
+
+/// Reads the current firmware of the TPM.
+///
+/// # Details
+/// The blocks are read with increasing sequence numbers, starting at zero,
+/// until the TPM returns an empty block. The blocks are returned in the order
+/// in which they would have to be sent to the TPM to recover from an
+/// abandoned field upgrade.
+///
+/// # Arguments
+/// * `tpm` - The TPM from which the firmware is read.
+///
+/// # Returns
+/// The blocks of firmware data.
+pub fn read_firmware<T: FieldUpgradeTpm>(tpm: &mut T) -> Result<Vec<MaxBuffer>> {
+    let mut blocks = Vec::new();
+    for sequence_number in 0..=u32::MAX {
+        let block = tpm.firmware_read(sequence_number)?;
+        if block.is_empty() {
+            return Ok(blocks);
+        }
+        blocks.push(block);
+    }
+    log::error!("The TPM did not signal the end of the firmware");
+    Err(Error::local_error(WrapperErrorKind::WrongValueFromTpm))
+}
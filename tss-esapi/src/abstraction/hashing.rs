@@ -0,0 +1,70 @@
+// Copyright 2023 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Software implementations of the hashing algorithms supported by the TPM
+//!
+//! These are used by the abstractions that need to reproduce digests
+//! computed by the TPM, e.g. audit and policy digests.
+use std::convert::TryFrom;
+
+use crate::{
+    interface_types::algorithm::HashingAlgorithm, structures::Digest, Error, Result,
+    WrapperErrorKind,
+};
+use sha2::digest::DynDigest;
+
+/// Hasher computing a digest in software.
+pub(crate) struct Hasher {
+    inner: Box<dyn DynDigest>,
+}
+
+impl Hasher {
+    /// Creates a new hasher for the provided hashing algorithm.
+    ///
+    /// # Errors
+    /// * if the hashing algorithm is not supported in software, an
+    ///   `UnsupportedParam` wrapper error will be returned.
+    pub(crate) fn new(hashing_algorithm: HashingAlgorithm) -> Result<Self> {
+        let inner: Box<dyn DynDigest> = match hashing_algorithm {
+            HashingAlgorithm::Sha1 => Box::new(sha1::Sha1::default()),
+            HashingAlgorithm::Sha256 => Box::new(sha2::Sha256::default()),
+            HashingAlgorithm::Sha384 => Box::new(sha2::Sha384::default()),
+            HashingAlgorithm::Sha512 => Box::new(sha2::Sha512::default()),
+            HashingAlgorithm::Sha3_256 => Box::new(sha3::Sha3_256::default()),
+            HashingAlgorithm::Sha3_384 => Box::new(sha3::Sha3_384::default()),
+            HashingAlgorithm::Sha3_512 => Box::new(sha3::Sha3_512::default()),
+            HashingAlgorithm::Sm3_256 => Box::new(sm3::Sm3::default()),
+            HashingAlgorithm::Null => {
+                log::error!(
+                    "Hashing algorithm {:?} is not supported in software",
+                    hashing_algorithm
+                );
+                return Err(Error::local_error(WrapperErrorKind::UnsupportedParam));
+            }
+        };
+        Ok(Hasher { inner })
+    }
+
+    /// Adds data to the digest.
+    pub(crate) fn update(&mut self, data: &[u8]) {
+        self.inner.update(data);
+    }
+
+    /// Returns the size of the digest produced by the hasher.
+    pub(crate) fn output_size(&self) -> usize {
+        self.inner.output_size()
+    }
+
+    /// Consumes the hasher and returns the resulting digest.
+    pub(crate) fn finish(self) -> Result<Digest> {
+        Digest::try_from(self.inner.finalize().into_vec())
+    }
+}
+
+impl std::fmt::Debug for Hasher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Hasher")
+            .field("output_size", &self.output_size())
+            .finish()
+    }
+}
@@ -2,12 +2,24 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub mod ak;
+pub mod audit;
 pub mod cipher;
+pub mod creation;
+pub mod dictionary_attack;
 pub mod ek;
+pub mod field_upgrade;
 pub mod nv;
 pub mod pcr;
+pub mod physical_presence;
+pub mod policy;
 pub mod public;
+pub mod reparent;
+pub mod self_test;
+pub mod sequence;
 pub mod transient;
+pub mod x509;
+
+mod hashing;
 
 use std::convert::TryFrom;
 
@@ -7,10 +7,13 @@ use std::{
 };
 
 use crate::{
+    abstraction::hashing::Hasher,
     constants::{tss::*, CapabilityType, PropertyTag},
     handles::{AuthHandle, NvIndexHandle, NvIndexTpmHandle, TpmHandle},
-    interface_types::resource_handles::NvAuth,
-    structures::{CapabilityData, MaxNvBuffer, Name, NvPublic},
+    interface_types::{algorithm::HashingAlgorithm, resource_handles::NvAuth},
+    structures::{Attest, AttestInfo, CapabilityData, Data, MaxNvBuffer, Name, NvPublic},
+    traits::Marshall,
+    tss2_esys::TPMI_ALG_HASH,
     Context, Error, Result, WrapperErrorKind,
 };
 
@@ -87,6 +90,122 @@ pub fn list(context: &mut Context) -> Result<Vec<(NvPublic, Name)>> {
     })
 }
 
+/// Verifies that an attestation produced by [Context::nv_certify] certifies
+/// the expected contents of an NV index.
+///
+/// # Details
+/// The attestation is checked to contain the provided `qualifying_data`
+/// and the name of the NV index described by `nv_public`. The `nv_public`
+/// needs to be the current public area of the NV index, as read by
+/// [Context::nv_read_public], because the name of the NV index changes
+/// when, for example, the index is written for the first time.
+///
+/// What is certified depends on the type of the attestation:
+/// * `Nv` - The attestation contains the `nv_contents` read at `offset`.
+/// * `NvDigest` - The TPM produces this type when both the size and the offset
+///   passed to [Context::nv_certify] are zero. The attestation contains the digest,
+///   computed with `hashing_algorithm`, of the complete contents of the NV index,
+///   so `offset` has to be zero and `nv_contents` have to be the complete contents.
+///
+/// The signature over the attestation is not checked by this function and
+/// has to be verified separately using the public part of the signing key.
+///
+/// # Arguments
+/// * `attest` - The attestation returned by [Context::nv_certify].
+/// * `qualifying_data` - The qualifying data that was provided to the TPM.
+/// * `nv_public` - The expected [NvPublic] of the NV index.
+/// * `offset` - The expected offset into the NV area.
+/// * `nv_contents` - The expected contents of the NV area.
+/// * `hashing_algorithm` - The hashing algorithm of the signing scheme, which
+///                         the TPM uses to digest the contents of the NV index
+///                         in `NvDigest` attestations.
+///
+/// # Errors
+/// * if the attestation does not match the expected values, an
+///   `InconsistentParams` wrapper error will be returned.
+pub fn verify_nv_certify_info(
+    attest: &Attest,
+    qualifying_data: &Data,
+    nv_public: &NvPublic,
+    offset: u16,
+    nv_contents: &[u8],
+    #[cfg_attr(not(has_tpms_nv_digest_certify_info), allow(unused_variables))]
+    hashing_algorithm: HashingAlgorithm,
+) -> Result<()> {
+    if attest.extra_data() != qualifying_data {
+        log::error!("Attestation does not contain the expected qualifying data");
+        return Err(Error::local_error(WrapperErrorKind::InconsistentParams));
+    }
+
+    let nv_name = nv_index_name(nv_public)?;
+    match attest.attested() {
+        AttestInfo::Nv { info } => {
+            if info.index_name() != &nv_name {
+                log::error!("Certified NV index name does not match the expected name");
+                return Err(Error::local_error(WrapperErrorKind::InconsistentParams));
+            }
+
+            if info.offset() != offset {
+                log::error!(
+                    "Certified NV offset {} does not match the expected offset {}",
+                    info.offset(),
+                    offset
+                );
+                return Err(Error::local_error(WrapperErrorKind::InconsistentParams));
+            }
+
+            if info.nv_contents().as_slice() != nv_contents {
+                log::error!("Certified NV contents do not match the expected contents");
+                return Err(Error::local_error(WrapperErrorKind::InconsistentParams));
+            }
+        }
+        #[cfg(has_tpms_nv_digest_certify_info)]
+        AttestInfo::NvDigest { info } => {
+            if info.index_name() != &nv_name {
+                log::error!("Certified NV index name does not match the expected name");
+                return Err(Error::local_error(WrapperErrorKind::InconsistentParams));
+            }
+
+            if offset != 0 || nv_contents.len() != nv_public.data_size() {
+                log::error!("The digest of the NV contents only certifies the complete contents");
+                return Err(Error::local_error(WrapperErrorKind::InconsistentParams));
+            }
+
+            let mut hasher = Hasher::new(hashing_algorithm)?;
+            hasher.update(nv_contents);
+            if &hasher.finish()? != info.nv_digest() {
+                log::error!(
+                    "Certified NV digest does not match the digest of the expected contents"
+                );
+                return Err(Error::local_error(WrapperErrorKind::InconsistentParams));
+            }
+        }
+        _ => {
+            log::error!(
+                "Attestation type {:?} does not certify NV contents",
+                attest.attestation_type()
+            );
+            return Err(Error::local_error(WrapperErrorKind::InconsistentParams));
+        }
+    }
+
+    Ok(())
+}
+
+/// Computes the name of an NV index from its public area.
+fn nv_index_name(nv_public: &NvPublic) -> Result<Name> {
+    let mut hasher = Hasher::new(nv_public.name_algorithm())?;
+    hasher.update(&nv_public.marshall()?);
+    let name_algorithm = TPMI_ALG_HASH::from(nv_public.name_algorithm());
+    Name::try_from(
+        [
+            &name_algorithm.to_be_bytes()[..],
+            hasher.finish()?.as_bytes(),
+        ]
+        .concat(),
+    )
+}
+
 /// Options and flags which can be used to determine how a non-volatile storage index is opened.
 #[non_exhaustive]
 #[derive(Debug, Clone)]
@@ -0,0 +1,137 @@
+// Copyright 2023 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Physical presence command configuration
+//!
+//! The platform can configure which commands require the assertion of
+//! physical presence in addition to the normal authorization. This module
+//! provides helpers for reading the current configuration and for
+//! computing the changes needed to reach a desired configuration.
+use crate::{
+    constants::{CapabilityType, CommandCode},
+    interface_types::resource_handles::Platform,
+    structures::{CapabilityData, CommandCodeList},
+    tss2_esys::TPM2_CC,
+    Context, Error, Result, WrapperErrorKind,
+};
+use std::convert::TryFrom;
+
+/// Reads the list of commands that require physical presence.
+///
+/// # Details
+/// The list is read in as many pages as needed, but the combined
+/// list is limited to [CommandCodeList::MAX_SIZE] commands.
+///
+/// # Errors
+/// * if the TPM reports more commands than fit in a [CommandCodeList]
+///   a `WrongParamSize` wrapper error is returned.
+pub fn read_pp_commands(context: &mut Context) -> Result<CommandCodeList> {
+    let mut pp_commands = Vec::<CommandCode>::new();
+    let mut property: TPM2_CC = 0;
+    loop {
+        let (capability_data, more_data) = context.execute_without_session(|ctx| {
+            ctx.get_capability(
+                CapabilityType::PpCommands,
+                property,
+                CommandCodeList::MAX_SIZE as u32,
+            )
+        })?;
+        let command_code_list = match capability_data {
+            CapabilityData::PpCommands(command_code_list) => command_code_list,
+            _ => {
+                log::error!("The TPM returned unexpected capability data");
+                return Err(Error::local_error(WrapperErrorKind::WrongValueFromTpm));
+            }
+        };
+        if pp_commands.len() + command_code_list.len() > CommandCodeList::MAX_SIZE {
+            log::error!(
+                "The TPM reported too many commands requiring physical presence (> {})",
+                CommandCodeList::MAX_SIZE
+            );
+            return Err(Error::local_error(WrapperErrorKind::WrongParamSize));
+        }
+        let last_command_code = command_code_list.last().copied();
+        pp_commands.extend(command_code_list.into_inner());
+        match last_command_code {
+            Some(command_code) if more_data => property = TPM2_CC::from(command_code) + 1,
+            _ => break,
+        }
+    }
+    CommandCodeList::try_from(pp_commands)
+}
+
+/// The changes needed to go from one physical presence
+/// configuration to another.
+///
+/// # Details
+/// The lists can be passed directly to [Context::pp_commands],
+/// or applied with [PpCommandsDiff::apply].
+#[derive(Debug, Clone)]
+pub struct PpCommandsDiff {
+    set_list: CommandCodeList,
+    clear_list: CommandCodeList,
+}
+
+impl PpCommandsDiff {
+    /// Computes the changes needed to go from the `current` to
+    /// the `desired` list of commands requiring physical presence.
+    ///
+    /// # Details
+    /// Commands that appear more than once in `current` or `desired`
+    /// are only included once in the resulting lists, and no command
+    /// is in both the set list and the clear list.
+    pub fn new(current: &[CommandCode], desired: &[CommandCode]) -> Result<Self> {
+        Ok(PpCommandsDiff {
+            set_list: CommandCodeList::try_from(Self::difference(desired, current))?,
+            clear_list: CommandCodeList::try_from(Self::difference(current, desired))?,
+        })
+    }
+
+    /// Computes the changes needed to go from the configuration
+    /// currently used by the TPM to the `desired` one.
+    pub fn read(context: &mut Context, desired: &[CommandCode]) -> Result<Self> {
+        let current = read_pp_commands(context)?;
+        PpCommandsDiff::new(&current, desired)
+    }
+
+    /// Returns the commands that need to start requiring physical presence.
+    pub fn set_list(&self) -> &CommandCodeList {
+        &self.set_list
+    }
+
+    /// Returns the commands that need to stop requiring physical presence.
+    pub fn clear_list(&self) -> &CommandCodeList {
+        &self.clear_list
+    }
+
+    /// Indicates if the configurations are the same.
+    pub fn is_empty(&self) -> bool {
+        self.set_list.is_empty() && self.clear_list.is_empty()
+    }
+
+    /// Applies the changes to the TPM.
+    ///
+    /// # Details
+    /// Nothing is sent to the TPM if there are no changes. Otherwise an
+    /// authorization session for the platform hierarchy needs to be set in
+    /// the first session slot of the `context` and physical presence needs
+    /// to be asserted.
+    pub fn apply(self, context: &mut Context) -> Result<()> {
+        if self.is_empty() {
+            return Ok(());
+        }
+        context.pp_commands(Platform::Platform, self.set_list, self.clear_list)
+    }
+
+    /// Private function that returns the distinct commands
+    /// of `command_codes` that are not in `excluded`.
+    fn difference(command_codes: &[CommandCode], excluded: &[CommandCode]) -> Vec<CommandCode> {
+        let mut difference = Vec::<CommandCode>::new();
+        for command_code in command_codes {
+            if !excluded.contains(command_code) && !difference.contains(command_code) {
+                difference.push(*command_code);
+            }
+        }
+        difference
+    }
+}
@@ -0,0 +1,399 @@
+// Copyright 2023 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Module for computing policy digests in software
+//!
+//! Each policy command extends the policy digest of a session according
+//! to the rules in the TPM 2.0 specification, Part 3, e.g.
+//!
+//! `policyDigest_new := H(policyDigest_old || TPM_CC_PolicyCommandCode || code)`
+//!
+//! where the policy digest starts out as a zero digest. Reproducing the rules
+//! in software makes it possible to compute the digest that is used as the
+//! `auth_policy` of an object without having access to a TPM.
+//!
+//! The commands that carry out checks which do not affect the policy digest,
+//! such as PolicyTicket, which results in the same policy digest as the
+//! PolicySigned or PolicySecret that produced the ticket, do not have a
+//! corresponding method.
+use std::convert::TryFrom;
+
+use crate::{
+    abstraction::hashing::Hasher,
+    attributes::LocalityAttributes,
+    constants::{ArithmeticOperation, CommandCode},
+    interface_types::algorithm::HashingAlgorithm,
+    structures::{Digest, DigestList, Name, Nonce, Operand, PcrSelectionList},
+    tss2_esys::{TPM2_CC, TPM2_EO, TPMA_LOCALITY, TPML_PCR_SELECTION},
+    Error, Result, WrapperErrorKind,
+};
+
+/// Calculator for policy digests.
+///
+/// # Details
+/// The methods correspond to the `policy_*` methods of [Context](crate::Context)
+/// and update the policy digest in the same way as the TPM does for a trial session.
+///
+/// # Example
+/// ```rust
+/// use tss_esapi::{
+///     abstraction::policy::PolicyDigestCalculator, constants::CommandCode,
+///     interface_types::algorithm::HashingAlgorithm,
+/// };
+/// let mut calculator = PolicyDigestCalculator::new(HashingAlgorithm::Sha256)
+///     .expect("Failed to create calculator");
+/// calculator
+///     .policy_command_code(CommandCode::Unseal)
+///     .expect("Failed to compute policy command code");
+/// calculator
+///     .policy_auth_value()
+///     .expect("Failed to compute policy auth value");
+/// let auth_policy = calculator.policy_digest();
+/// ```
+#[derive(Debug, Clone)]
+pub struct PolicyDigestCalculator {
+    hashing_algorithm: HashingAlgorithm,
+    policy_digest: Digest,
+}
+
+impl PolicyDigestCalculator {
+    /// Creates a new calculator with a zero policy digest.
+    ///
+    /// # Errors
+    /// * if the hashing algorithm is not supported in software, an
+    ///   `UnsupportedParam` wrapper error will be returned.
+    pub fn new(hashing_algorithm: HashingAlgorithm) -> Result<Self> {
+        let digest_size = Hasher::new(hashing_algorithm)?.output_size();
+        Ok(PolicyDigestCalculator {
+            hashing_algorithm,
+            policy_digest: Digest::try_from(vec![0u8; digest_size])?,
+        })
+    }
+
+    /// Returns the hashing algorithm of the policy.
+    pub fn hashing_algorithm(&self) -> HashingAlgorithm {
+        self.hashing_algorithm
+    }
+
+    /// Returns the current policy digest.
+    pub fn policy_digest(&self) -> Digest {
+        self.policy_digest.clone()
+    }
+
+    /// Resets the policy digest to a zero digest.
+    pub fn policy_restart(&mut self) {
+        self.policy_digest = Digest::try_from(vec![0u8; self.policy_digest.len()])
+            .expect("The size of the policy digest is always valid");
+    }
+
+    /// Extends the policy digest with an assertion signed by the object
+    /// with the name `auth_object_name`.
+    pub fn policy_signed(&mut self, auth_object_name: Name, policy_ref: Nonce) -> Result<()> {
+        self.policy_update(CommandCode::PolicySigned, &auth_object_name, &policy_ref)
+    }
+
+    /// Extends the policy digest with an assertion that the authorization
+    /// of the entity with the name `auth_handle_name` was provided.
+    pub fn policy_secret(&mut self, auth_handle_name: Name, policy_ref: Nonce) -> Result<()> {
+        self.policy_update(CommandCode::PolicySecret, &auth_handle_name, &policy_ref)
+    }
+
+    /// Replaces the policy digest with a digest of the alternative policies.
+    ///
+    /// # Errors
+    /// * if the `digest_list` contains less than two digests a `WrongParamSize`
+    ///   wrapper error is returned.
+    pub fn policy_or(&mut self, digest_list: DigestList) -> Result<()> {
+        if digest_list.len() < 2 {
+            log::error!(
+                "The digest list only contains {} digests, it must contain at least 2",
+                digest_list.len()
+            );
+            return Err(Error::local_error(WrapperErrorKind::WrongParamSize));
+        }
+        self.policy_restart();
+        let digests = digest_list
+            .value()
+            .iter()
+            .map(Digest::as_bytes)
+            .collect::<Vec<&[u8]>>();
+        self.extend(CommandCode::PolicyOr, &digests)
+    }
+
+    /// Extends the policy digest with the selected PCRs and
+    /// the digest of their values.
+    ///
+    /// # Arguments
+    /// * `pcr_policy_digest` - The digest of the concatenated PCR values, see
+    ///                         [PolicyDigestCalculator::pcr_values_digest].
+    /// * `pcr_selection_list` - The selected PCRs.
+    ///
+    /// # Errors
+    /// * if the size of `pcr_policy_digest` does not match the digest size of
+    ///   the policy, an `InconsistentParams` wrapper error will be returned.
+    pub fn policy_pcr(
+        &mut self,
+        pcr_policy_digest: Digest,
+        pcr_selection_list: PcrSelectionList,
+    ) -> Result<()> {
+        self.ensure_digest_size(&pcr_policy_digest)?;
+        let pcrs = Self::marshall_pcr_selection_list(pcr_selection_list);
+        self.extend(CommandCode::PolicyPcr, &[&pcrs, &pcr_policy_digest])
+    }
+
+    /// Computes the digest of PCR values, as used in [PolicyDigestCalculator::policy_pcr].
+    ///
+    /// # Arguments
+    /// * `pcr_values` - The values of the selected PCRs, in the order they
+    ///                  appear in the selection.
+    pub fn pcr_values_digest(&self, pcr_values: &[Digest]) -> Result<Digest> {
+        let mut hasher = Hasher::new(self.hashing_algorithm)?;
+        pcr_values
+            .iter()
+            .for_each(|pcr_value| hasher.update(pcr_value));
+        hasher.finish()
+    }
+
+    /// Extends the policy digest with the localities
+    /// from which the command may be executed.
+    pub fn policy_locality(&mut self, locality: LocalityAttributes) -> Result<()> {
+        self.extend(
+            CommandCode::PolicyLocality,
+            &[&[TPMA_LOCALITY::from(locality)]],
+        )
+    }
+
+    /// Extends the policy digest with a comparison of the contents
+    /// of the NV index with the name `nv_index_name`.
+    pub fn policy_nv(
+        &mut self,
+        nv_index_name: Name,
+        operand_b: Operand,
+        offset: u16,
+        operation: ArithmeticOperation,
+    ) -> Result<()> {
+        let args = self.operand_args(&operand_b, offset, operation)?;
+        self.extend(CommandCode::PolicyNv, &[&args, nv_index_name.value()])
+    }
+
+    /// Extends the policy digest with a comparison of the
+    /// TPM clock and timer values.
+    pub fn policy_counter_timer(
+        &mut self,
+        operand_b: Operand,
+        offset: u16,
+        operation: ArithmeticOperation,
+    ) -> Result<()> {
+        let args = self.operand_args(&operand_b, offset, operation)?;
+        self.extend(CommandCode::PolicyCounterTimer, &[&args])
+    }
+
+    /// Extends the policy digest with the command code of the
+    /// command that may be authorized.
+    pub fn policy_command_code(&mut self, code: CommandCode) -> Result<()> {
+        self.extend(
+            CommandCode::PolicyCommandCode,
+            &[&TPM2_CC::from(code).to_be_bytes()],
+        )
+    }
+
+    /// Extends the policy digest with an assertion of physical presence.
+    pub fn policy_physical_presence(&mut self) -> Result<()> {
+        self.extend(CommandCode::PolicyPhysicalPresence, &[])
+    }
+
+    /// Extends the policy digest with the cpHash of the command that may be authorized.
+    ///
+    /// # Errors
+    /// * if the size of `cp_hash_a` does not match the digest size of
+    ///   the policy, an `InconsistentParams` wrapper error will be returned.
+    pub fn policy_cp_hash(&mut self, cp_hash_a: Digest) -> Result<()> {
+        self.ensure_digest_size(&cp_hash_a)?;
+        self.extend(CommandCode::PolicyCpHash, &[&cp_hash_a])
+    }
+
+    /// Extends the policy digest with the digest of the names of
+    /// the handles of the command that may be authorized.
+    ///
+    /// # Errors
+    /// * if the size of `name_hash` does not match the digest size of
+    ///   the policy, an `InconsistentParams` wrapper error will be returned.
+    pub fn policy_name_hash(&mut self, name_hash: Digest) -> Result<()> {
+        self.ensure_digest_size(&name_hash)?;
+        self.extend(CommandCode::PolicyNameHash, &[&name_hash])
+    }
+
+    /// Extends the policy digest with the selection of the new parent
+    /// of a duplicated object.
+    pub fn policy_duplication_select(
+        &mut self,
+        object_name: Name,
+        new_parent_name: Name,
+        include_object: bool,
+    ) -> Result<()> {
+        if include_object {
+            self.extend(
+                CommandCode::PolicyDuplicationSelect,
+                &[object_name.value(), new_parent_name.value(), &[1u8]],
+            )
+        } else {
+            self.extend(
+                CommandCode::PolicyDuplicationSelect,
+                &[new_parent_name.value(), &[0u8]],
+            )
+        }
+    }
+
+    /// Replaces the policy digest with a policy that allows any policy
+    /// approved by the key with the name `key_sign`.
+    pub fn policy_authorize(&mut self, policy_ref: Nonce, key_sign: &Name) -> Result<()> {
+        self.policy_restart();
+        self.policy_update(CommandCode::PolicyAuthorize, key_sign, &policy_ref)
+    }
+
+    /// Extends the policy digest with a requirement to provide the auth value
+    /// of the authorized entity in an HMAC.
+    pub fn policy_auth_value(&mut self) -> Result<()> {
+        self.extend(CommandCode::PolicyAuthValue, &[])
+    }
+
+    /// Extends the policy digest with a requirement to provide the auth value
+    /// of the authorized entity as a password.
+    ///
+    /// # Details
+    /// The policy digest is the same as for [PolicyDigestCalculator::policy_auth_value].
+    pub fn policy_password(&mut self) -> Result<()> {
+        self.extend(CommandCode::PolicyAuthValue, &[])
+    }
+
+    /// Extends the policy digest with a requirement on the written
+    /// attribute of the NV index that is authorized.
+    pub fn policy_nv_written(&mut self, written_set: bool) -> Result<()> {
+        self.extend(CommandCode::PolicyNvWritten, &[&[u8::from(written_set)]])
+    }
+
+    /// Extends the policy digest with the digest of the template
+    /// of the object that may be created.
+    ///
+    /// # Errors
+    /// * if the size of `template_hash` does not match the digest size of
+    ///   the policy, an `InconsistentParams` wrapper error will be returned.
+    pub fn policy_template(&mut self, template_hash: Digest) -> Result<()> {
+        self.ensure_digest_size(&template_hash)?;
+        self.extend(CommandCode::PolicyTemplate, &[&template_hash])
+    }
+
+    /// Replaces the policy digest with a policy that allows the policy
+    /// stored in the NV index with the name `nv_index_name`.
+    pub fn policy_authorize_nv(&mut self, nv_index_name: Name) -> Result<()> {
+        self.policy_restart();
+        self.extend(CommandCode::PolicyAuthorizeNv, &[nv_index_name.value()])
+    }
+
+    /// Extends the policy digest with the selection of the attached
+    /// component that may receive the object.
+    ///
+    /// The `object_name` is only included in the policy digest
+    /// if `include_object` is set.
+    pub fn policy_ac_send_select(
+        &mut self,
+        object_name: Name,
+        auth_handle_name: Name,
+        ac_name: Name,
+        include_object: bool,
+    ) -> Result<()> {
+        if include_object {
+            self.extend(
+                CommandCode::PolicyAcSendSelect,
+                &[
+                    object_name.value(),
+                    auth_handle_name.value(),
+                    ac_name.value(),
+                    &[1u8],
+                ],
+            )
+        } else {
+            self.extend(
+                CommandCode::PolicyAcSendSelect,
+                &[auth_handle_name.value(), ac_name.value(), &[0u8]],
+            )
+        }
+    }
+
+    /// Private method that extends the policy digest:
+    ///
+    /// `policyDigest_new := H(policyDigest_old || commandCode || data)`
+    fn extend(&mut self, command_code: CommandCode, data: &[&[u8]]) -> Result<()> {
+        let mut hasher = Hasher::new(self.hashing_algorithm)?;
+        hasher.update(&self.policy_digest);
+        hasher.update(&TPM2_CC::from(command_code).to_be_bytes());
+        data.iter().for_each(|item| hasher.update(item));
+        self.policy_digest = hasher.finish()?;
+        Ok(())
+    }
+
+    /// Private method implementing the PolicyUpdate function
+    /// of the specification:
+    ///
+    /// `policyDigest_new := H(H(policyDigest_old || commandCode || name) || policyRef)`
+    fn policy_update(
+        &mut self,
+        command_code: CommandCode,
+        name: &Name,
+        policy_ref: &Nonce,
+    ) -> Result<()> {
+        self.extend(command_code, &[name.value()])?;
+        let mut hasher = Hasher::new(self.hashing_algorithm)?;
+        hasher.update(&self.policy_digest);
+        hasher.update(policy_ref);
+        self.policy_digest = hasher.finish()?;
+        Ok(())
+    }
+
+    /// Private method that computes the digest of the arguments
+    /// of PolicyNV and PolicyCounterTimer:
+    ///
+    /// `args := H(operandB || offset || operation)`
+    fn operand_args(
+        &self,
+        operand_b: &Operand,
+        offset: u16,
+        operation: ArithmeticOperation,
+    ) -> Result<Digest> {
+        let mut hasher = Hasher::new(self.hashing_algorithm)?;
+        hasher.update(operand_b);
+        hasher.update(&offset.to_be_bytes());
+        hasher.update(&TPM2_EO::from(operation).to_be_bytes());
+        hasher.finish()
+    }
+
+    /// Private method that checks that a digest has the
+    /// digest size of the policy.
+    fn ensure_digest_size(&self, digest: &Digest) -> Result<()> {
+        if digest.len() != self.policy_digest.len() {
+            log::error!(
+                "The size of the digest({}) does not match the policy digest size({})",
+                digest.len(),
+                self.policy_digest.len()
+            );
+            return Err(Error::local_error(WrapperErrorKind::InconsistentParams));
+        }
+        Ok(())
+    }
+
+    /// Private function that marshalls a PCR selection list.
+    fn marshall_pcr_selection_list(pcr_selection_list: PcrSelectionList) -> Vec<u8> {
+        let tpml_pcr_selection = TPML_PCR_SELECTION::from(pcr_selection_list);
+        let mut buffer = tpml_pcr_selection.count.to_be_bytes().to_vec();
+        tpml_pcr_selection.pcrSelections[..tpml_pcr_selection.count as usize]
+            .iter()
+            .for_each(|pcr_selection| {
+                buffer.extend_from_slice(&pcr_selection.hash.to_be_bytes());
+                buffer.push(pcr_selection.sizeofSelect);
+                buffer.extend_from_slice(
+                    &pcr_selection.pcrSelect[..pcr_selection.sizeofSelect as usize],
+                );
+            });
+        buffer
+    }
+}
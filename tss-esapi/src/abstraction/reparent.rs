@@ -0,0 +1,251 @@
+// Copyright 2023 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Moving duplicable keys between parents
+//!
+//! A key that has neither `fixed_tpm` nor `fixed_parent` set can be moved
+//! to another parent by duplicating it and importing the duplicate under
+//! the new parent. The key needs to be created with the policy computed
+//! by [duplication_select_policy], which only allows duplication to one
+//! specific parent.
+use crate::{
+    abstraction::cipher::Cipher,
+    attributes::SessionAttributesBuilder,
+    constants::SessionType,
+    handles::{KeyHandle, ObjectHandle, SessionHandle},
+    interface_types::{algorithm::HashingAlgorithm, session_handles::PolicySession},
+    structures::{Data, Digest, EncryptedSecret, Name, Private, Public, SymmetricDefinitionObject},
+    Context, Error, Result, WrapperErrorKind,
+};
+use std::convert::{TryFrom, TryInto};
+
+/// Computes the policy that allows an object to be duplicated to `new_parent_name`.
+///
+/// # Details
+/// The name of the duplicated object is not included in the policy, so the
+/// same policy can be used for all objects that are to be moved to the
+/// new parent. The resulting digest is meant to be used as the auth policy
+/// of the object.
+pub fn duplication_select_policy(
+    context: &mut Context,
+    new_parent_name: Name,
+    hashing_algorithm: HashingAlgorithm,
+) -> Result<Digest> {
+    let trial_session = context
+        .execute_without_session(|ctx| {
+            ctx.start_auth_session(
+                None,
+                None,
+                None,
+                SessionType::Trial,
+                Cipher::aes_128_cfb().try_into()?,
+                hashing_algorithm,
+            )
+        })?
+        .ok_or_else(|| Error::local_error(WrapperErrorKind::WrongValueFromTpm))?;
+
+    context.execute_with_temporary_object(SessionHandle::from(trial_session).into(), |ctx, _| {
+        ctx.execute_without_session(|ctx| {
+            let policy_session = PolicySession::try_from(trial_session)?;
+            ctx.policy_duplication_select(
+                policy_session,
+                Name::try_from(Vec::<u8>::new())?,
+                new_parent_name,
+                false,
+            )?;
+            ctx.policy_get_digest(policy_session)
+        })
+    })
+}
+
+/// A duplicated object
+///
+/// # Details
+/// The duplicate is protected by the seed of the parent it was duplicated
+/// or rewrapped to and, if a symmetric algorithm was selected, by an inner
+/// wrapper using `encryption_key`.
+#[derive(Debug, Clone)]
+pub struct DuplicatedObject {
+    public: Public,
+    name: Name,
+    encryption_key: Data,
+    duplicate: Private,
+    sym_seed: EncryptedSecret,
+    symmetric_alg: SymmetricDefinitionObject,
+}
+
+impl DuplicatedObject {
+    /// Duplicates `object_handle` to `new_parent_handle`.
+    ///
+    /// # Details
+    /// A policy session satisfying the policy computed by [duplication_select_policy]
+    /// is used to authorize the duplication.
+    ///
+    /// # Arguments
+    /// * `context` - The context used to duplicate the object.
+    /// * `object_handle` - An [ObjectHandle] of the object that will be duplicated.
+    /// * `new_parent_handle` - An [ObjectHandle] of the new parent.
+    /// * `symmetric_alg` - Symmetric algorithm to be used for the inner wrapper.
+    pub fn duplicate(
+        context: &mut Context,
+        object_handle: ObjectHandle,
+        new_parent_handle: ObjectHandle,
+        symmetric_alg: SymmetricDefinitionObject,
+    ) -> Result<Self> {
+        let (public, object_name, _) = context.read_public(KeyHandle::from(object_handle))?;
+        let new_parent_name = context.tr_get_name(new_parent_handle)?;
+
+        let policy_auth_session = context
+            .execute_without_session(|ctx| {
+                ctx.start_auth_session(
+                    None,
+                    None,
+                    None,
+                    SessionType::Policy,
+                    Cipher::aes_128_cfb().try_into()?,
+                    public.name_hashing_algorithm(),
+                )
+            })?
+            .ok_or_else(|| Error::local_error(WrapperErrorKind::WrongValueFromTpm))?;
+
+        let (session_attributes, session_attributes_mask) = SessionAttributesBuilder::new()
+            .with_decrypt(true)
+            .with_encrypt(true)
+            .build();
+        context.tr_sess_set_attributes(
+            policy_auth_session,
+            session_attributes,
+            session_attributes_mask,
+        )?;
+
+        let (encryption_key, duplicate, sym_seed) = context.execute_with_temporary_object(
+            SessionHandle::from(policy_auth_session).into(),
+            |ctx, _| {
+                ctx.execute_without_session(|ctx| {
+                    ctx.policy_duplication_select(
+                        PolicySession::try_from(policy_auth_session)?,
+                        object_name.clone(),
+                        new_parent_name,
+                        false,
+                    )
+                })?;
+
+                ctx.execute_with_session(Some(policy_auth_session), |ctx| {
+                    ctx.duplicate(object_handle, new_parent_handle, None, symmetric_alg)
+                })
+            },
+        )?;
+
+        Ok(DuplicatedObject {
+            public,
+            name: object_name,
+            encryption_key,
+            duplicate,
+            sym_seed,
+            symmetric_alg,
+        })
+    }
+
+    /// Changes the outer wrapper of the duplicate from `old_parent` to `new_parent`.
+    ///
+    /// # Details
+    /// An authorization session for `old_parent` needs to be set
+    /// in the first session slot of the `context`.
+    pub fn rewrap(
+        self,
+        context: &mut Context,
+        old_parent: ObjectHandle,
+        new_parent: ObjectHandle,
+    ) -> Result<Self> {
+        let (duplicate, sym_seed) = context.rewrap(
+            old_parent,
+            new_parent,
+            self.duplicate,
+            self.name.clone(),
+            self.sym_seed,
+        )?;
+        Ok(DuplicatedObject {
+            duplicate,
+            sym_seed,
+            ..self
+        })
+    }
+
+    /// Imports the duplicate under `parent_handle`.
+    ///
+    /// # Details
+    /// An authorization session for `parent_handle` needs to be set
+    /// in the first session slot of the `context`.
+    ///
+    /// # Returns
+    /// The private part of the object that can be loaded under
+    /// `parent_handle` together with [DuplicatedObject::public].
+    pub fn import(self, context: &mut Context, parent_handle: ObjectHandle) -> Result<Private> {
+        context.import(
+            parent_handle,
+            Some(self.encryption_key),
+            self.public,
+            self.duplicate,
+            self.sym_seed,
+            self.symmetric_alg,
+        )
+    }
+
+    /// Returns the public part of the duplicated object
+    pub const fn public(&self) -> &Public {
+        &self.public
+    }
+
+    /// Returns the name of the duplicated object
+    pub const fn name(&self) -> &Name {
+        &self.name
+    }
+
+    /// Returns the key of the inner wrapper
+    pub const fn encryption_key(&self) -> &Data {
+        &self.encryption_key
+    }
+
+    /// Returns the duplicate
+    pub const fn duplicate_private(&self) -> &Private {
+        &self.duplicate
+    }
+
+    /// Returns the seed protected by the current parent
+    pub const fn sym_seed(&self) -> &EncryptedSecret {
+        &self.sym_seed
+    }
+
+    /// Returns the symmetric algorithm of the inner wrapper
+    pub const fn symmetric_alg(&self) -> SymmetricDefinitionObject {
+        self.symmetric_alg
+    }
+}
+
+/// Moves `object_handle` to `new_parent_handle`.
+///
+/// # Details
+/// The object is duplicated to the new parent and then imported under it.
+/// The object must have been created with the policy computed by
+/// [duplication_select_policy] for `new_parent_handle`. An authorization
+/// session for `new_parent_handle` needs to be set in the first session
+/// slot of the `context`.
+///
+/// # Returns
+/// The private and public part of the object that can be loaded
+/// under `new_parent_handle`.
+pub fn reparent(
+    context: &mut Context,
+    object_handle: ObjectHandle,
+    new_parent_handle: KeyHandle,
+) -> Result<(Private, Public)> {
+    let duplicated_object = DuplicatedObject::duplicate(
+        context,
+        object_handle,
+        new_parent_handle.into(),
+        SymmetricDefinitionObject::Null,
+    )?;
+    let public = duplicated_object.public().clone();
+    let private = duplicated_object.import(context, new_parent_handle.into())?;
+    Ok((private, public))
+}
@@ -0,0 +1,84 @@
+// Copyright 2023 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Incremental self testing
+//!
+//! A full self test of the TPM can take a considerable amount of time.
+//! This module provides a helper that only tests the algorithms that
+//! are about to be used, leaving the remaining algorithms to be tested
+//! on demand.
+use crate::{
+    constants::{return_code::TpmFormatZeroWarning, AlgorithmIdentifier},
+    error::{TpmFormatZeroResponseCode, TpmFormatZeroWarningResponseCode, TpmResponseCode},
+    structures::AlgorithmList,
+    Context, Error, Result, ReturnCode,
+};
+use std::{convert::TryFrom, thread, time::Duration};
+
+/// Makes sure that the TPM has tested the provided algorithms.
+///
+/// # Details
+/// The TPM is asked to test the `algorithms` that it has not yet tested
+/// by using [Context::incremental_self_test]. The TPM can perform the tests
+/// in the background, so the request is repeated every `retry_interval`
+/// until none of the `algorithms` remain to be tested. Responses with a
+/// `Testing` [TpmFormatZeroWarning] are retried in the same way.
+///
+/// # Arguments
+/// * `context` - The [Context] used to communicate with the TPM.
+/// * `algorithms` - The algorithms that are about to be used.
+/// * `retry_interval` - The time to wait between attempts.
+/// * `max_attempts` - The maximum number of attempts.
+///
+/// # Errors
+/// * if the algorithms have not been tested after `max_attempts` then a
+///   `Testing` [TpmFormatZeroWarning] response code is returned.
+pub fn ensure_algorithms_tested(
+    context: &mut Context,
+    algorithms: &[AlgorithmIdentifier],
+    retry_interval: Duration,
+    max_attempts: usize,
+) -> Result<()> {
+    let mut remaining = AlgorithmList::try_from(algorithms.to_vec())?;
+    for attempt in 1..=max_attempts {
+        match context.execute_without_session(|ctx| ctx.incremental_self_test(remaining.clone())) {
+            Ok(to_do_list) => {
+                remaining = AlgorithmList::try_from(
+                    remaining
+                        .into_inner()
+                        .into_iter()
+                        .filter(|algorithm| to_do_list.contains(algorithm))
+                        .collect::<Vec<AlgorithmIdentifier>>(),
+                )?;
+                if remaining.is_empty() {
+                    return Ok(());
+                }
+            }
+            Err(error) if is_testing(&error) => {}
+            Err(error) => return Err(error),
+        }
+        if attempt < max_attempts {
+            thread::sleep(retry_interval);
+        }
+    }
+    Err(testing_error())
+}
+
+/// Indicates if the error is a `Testing` warning from the TPM.
+fn is_testing(error: &Error) -> bool {
+    matches!(
+        error,
+        Error::TssError(ReturnCode::Tpm(TpmResponseCode::FormatZero(
+            TpmFormatZeroResponseCode::Warning(warning)
+        ))) if warning.error_number() == TpmFormatZeroWarning::Testing
+    )
+}
+
+/// Creates the error returned when the tests did not complete in time.
+fn testing_error() -> Error {
+    Error::tss_error(ReturnCode::Tpm(TpmResponseCode::FormatZero(
+        TpmFormatZeroResponseCode::Warning(TpmFormatZeroWarningResponseCode::from(
+            TpmFormatZeroWarning::Testing,
+        )),
+    )))
+}
@@ -0,0 +1,250 @@
+// Copyright 2023 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Module for streaming data into hash, HMAC and event sequences
+//!
+//! The sequence types implement [std::io::Write] which makes it possible to hash
+//! data that is larger than what fits in a single [MaxBuffer].
+use std::convert::TryFrom;
+
+use crate::{
+    constants::PropertyTag,
+    handles::{KeyHandle, ObjectHandle, PcrHandle},
+    interface_types::{algorithm::HashingAlgorithm, resource_handles::Hierarchy},
+    structures::{Auth, Digest, DigestValues, HashcheckTicket, MaxBuffer},
+    Context, Error, Result, WrapperErrorKind,
+};
+
+/// Get the maximum size of the data that can be sent to the TPM in one
+/// sequence update.
+pub(crate) fn max_input_buffer_size(context: &mut Context) -> Result<usize> {
+    Ok(context
+        .get_tpm_property(PropertyTag::InputBuffer)?
+        .map(usize::try_from)
+        .transpose()
+        .map_err(|_| {
+            log::error!("Failed to obtain valid maximum input buffer size");
+            Error::local_error(WrapperErrorKind::InternalError)
+        })?
+        .map_or(MaxBuffer::MAX_SIZE, |size| {
+            std::cmp::min(size, MaxBuffer::MAX_SIZE)
+        }))
+}
+
+/// Shared state of the different sequence types.
+#[derive(Debug)]
+struct Sequence<'a> {
+    context: &'a mut Context,
+    // Set to None when the sequence has been completed.
+    sequence_handle: Option<ObjectHandle>,
+    buffer_size: usize,
+}
+
+impl<'a> Sequence<'a> {
+    fn new(context: &'a mut Context, sequence_handle: ObjectHandle) -> Result<Self> {
+        let buffer_size = match max_input_buffer_size(context) {
+            Ok(buffer_size) => buffer_size,
+            Err(e) => {
+                let _ = context.flush_context(sequence_handle);
+                return Err(e);
+            }
+        };
+        Ok(Sequence {
+            context,
+            sequence_handle: Some(sequence_handle),
+            buffer_size,
+        })
+    }
+
+    fn handle(&self) -> Result<ObjectHandle> {
+        self.sequence_handle.ok_or_else(|| {
+            log::error!("The sequence has already been completed");
+            Error::local_error(WrapperErrorKind::InvalidHandleState)
+        })
+    }
+
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+        let size = std::cmp::min(self.buffer_size, buf.len());
+        let data = MaxBuffer::from_bytes(&buf[..size])
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e))?;
+        let sequence_handle = self
+            .handle()
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e))?;
+        self.context
+            .sequence_update(sequence_handle, data)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e))?;
+        Ok(size)
+    }
+}
+
+impl Drop for Sequence<'_> {
+    fn drop(&mut self) {
+        if let Some(sequence_handle) = self.sequence_handle.take() {
+            let _ = self
+                .context
+                .execute_without_session(|ctx| ctx.flush_context(sequence_handle));
+        }
+    }
+}
+
+/// Hash or HMAC sequence
+///
+/// Data written to the sequence is sent to the TPM in chunks that fit the
+/// TPM input buffer. The sequence uses the authorization sessions that are
+/// set on the context when data is written and when it is finished.
+///
+/// If the sequence is dropped without being finished the sequence object is
+/// flushed from the TPM.
+///
+/// # Example
+///
+/// ```rust
+/// # use tss_esapi::{Context, tcti_ldr::TctiNameConf,
+/// #     interface_types::{
+/// #         algorithm::HashingAlgorithm,
+/// #         resource_handles::Hierarchy,
+/// #         session_handles::AuthSession,
+/// #     },
+/// # };
+/// use std::io::Write;
+/// use tss_esapi::abstraction::sequence::HashSequence;
+/// # let mut context =
+/// #     Context::new(
+/// #         TctiNameConf::from_environment_variable().expect("Failed to get TCTI"),
+/// #     ).expect("Failed to create Context");
+/// let (digest, ticket) = context.execute_with_session(Some(AuthSession::Password), |ctx| {
+///     let mut hash_sequence = HashSequence::start(ctx, HashingAlgorithm::Sha256, None)
+///         .expect("Failed to start hash sequence");
+///     hash_sequence
+///         .write_all(&[0xa5; 4096])
+///         .expect("Failed to write data to the hash sequence");
+///     hash_sequence
+///         .finish(Hierarchy::Owner)
+///         .expect("Failed to finish hash sequence")
+/// });
+/// ```
+#[derive(Debug)]
+pub struct HashSequence<'a> {
+    sequence: Sequence<'a>,
+}
+
+impl<'a> HashSequence<'a> {
+    /// Starts a hash sequence using the provided hashing algorithm.
+    ///
+    /// The `auth` value will be the authorization value of the sequence
+    /// object.
+    pub fn start(
+        context: &'a mut Context,
+        hashing_algorithm: HashingAlgorithm,
+        auth: Option<Auth>,
+    ) -> Result<Self> {
+        if hashing_algorithm == HashingAlgorithm::Null {
+            log::error!("A hash sequence cannot use the Null hashing algorithm");
+            return Err(Error::local_error(WrapperErrorKind::InvalidParam));
+        }
+        let sequence_handle = context.hash_sequence_start(hashing_algorithm, auth)?;
+        Ok(HashSequence {
+            sequence: Sequence::new(context, sequence_handle)?,
+        })
+    }
+
+    /// Starts an HMAC sequence using the key associated with `key_handle`.
+    ///
+    /// Starting an HMAC sequence requires an authorization session for the key.
+    pub fn start_hmac(
+        context: &'a mut Context,
+        key_handle: KeyHandle,
+        hashing_algorithm: HashingAlgorithm,
+        auth: Option<Auth>,
+    ) -> Result<Self> {
+        let sequence_handle = context.hmac_start(key_handle.into(), auth, hashing_algorithm)?;
+        Ok(HashSequence {
+            sequence: Sequence::new(context, sequence_handle)?,
+        })
+    }
+
+    /// Completes the sequence.
+    ///
+    /// # Returns
+    /// The resulting [Digest] and a [HashcheckTicket] produced for the `hierarchy`.
+    /// The ticket can be used when signing the digest with a restricted signing key.
+    /// HMAC sequences always produce a `Null` ticket.
+    pub fn finish(mut self, hierarchy: Hierarchy) -> Result<(Digest, HashcheckTicket)> {
+        let sequence_handle = self.sequence.handle()?;
+        let result = self.sequence.context.sequence_complete(
+            sequence_handle,
+            MaxBuffer::default(),
+            hierarchy,
+        )?;
+        self.sequence.sequence_handle = None;
+        Ok(result)
+    }
+}
+
+impl std::io::Write for HashSequence<'_> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.sequence.write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        // Data isn't buffered
+        Ok(())
+    }
+}
+
+/// Event sequence
+///
+/// An event sequence computes digests for all the hashing algorithms
+/// implemented by the TPM and can optionally extend them into a PCR
+/// when it is finished.
+///
+/// Writing data to the sequence requires an authorization session for
+/// the sequence object. Finishing it requires an additional session for the PCR.
+#[derive(Debug)]
+pub struct EventSequence<'a> {
+    sequence: Sequence<'a>,
+}
+
+impl<'a> EventSequence<'a> {
+    /// Starts an event sequence.
+    ///
+    /// The `auth` value will be the authorization value of the sequence
+    /// object.
+    pub fn start(context: &'a mut Context, auth: Option<Auth>) -> Result<Self> {
+        let sequence_handle = context.hash_sequence_start(HashingAlgorithm::Null, auth)?;
+        Ok(EventSequence {
+            sequence: Sequence::new(context, sequence_handle)?,
+        })
+    }
+
+    /// Completes the sequence.
+    ///
+    /// If `pcr_handle` is not `None`, the resulting digests are extended into that PCR.
+    ///
+    /// # Returns
+    /// The [DigestValues] computed for each of the hashing algorithms implemented by the TPM.
+    pub fn finish(mut self, pcr_handle: Option<PcrHandle>) -> Result<DigestValues> {
+        let sequence_handle = self.sequence.handle()?;
+        let result = self.sequence.context.event_sequence_complete(
+            pcr_handle,
+            sequence_handle,
+            MaxBuffer::default(),
+        )?;
+        self.sequence.sequence_handle = None;
+        Ok(result)
+    }
+}
+
+impl std::io::Write for EventSequence<'_> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.sequence.write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        // Data isn't buffered
+        Ok(())
+    }
+}
@@ -0,0 +1,204 @@
+// Copyright 2023 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+
+//! X.509 certificates issued by the TPM
+//!
+//! TPM2_CertifyX509 signs a `TBSCertificate` of which only a part is provided by
+//! the caller. The TPM adds the fields that it is responsible for (version, serial
+//! number, signature algorithm and subject public key info) and returns them, DER
+//! encoded, together with a signature over the complete `TBSCertificate`.
+//!
+//! [PartialCertificate] holds the fields provided by the caller and [assemble_certificate]
+//! combines them with the output of the TPM into a complete [Certificate].
+use std::convert::TryFrom;
+
+use crate::{
+    structures::{MaxBuffer, Signature},
+    Error, Result, WrapperErrorKind,
+};
+use log::error;
+use picky_asn1::{
+    bit_string::BitString,
+    wrapper::{ExplicitContextTag0, ExplicitContextTag3, IntegerAsn1},
+};
+use picky_asn1_x509::{
+    AlgorithmIdentifier, Certificate, Extensions, Name, SubjectPublicKeyInfo, TbsCertificate,
+    Validity, Version,
+};
+use serde::{Deserialize, Serialize};
+
+/// The fields of a `TBSCertificate` that are provided to the TPM.
+///
+/// # Details
+/// The partial certificate does not contain a signature algorithm
+/// identifier, this is always added by the TPM based on the signing
+/// key and the signing scheme.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct PartialCertificate {
+    issuer: Name,
+    validity: Validity,
+    subject: Name,
+    extensions: ExplicitContextTag3<Extensions>,
+}
+
+impl PartialCertificate {
+    /// Creates a new partial certificate.
+    ///
+    /// # Details
+    /// The TPM requires `extensions` to be present, and if it contains
+    /// a key usage extension it has to be consistent with the attributes
+    /// of the certified object.
+    pub fn new(issuer: Name, validity: Validity, subject: Name, extensions: Extensions) -> Self {
+        PartialCertificate {
+            issuer,
+            validity,
+            subject,
+            extensions: ExplicitContextTag3(extensions),
+        }
+    }
+
+    /// Returns the issuer of the certificate
+    pub fn issuer(&self) -> &Name {
+        &self.issuer
+    }
+
+    /// Returns the validity of the certificate
+    pub fn validity(&self) -> &Validity {
+        &self.validity
+    }
+
+    /// Returns the subject of the certificate
+    pub fn subject(&self) -> &Name {
+        &self.subject
+    }
+
+    /// Returns the extensions of the certificate
+    pub fn extensions(&self) -> &Extensions {
+        &self.extensions.0
+    }
+}
+
+impl TryFrom<&PartialCertificate> for MaxBuffer {
+    type Error = Error;
+
+    /// Produces the DER encoded partial certificate expected
+    /// by [Context::certify_x509](crate::Context::certify_x509).
+    fn try_from(partial_certificate: &PartialCertificate) -> Result<Self> {
+        let encoded = picky_asn1_der::to_vec(partial_certificate).map_err(|e| {
+            error!("Failed to encode partial certificate: {}", e);
+            Error::local_error(WrapperErrorKind::InvalidParam)
+        })?;
+        MaxBuffer::try_from(encoded)
+    }
+}
+
+/// The fields of a `TBSCertificate` that are added by the TPM.
+#[derive(Deserialize, Debug)]
+struct AddedToCertificate {
+    version: ExplicitContextTag0<Version>,
+    serial_number: IntegerAsn1,
+    signature: AlgorithmIdentifier,
+    subject_public_key_info: SubjectPublicKeyInfo,
+}
+
+/// The `Ecdsa-Sig-Value` of RFC 3279.
+#[derive(Serialize, Debug)]
+struct EcdsaSignatureValue {
+    r: IntegerAsn1,
+    s: IntegerAsn1,
+}
+
+/// Certifies an object loaded in the TPM with an X.509 certificate.
+///
+/// # Arguments
+/// * `context` - The context used to certify the object.
+/// * `object_handle` - Handle of the object to be certified.
+/// * `signing_key_handle` - Handle of the key used to sign the certificate.
+/// * `signing_scheme` - Signing scheme to use if the scheme for `signing_key_handle` is `Null`.
+/// * `partial_certificate` - The fields of the certificate provided by the caller.
+///
+/// Authorization sessions for `object_handle` and `signing_key_handle` need
+/// to be set in the first and second session slots of the `context`.
+#[cfg(has_esys_certify_x509)]
+pub fn certify_x509(
+    context: &mut crate::Context,
+    object_handle: crate::handles::ObjectHandle,
+    signing_key_handle: crate::handles::KeyHandle,
+    signing_scheme: crate::structures::SignatureScheme,
+    partial_certificate: &PartialCertificate,
+) -> Result<Certificate> {
+    let (added_to_certificate, _, signature) = context.certify_x509(
+        object_handle,
+        signing_key_handle,
+        signing_scheme,
+        MaxBuffer::try_from(partial_certificate)?,
+    )?;
+    assemble_certificate(partial_certificate, &added_to_certificate, &signature)
+}
+
+/// Assembles a complete certificate from the `partial_certificate` provided
+/// to the TPM and the output of [Context::certify_x509](crate::Context::certify_x509).
+///
+/// # Details
+/// The resulting [Certificate] can be DER encoded using `picky_asn1_der`.
+///
+/// # Arguments
+/// * `partial_certificate` - The partial certificate provided to the TPM.
+/// * `added_to_certificate` - The DER encoded fields added by the TPM.
+/// * `signature` - The signature over the `TBSCertificate` produced by the TPM.
+///
+/// # Errors
+/// * if `added_to_certificate` cannot be decoded, an `InvalidParam` wrapper
+///   error will be returned.
+/// * if the signature is not an RSA or ECDSA signature, an `UnsupportedParam`
+///   wrapper error will be returned.
+pub fn assemble_certificate(
+    partial_certificate: &PartialCertificate,
+    added_to_certificate: &MaxBuffer,
+    signature: &Signature,
+) -> Result<Certificate> {
+    let added_to_certificate: AddedToCertificate =
+        picky_asn1_der::from_bytes(added_to_certificate.as_bytes()).map_err(|e| {
+            error!(
+                "Failed to decode the fields added to the certificate: {}",
+                e
+            );
+            Error::local_error(WrapperErrorKind::InvalidParam)
+        })?;
+
+    let signature_value = match signature {
+        Signature::RsaSsa(rsa_signature) | Signature::RsaPss(rsa_signature) => {
+            rsa_signature.signature().as_bytes().to_vec()
+        }
+        Signature::EcDsa(ecc_signature) => picky_asn1_der::to_vec(&EcdsaSignatureValue {
+            r: IntegerAsn1::from_bytes_be_unsigned(ecc_signature.signature_r().as_bytes().to_vec()),
+            s: IntegerAsn1::from_bytes_be_unsigned(ecc_signature.signature_s().as_bytes().to_vec()),
+        })
+        .map_err(|e| {
+            error!("Failed to encode ECDSA signature: {}", e);
+            Error::local_error(WrapperErrorKind::InvalidParam)
+        })?,
+        _ => {
+            error!(
+                "Signature algorithm {:?} is not supported in X.509 certificates",
+                signature.algorithm()
+            );
+            return Err(Error::local_error(WrapperErrorKind::UnsupportedParam));
+        }
+    };
+
+    Ok(Certificate {
+        tbs_certificate: TbsCertificate {
+            version: added_to_certificate.version,
+            serial_number: added_to_certificate.serial_number,
+            signature: added_to_certificate.signature.clone(),
+            issuer: partial_certificate.issuer.clone(),
+            validity: partial_certificate.validity.clone(),
+            subject: partial_certificate.subject.clone(),
+            subject_public_key_info: added_to_certificate.subject_public_key_info,
+            extensions: partial_certificate.extensions.clone(),
+        },
+        signature_algorithm: added_to_certificate.signature,
+        signature_value: BitString::with_bytes(signature_value).into(),
+    })
+}
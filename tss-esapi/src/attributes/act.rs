@@ -0,0 +1,30 @@
+// Copyright 2023 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+
+use bitfield::bitfield;
+
+bitfield! {
+    /// Bitfield representing the authenticated countdown timer attributes.
+    ///
+    /// # Details
+    /// This corresponds to TPMA_ACT.
+    #[derive(Copy, Clone, Eq, PartialEq)]
+    pub struct ActAttributes(u32);
+    impl Debug;
+
+    pub signaled, _: 0;
+    pub preserve_signaled, _: 1;
+    // 31:2 Reserved
+}
+
+impl From<u32> for ActAttributes {
+    fn from(tpma_act: u32) -> Self {
+        ActAttributes(tpma_act)
+    }
+}
+
+impl From<ActAttributes> for u32 {
+    fn from(act_attributes: ActAttributes) -> Self {
+        act_attributes.0
+    }
+}
@@ -0,0 +1,51 @@
+// Copyright 2023 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use crate::{
+    constants::tss::{TPM2_AT_ANY, TPM2_AT_ERROR, TPM2_AT_PV1, TPM2_AT_VEND},
+    tss2_esys::TPM_AT,
+    Error, Result, WrapperErrorKind,
+};
+use log::error;
+use std::convert::TryFrom;
+
+/// Enum representing the different attached component tag values.
+///
+/// # Details
+/// This corresponds to TPM_AT. Vendor specific tags are all
+/// values with the `TPM_AT_VEND` bit set and are represented
+/// by the `Vendor` variant holding the complete tag value.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum AttachedComponentTag {
+    Any,
+    Error,
+    Pv1,
+    Vendor(u32),
+}
+
+impl From<AttachedComponentTag> for TPM_AT {
+    fn from(attached_component_tag: AttachedComponentTag) -> TPM_AT {
+        match attached_component_tag {
+            AttachedComponentTag::Any => TPM2_AT_ANY,
+            AttachedComponentTag::Error => TPM2_AT_ERROR,
+            AttachedComponentTag::Pv1 => TPM2_AT_PV1,
+            AttachedComponentTag::Vendor(value) => value | TPM2_AT_VEND,
+        }
+    }
+}
+
+impl TryFrom<TPM_AT> for AttachedComponentTag {
+    type Error = Error;
+
+    fn try_from(tpm_at: TPM_AT) -> Result<AttachedComponentTag> {
+        match tpm_at {
+            TPM2_AT_ANY => Ok(AttachedComponentTag::Any),
+            TPM2_AT_ERROR => Ok(AttachedComponentTag::Error),
+            TPM2_AT_PV1 => Ok(AttachedComponentTag::Pv1),
+            _ if tpm_at & TPM2_AT_VEND != 0 => Ok(AttachedComponentTag::Vendor(tpm_at)),
+            _ => {
+                error!("value = {} did not match any AttachedComponentTag.", tpm_at);
+                Err(Error::local_error(WrapperErrorKind::InvalidParam))
+            }
+        }
+    }
+}
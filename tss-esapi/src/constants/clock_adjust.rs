@@ -0,0 +1,48 @@
+// Copyright 2023 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use crate::{
+    constants::tss::{
+        TPM2_CLOCK_COARSE_FASTER, TPM2_CLOCK_COARSE_SLOWER, TPM2_CLOCK_FINE_FASTER,
+        TPM2_CLOCK_FINE_SLOWER, TPM2_CLOCK_MEDIUM_FASTER, TPM2_CLOCK_MEDIUM_SLOWER,
+        TPM2_CLOCK_NO_CHANGE,
+    },
+    tss2_esys::TPM2_CLOCK_ADJUST,
+    Error, Result, WrapperErrorKind,
+};
+use log::error;
+use num_derive::{FromPrimitive, ToPrimitive};
+use num_traits::{FromPrimitive, ToPrimitive};
+use std::convert::TryFrom;
+
+/// Enum representing the different TPM clock rate adjustment values.
+#[derive(FromPrimitive, ToPrimitive, Debug, Copy, Clone, PartialEq, Eq)]
+#[repr(i8)]
+pub enum ClockAdjust {
+    CoarseSlower = TPM2_CLOCK_COARSE_SLOWER,
+    MediumSlower = TPM2_CLOCK_MEDIUM_SLOWER,
+    FineSlower = TPM2_CLOCK_FINE_SLOWER,
+    NoChange = TPM2_CLOCK_NO_CHANGE,
+    FineFaster = TPM2_CLOCK_FINE_FASTER,
+    MediumFaster = TPM2_CLOCK_MEDIUM_FASTER,
+    CoarseFaster = TPM2_CLOCK_COARSE_FASTER,
+}
+
+impl From<ClockAdjust> for TPM2_CLOCK_ADJUST {
+    fn from(clock_adjust: ClockAdjust) -> TPM2_CLOCK_ADJUST {
+        // The values are well defined so this cannot fail.
+        clock_adjust.to_i8().unwrap()
+    }
+}
+
+impl TryFrom<TPM2_CLOCK_ADJUST> for ClockAdjust {
+    type Error = Error;
+    fn try_from(tpm_clock_adjust: TPM2_CLOCK_ADJUST) -> Result<ClockAdjust> {
+        ClockAdjust::from_i8(tpm_clock_adjust).ok_or_else(|| {
+            error!(
+                "value = {} did not match any ClockAdjust.",
+                tpm_clock_adjust
+            );
+            Error::local_error(WrapperErrorKind::InvalidParam)
+        })
+    }
+}
@@ -370,6 +370,11 @@ pub const TPM2_CAP_ACT: TPM2_CAP = 0x0000000A; /* TPM2_HANDLE */
 pub const TPM2_CAP_LAST: TPM2_CAP = 0x0000000A;
 pub const TPM2_CAP_VENDOR_PROPERTY: TPM2_CAP = 0x00000100; /* manufacturer specific */
 
+pub const TPM2_AT_ANY: TPM_AT = 0x00000000; /* in a command a non-specific request for AC information in a response indicates that outputData is not meaningful */
+pub const TPM2_AT_ERROR: TPM_AT = 0x00000001; /* indicates a TCG defined device-specific error */
+pub const TPM2_AT_PV1: TPM_AT = 0x00000002; /* indicates the most significant 32 bits of a pairing value for the AC */
+pub const TPM2_AT_VEND: TPM_AT = 0x80000000; /* value added to a TPM2_AT to indicate a vendor-specific tag value */
+
 pub const TPM2_NT_ORDINARY: TPM2_NT = 0x0; /* Ordinary – contains data that is opaque to the TPM that can only be modified using TPM2_NV_Write(). */
 pub const TPM2_NT_COUNTER: TPM2_NT = 0x1; /* Counter – contains an 8-octet value that is to be used as a counter and can only be modified with TPM2_NV_Increment() */
 pub const TPM2_NT_BITS: TPM2_NT = 0x2; /* Bit Field – contains an 8-octet value to be used as a bit field and can only be modified with TPM2_NV_SetBits(). */
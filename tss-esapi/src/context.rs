@@ -3,7 +3,7 @@
 mod handle_manager;
 use crate::{
     attributes::SessionAttributesBuilder,
-    constants::{CapabilityType, PropertyTag, SessionType},
+    constants::{tss::TPM2_PT_VAR, CapabilityType, CommandCode, PropertyTag, SessionType},
     handles::{ObjectHandle, SessionHandle},
     interface_types::{algorithm::HashingAlgorithm, session_handles::AuthSession},
     structures::{CapabilityData, SymmetricDefinition},
@@ -61,6 +61,8 @@ pub struct Context {
     handle_manager: HandleManager,
     /// A cache of determined TPM limits
     cached_tpm_properties: HashMap<PropertyTag, u32>,
+    /// A cache of the commands that have been checked for support by the TPM
+    cached_supported_commands: HashMap<CommandCode, bool>,
 }
 
 // Implementation of the TPM commands
@@ -112,6 +114,7 @@ impl Context {
             _tcti_context,
             handle_manager: HandleManager::new(),
             cached_tpm_properties: HashMap::new(),
+            cached_supported_commands: HashMap::new(),
         })
     }
 
@@ -334,6 +337,9 @@ impl Context {
     /// the TPM has a value for it else None will be returned.
     /// If None is returned then use default from specification.
     ///
+    /// The values of fixed properties are cached by the context,
+    /// variable properties are retrieved from the TPM on every call.
+    ///
     /// # Errors
     /// If the TPM returns a value that is wrong when
     /// its capabilities is being retrieved then a
@@ -369,17 +375,53 @@ impl Context {
             _ => return Err(Error::WrapperError(ErrorKind::WrongValueFromTpm)),
         };
 
+        let mut value = None;
         for tagged_property in props {
-            // If we are returned a property we don't know, just ignore it
-            let _ = self
-                .cached_tpm_properties
-                .insert(tagged_property.property(), tagged_property.value());
+            if tagged_property.property() == property {
+                value = Some(tagged_property.value());
+            }
+            // Only the fixed properties are cached, the variable
+            // ones can change while the context is in use.
+            if u32::from(tagged_property.property()) < TPM2_PT_VAR {
+                let _ = self
+                    .cached_tpm_properties
+                    .insert(tagged_property.property(), tagged_property.value());
+            }
         }
+        Ok(value)
+    }
 
-        if let Some(val) = self.cached_tpm_properties.get(&property) {
-            return Ok(Some(*val));
+    /// Determine if a command is supported by the TPM
+    ///
+    /// # Details
+    /// The result is cached by the context, so the command capability
+    /// of the TPM is only retrieved the first time a command is checked.
+    ///
+    /// # Errors
+    /// If the TPM returns a value that is wrong when
+    /// its capabilities is being retrieved then a
+    /// `WrongValueFromTpm` is returned.
+    pub(crate) fn is_command_supported(&mut self, command_code: CommandCode) -> Result<bool> {
+        // Return cached value if it exists
+        if let Some(&supported) = self.cached_supported_commands.get(&command_code) {
+            return Ok(supported);
         }
-        Ok(None)
+
+        let command_code_value = TPM2_CC::from(command_code);
+        let (capabs, _) = self.execute_without_session(|ctx| {
+            ctx.get_capability(CapabilityType::Command, command_code_value, 1)
+        })?;
+
+        let supported = match capabs {
+            CapabilityData::Commands(command_code_attributes_list) => command_code_attributes_list
+                .find((command_code_value & 0xffff) as u16)
+                .is_some(),
+            _ => return Err(Error::WrapperError(ErrorKind::WrongValueFromTpm)),
+        };
+        let _ = self
+            .cached_supported_commands
+            .insert(command_code, supported);
+        Ok(supported)
     }
 
     // ////////////////////////////////////////////////////////////////////////
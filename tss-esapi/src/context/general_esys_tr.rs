@@ -6,7 +6,11 @@ use crate::{
     handles::{handle_conversion::TryIntoNotNone, TpmHandle},
     structures::Auth,
     structures::Name,
-    tss2_esys::{Esys_TR_Close, Esys_TR_FromTPMPublic, Esys_TR_GetName, Esys_TR_SetAuth},
+    tss2_esys::{
+        Esys_Free, Esys_TR_Close, Esys_TR_Deserialize, Esys_TR_FromTPMPublic, Esys_TR_GetName,
+        Esys_TR_Serialize, Esys_TR_SetAuth,
+    },
+    utils::SerializedEsysTr,
     Context, Result, ReturnCode,
 };
 use log::error;
@@ -388,6 +392,77 @@ impl Context {
         TpmHandle::try_from(tpm_handle)
     }
 
-    // Missing function: Esys_TR_Serialize
-    // Missing function: Esys_TR_Deserialize
+    /// Serializes the metadata of an ESYS object.
+    ///
+    /// # Details
+    /// The serialized metadata includes the public area and the name of the
+    /// object, but not its authorization value. It can be restored with
+    /// [Context::tr_deserialize] without communicating with the TPM.
+    ///
+    /// # Arguments
+    /// * `object_handle` - An [ObjectHandle] referring to the object that is to be serialized.
+    ///
+    /// # Returns
+    /// The [SerializedEsysTr] containing the metadata of the object.
+    pub fn tr_serialize(&mut self, object_handle: ObjectHandle) -> Result<SerializedEsysTr> {
+        let mut buffer_ptr = null_mut();
+        let mut buffer_size = 0;
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_TR_Serialize(
+                    self.mut_context(),
+                    object_handle.into(),
+                    &mut buffer_ptr,
+                    &mut buffer_size,
+                )
+            },
+            |ret| {
+                error!("Error when serializing an ESYS handle: {:#010X}", ret);
+            },
+        )?;
+        let buffer = unsafe {
+            let buffer = std::slice::from_raw_parts(buffer_ptr, buffer_size as usize).to_vec();
+            Esys_Free(buffer_ptr.cast());
+            buffer
+        };
+        Ok(SerializedEsysTr::from(buffer))
+    }
+
+    /// Restores the metadata of an ESYS object.
+    ///
+    /// # Details
+    /// The metadata is restored from the serialized data without communicating
+    /// with the TPM, so the authorization value of the object has to be set
+    /// again with [Context::tr_set_auth] if it is needed.
+    ///
+    /// The returned handle is closed, and not flushed, when the context is dropped.
+    ///
+    /// # Arguments
+    /// * `serialized_esys_tr` - The [SerializedEsysTr] created by [Context::tr_serialize].
+    ///
+    /// # Returns
+    /// A handle to the restored ESYS object.
+    pub fn tr_deserialize(
+        &mut self,
+        serialized_esys_tr: &SerializedEsysTr,
+    ) -> Result<ObjectHandle> {
+        let buffer = serialized_esys_tr.as_bytes();
+        let mut object = ObjectHandle::None.into();
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_TR_Deserialize(
+                    self.mut_context(),
+                    buffer.as_ptr(),
+                    buffer.len() as _,
+                    &mut object,
+                )
+            },
+            |ret| {
+                error!("Error when deserializing an ESYS handle: {:#010X}", ret);
+            },
+        )?;
+        self.handle_manager
+            .add_handle(object.into(), HandleDropAction::Close)?;
+        Ok(object.into())
+    }
 }
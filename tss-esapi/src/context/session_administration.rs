@@ -3,12 +3,14 @@
 use crate::{
     attributes::{SessionAttributes, SessionAttributesMask},
     handles::SessionHandle,
-    interface_types::session_handles::AuthSession,
-    tss2_esys::{Esys_TRSess_GetAttributes, Esys_TRSess_SetAttributes},
-    Context, Result, ReturnCode,
+    interface_types::session_handles::{AuthSession, HmacSession, PolicySession},
+    structures::{Nonce, SessionInfo},
+    tss2_esys::{Esys_TRSess_GetAttributes, Esys_TRSess_GetNonceTPM, Esys_TRSess_SetAttributes},
+    Context, Error, Result, ReturnCode, WrapperErrorKind,
 };
 use log::error;
-use std::convert::TryInto;
+use std::convert::{TryFrom, TryInto};
+use std::ptr::null_mut;
 
 impl Context {
     /// Set the given attributes on a given session.
@@ -51,5 +53,49 @@ impl Context {
         Ok(SessionAttributes(flags))
     }
 
-    // Missing function: Esys_TRSess_GetNonceTPM
+    /// Get the most recent nonce generated by the TPM for a session.
+    ///
+    /// # Details
+    /// The nonce is needed when computing authorizations outside of the
+    /// TPM, e.g. the signature used in [Context::policy_signed].
+    pub fn tr_sess_get_nonce_tpm(&mut self, session: AuthSession) -> Result<Nonce> {
+        let mut nonce_ptr = null_mut();
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_TRSess_GetNonceTPM(
+                    self.mut_context(),
+                    SessionHandle::from(session).into(),
+                    &mut nonce_ptr,
+                )
+            },
+            |ret| {
+                error!("Error when getting session nonce: {:#010X}", ret);
+            },
+        )?;
+        Nonce::try_from(Context::ffi_data_to_owned(nonce_ptr))
+    }
+
+    /// Get information about a session.
+    ///
+    /// # Errors
+    /// * if `session` is a password session an `InvalidParam` wrapper error is returned.
+    pub fn session_info(&mut self, session: AuthSession) -> Result<SessionInfo> {
+        let hashing_algorithm = match session {
+            AuthSession::HmacSession(HmacSession::HmacSession {
+                hashing_algorithm, ..
+            })
+            | AuthSession::PolicySession(PolicySession::PolicySession {
+                hashing_algorithm, ..
+            }) => hashing_algorithm,
+            AuthSession::Password => {
+                error!("Password sessions have no session information");
+                return Err(Error::local_error(WrapperErrorKind::InvalidParam));
+            }
+        };
+        Ok(SessionInfo {
+            hashing_algorithm,
+            attributes: self.tr_sess_get_attributes(session)?,
+            nonce_tpm: self.tr_sess_get_nonce_tpm(session)?,
+        })
+    }
 }
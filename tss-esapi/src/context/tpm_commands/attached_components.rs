@@ -1,9 +1,169 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
 use crate::Context;
+#[cfg(has_esys_ac)]
+use crate::{
+    constants::AttachedComponentTag,
+    handles::{AuthHandle, ObjectHandle, SessionHandle},
+    interface_types::{resource_handles::NvAuth, session_handles::PolicySession, YesNo},
+    structures::{AcCapabilityList, AcOutput, MaxBuffer, Name},
+    Result, ReturnCode,
+};
+#[cfg(has_esys_ac)]
+use log::error;
+#[cfg(has_esys_ac)]
+use std::{convert::TryFrom, ptr::null_mut};
 
 impl Context {
-    // Missing function: AC_GetCapability
-    // Missing function: AC_Send
-    // Missing function: Policy_AC_SendSelect
+    /// Returns the capabilities of an attached component.
+    ///
+    /// # Arguments
+    /// * `ac` - An [ObjectHandle] of the attached component. It can be retrieved
+    ///          by calling [Context::tr_from_tpm_public] with the TPM handle of
+    ///          the attached component.
+    /// * `capability` - The [AttachedComponentTag] of the first capability to return.
+    /// * `count` - The maximum number of capabilities to return.
+    ///
+    /// # Returns
+    /// The capabilities of the attached component and a boolean indicating
+    /// if there are more capabilities available.
+    #[cfg(has_esys_ac)]
+    pub fn ac_get_capability(
+        &mut self,
+        ac: ObjectHandle,
+        capability: AttachedComponentTag,
+        count: u32,
+    ) -> Result<(AcCapabilityList, bool)> {
+        let mut capability_data_ptr = null_mut();
+        let mut more_data = YesNo::No.into();
+        ReturnCode::ensure_success(
+            unsafe {
+                crate::tss2_esys::Esys_AC_GetCapability(
+                    self.mut_context(),
+                    self.optional_session_1(),
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    ac.into(),
+                    capability.into(),
+                    count,
+                    &mut more_data,
+                    &mut capability_data_ptr,
+                )
+            },
+            |ret| {
+                error!(
+                    "Error when getting attached component capabilities: {:#010X}",
+                    ret
+                );
+            },
+        )?;
+
+        Ok((
+            AcCapabilityList::try_from(Context::ffi_data_to_owned(capability_data_ptr))?,
+            YesNo::try_from(more_data)?.into(),
+        ))
+    }
+
+    /// Sends an object to an attached component.
+    ///
+    /// # Details
+    /// The sensitive area of `send_object` is protected and sent to the
+    /// attached component `ac`.
+    ///
+    /// Please beware that this method requires two authorization
+    /// session handles to be present, one for `send_object` and one
+    /// for `auth_handle`. The object is used in the DUP role, so the
+    /// session for `send_object` must be a policy session satisfying
+    /// the policy of the object, e.g. one containing
+    /// [policy_command_code](Context::policy_command_code) with
+    /// `CommandCode::AcSend` or [policy_ac_send_select](Context::policy_ac_send_select).
+    /// An object can only be sent if neither `fixed_tpm` nor `fixed_parent`
+    /// is set in its attributes.
+    ///
+    /// # Arguments
+    /// * `send_object` - An [ObjectHandle] of the object to send.
+    /// * `auth_handle` - The [NvAuth] handle that authorizes the sending of the object.
+    /// * `ac` - An [ObjectHandle] of the attached component.
+    /// * `ac_data_in` - Optional non-secret data to send along with the object.
+    ///
+    /// # Returns
+    /// The response of the attached component.
+    #[cfg(has_esys_ac)]
+    pub fn ac_send(
+        &mut self,
+        send_object: ObjectHandle,
+        auth_handle: NvAuth,
+        ac: ObjectHandle,
+        ac_data_in: MaxBuffer,
+    ) -> Result<AcOutput> {
+        let mut ac_data_out_ptr = null_mut();
+        ReturnCode::ensure_success(
+            unsafe {
+                crate::tss2_esys::Esys_AC_Send(
+                    self.mut_context(),
+                    send_object.into(),
+                    AuthHandle::from(auth_handle).into(),
+                    ac.into(),
+                    self.required_session_1()?,
+                    self.required_session_2()?,
+                    self.optional_session_3(),
+                    &ac_data_in.into(),
+                    &mut ac_data_out_ptr,
+                )
+            },
+            |ret| {
+                error!(
+                    "Error when sending object to attached component: {:#010X}",
+                    ret
+                );
+            },
+        )?;
+
+        AcOutput::try_from(Context::ffi_data_to_owned(ac_data_out_ptr))
+    }
+
+    /// Allows qualification of the sending of an object to an attached component.
+    ///
+    /// # Details
+    /// Limits the use of the policy session to [Context::ac_send] with the
+    /// specified authorization handle and attached component and, if
+    /// `include_object` is set, the specified object.
+    ///
+    /// # Arguments
+    /// * `policy_session` - The [PolicySession] that is extended.
+    /// * `object_name` - The [Name] of the object to be sent.
+    /// * `auth_handle_name` - The [Name] of the handle that authorizes the sending.
+    /// * `ac_name` - The [Name] of the attached component.
+    /// * `include_object` - Indicates if `object_name` is included in the policy digest.
+    #[cfg(has_esys_ac)]
+    pub fn policy_ac_send_select(
+        &mut self,
+        policy_session: PolicySession,
+        object_name: Name,
+        auth_handle_name: Name,
+        ac_name: Name,
+        include_object: bool,
+    ) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe {
+                crate::tss2_esys::Esys_Policy_AC_SendSelect(
+                    self.mut_context(),
+                    SessionHandle::from(policy_session).into(),
+                    self.optional_session_1(),
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    &object_name.into(),
+                    &auth_handle_name.into(),
+                    &ac_name.into(),
+                    YesNo::from(include_object).into(),
+                )
+            },
+            |ret| {
+                error!(
+                    "Error when computing policy attached component send select: {:#010X}",
+                    ret
+                );
+            },
+        )
+    }
 }
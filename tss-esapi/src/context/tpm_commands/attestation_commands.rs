@@ -1,13 +1,20 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
 use crate::{
-    handles::{KeyHandle, ObjectHandle},
-    structures::{Attest, AttestBuffer, Data, PcrSelectionList, Signature, SignatureScheme},
-    tss2_esys::{Esys_Certify, Esys_Quote},
+    handles::{KeyHandle, ObjectHandle, SessionHandle},
+    interface_types::{resource_handles::Endorsement, session_handles::AuthSession},
+    structures::{
+        Attest, AttestBuffer, CreationTicket, Data, Digest, PcrSelectionList, Signature,
+        SignatureScheme,
+    },
+    tss2_esys::{
+        Esys_Certify, Esys_CertifyCreation, Esys_GetCommandAuditDigest, Esys_GetSessionAuditDigest,
+        Esys_GetTime, Esys_Quote,
+    },
     Context, Result, ReturnCode,
 };
 use log::error;
-use std::convert::TryFrom;
+use std::convert::{TryFrom, TryInto};
 use std::ptr::null_mut;
 
 impl Context {
@@ -152,7 +159,144 @@ impl Context {
         ))
     }
 
-    // Missing function: CertifyCreation
+    /// Prove the association between an object and its creation data
+    ///
+    /// # Arguments
+    /// * `signing_key_handle` - Handle of the key used to sign the attestation buffer
+    /// * `object_handle` - Handle of the object associated with the creation data
+    /// * `qualifying_data` - Qualifying data
+    /// * `creation_hash` - Hash of the creation data produced when the object was created
+    /// * `signing_scheme` - Signing scheme to use if the scheme for `signing_key_handle` is `Null`.
+    /// * `creation_ticket` - Ticket produced when the object was created
+    ///
+    /// # Details
+    /// The `creation_hash` and `creation_ticket` are the ones returned by [Self::create()]
+    /// or [Self::create_primary()] for the object. The TPM validates the ticket before
+    /// signing an attestation containing the name of the object and the creation hash.
+    ///
+    /// Only `signing_key_handle` requires an authorization session.
+    ///
+    /// If `signing_key_handle` has the Restricted attribute set to `true` then `signing_scheme` must be
+    /// [SignatureScheme::Null].
+    ///
+    /// # Returns
+    /// The command returns a tuple consisting of:
+    /// * `attest_data` - TPM-generated attestation data.
+    /// * `signature` - Signature for the attestation data.
+    ///
+    /// # Errors
+    /// * if the qualifying data provided is too long, a `WrongParamSize` wrapper error will be returned
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use tss_esapi::{Context, TctiNameConf};
+    /// # use std::convert::TryFrom;
+    /// # use tss_esapi::{
+    /// #     abstraction::cipher::Cipher,
+    /// #     interface_types::{
+    /// #         algorithm::{HashingAlgorithm, RsaSchemeAlgorithm},
+    /// #         key_bits::RsaKeyBits,
+    /// #         resource_handles::Hierarchy,
+    /// #     },
+    /// #     structures::{RsaExponent, RsaScheme},
+    /// #     utils::{create_unrestricted_signing_rsa_public, create_restricted_decryption_rsa_public},
+    /// # };
+    /// use std::convert::TryInto;
+    /// use tss_esapi::{
+    ///     structures::{Data, SignatureScheme},
+    ///     interface_types::session_handles::AuthSession,
+    /// };
+    /// # let mut context =
+    /// #     Context::new(
+    /// #         TctiNameConf::from_environment_variable().expect("Failed to get TCTI"),
+    /// #     ).expect("Failed to create Context");
+    /// # let signing_key_pub = create_unrestricted_signing_rsa_public(
+    /// #         RsaScheme::create(RsaSchemeAlgorithm::RsaSsa, Some(HashingAlgorithm::Sha256))
+    /// #         .expect("Failed to create RSA scheme"),
+    /// #     RsaKeyBits::Rsa2048,
+    /// #     RsaExponent::default(),
+    /// # )
+    /// # .expect("Failed to create an unrestricted signing rsa public structure");
+    /// # let sign_key_handle = context
+    /// #     .execute_with_nullauth_session(|ctx| {
+    /// #         ctx.create_primary(Hierarchy::Owner, signing_key_pub, None, None, None, None)
+    /// #     })
+    /// #     .unwrap()
+    /// #     .key_handle;
+    /// # let decryption_key_pub = create_restricted_decryption_rsa_public(
+    /// #         Cipher::aes_256_cfb()
+    /// #         .try_into()
+    /// #         .expect("Failed to create symmetric object"),
+    /// #     RsaKeyBits::Rsa2048,
+    /// #     RsaExponent::default(),
+    /// # )
+    /// # .expect("Failed to create a restricted decryption rsa public structure");
+    /// let created_primary = context
+    ///     .execute_with_nullauth_session(|ctx| {
+    ///         ctx.create_primary(
+    ///             Hierarchy::Owner,
+    ///             decryption_key_pub,
+    ///             None,
+    ///             None,
+    ///             None,
+    ///             None,
+    ///         )
+    ///     })
+    ///     .expect("Failed to create primary key");
+    /// let (attest, signature) = context
+    ///     .execute_with_session(Some(AuthSession::Password), |ctx| {
+    ///         ctx.certify_creation(
+    ///             sign_key_handle,
+    ///             created_primary.key_handle.into(),
+    ///             Data::try_from(vec![0xff; 16]).unwrap(),
+    ///             created_primary.creation_hash,
+    ///             SignatureScheme::Null,
+    ///             created_primary.creation_ticket,
+    ///         )
+    ///     })
+    ///     .expect("Failed to certify creation");
+    /// ```
+    pub fn certify_creation(
+        &mut self,
+        signing_key_handle: KeyHandle,
+        object_handle: ObjectHandle,
+        qualifying_data: Data,
+        creation_hash: Digest,
+        signing_scheme: SignatureScheme,
+        creation_ticket: CreationTicket,
+    ) -> Result<(Attest, Signature)> {
+        let mut certify_info_ptr = null_mut();
+        let mut signature_ptr = null_mut();
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_CertifyCreation(
+                    self.mut_context(),
+                    signing_key_handle.into(),
+                    object_handle.into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    &qualifying_data.into(),
+                    &creation_hash.into(),
+                    &signing_scheme.into(),
+                    &creation_ticket.try_into()?,
+                    &mut certify_info_ptr,
+                    &mut signature_ptr,
+                )
+            },
+            |ret| {
+                error!("Error in certifying creation: {:#010X}", ret);
+            },
+        )?;
+
+        let certify_info = Context::ffi_data_to_owned(certify_info_ptr);
+        let signature = Context::ffi_data_to_owned(signature_ptr);
+        Ok((
+            Attest::try_from(AttestBuffer::try_from(certify_info)?)?,
+            Signature::try_from(signature)?,
+        ))
+    }
 
     /// Generate a quote on the selected PCRs
     ///
@@ -195,8 +339,304 @@ impl Context {
         ))
     }
 
-    // Missing function: GetSessionAuditDigest
-    // Missing function: GestCommandAuditDigest
-    // Missing function: GetTime
-    // Missing function: CertifyX509
+    /// Get a signed attestation of the audit digest of a session.
+    ///
+    /// # Arguments
+    /// * `signing_key_handle` - Handle of the key used to sign the attestation buffer
+    /// * `audit_session` - The session for which the audit digest is to be attested.
+    /// * `qualifying_data` - Qualifying data
+    /// * `signing_scheme` - Signing scheme to use if the scheme for `signing_key_handle` is `Null`.
+    ///
+    /// The command requires two authorization sessions, the first one for the
+    /// privacy administrator (the endorsement hierarchy) and the second one for
+    /// the `signing_key_handle`. The `audit_session` is not used for authorization.
+    ///
+    /// # Returns
+    /// The command returns a tuple consisting of:
+    /// * `attest_data` - TPM-generated attestation data containing the
+    ///                   [SessionAuditInfo](crate::structures::SessionAuditInfo).
+    /// * `signature` - Signature for the attestation data.
+    ///
+    /// The session digest can be compared to a digest computed with
+    /// [compute_audit_digest](crate::abstraction::audit::compute_audit_digest).
+    ///
+    /// # Errors
+    /// * if the qualifying data provided is too long, a `WrongParamSize` wrapper error will be returned
+    pub fn get_session_audit_digest(
+        &mut self,
+        signing_key_handle: KeyHandle,
+        audit_session: AuthSession,
+        qualifying_data: Data,
+        signing_scheme: SignatureScheme,
+    ) -> Result<(Attest, Signature)> {
+        let mut audit_info_ptr = null_mut();
+        let mut signature_ptr = null_mut();
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_GetSessionAuditDigest(
+                    self.mut_context(),
+                    ObjectHandle::from(Endorsement::Endorsement).into(),
+                    signing_key_handle.into(),
+                    SessionHandle::from(audit_session).into(),
+                    self.required_session_1()?,
+                    self.required_session_2()?,
+                    self.optional_session_3(),
+                    &qualifying_data.into(),
+                    &signing_scheme.into(),
+                    &mut audit_info_ptr,
+                    &mut signature_ptr,
+                )
+            },
+            |ret| {
+                error!("Error in getting session audit digest: {:#010X}", ret);
+            },
+        )?;
+
+        let audit_info = Context::ffi_data_to_owned(audit_info_ptr);
+        let signature = Context::ffi_data_to_owned(signature_ptr);
+        Ok((
+            Attest::try_from(AttestBuffer::try_from(audit_info)?)?,
+            Signature::try_from(signature)?,
+        ))
+    }
+
+    /// Get a signed attestation of the command audit digest.
+    ///
+    /// # Arguments
+    /// * `signing_key_handle` - Handle of the key used to sign the attestation buffer
+    /// * `qualifying_data` - Qualifying data
+    /// * `signing_scheme` - Signing scheme to use if the scheme for `signing_key_handle` is `Null`.
+    ///
+    /// The command requires two authorization sessions, the first one for the
+    /// privacy administrator (the endorsement hierarchy) and the second one for
+    /// the `signing_key_handle`.
+    ///
+    /// When `signing_key_handle` is not [KeyHandle::Null], the command audit digest
+    /// is cleared by the TPM once it has been attested.
+    ///
+    /// # Returns
+    /// The command returns a tuple consisting of:
+    /// * `attest_data` - TPM-generated attestation data containing the
+    ///                   [CommandAuditInfo](crate::structures::CommandAuditInfo).
+    /// * `signature` - Signature for the attestation data.
+    ///
+    /// The audit digest can be compared to a digest computed with
+    /// [compute_audit_digest](crate::abstraction::audit::compute_audit_digest).
+    ///
+    /// # Errors
+    /// * if the qualifying data provided is too long, a `WrongParamSize` wrapper error will be returned
+    pub fn get_command_audit_digest(
+        &mut self,
+        signing_key_handle: KeyHandle,
+        qualifying_data: Data,
+        signing_scheme: SignatureScheme,
+    ) -> Result<(Attest, Signature)> {
+        let mut audit_info_ptr = null_mut();
+        let mut signature_ptr = null_mut();
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_GetCommandAuditDigest(
+                    self.mut_context(),
+                    ObjectHandle::from(Endorsement::Endorsement).into(),
+                    signing_key_handle.into(),
+                    self.required_session_1()?,
+                    self.required_session_2()?,
+                    self.optional_session_3(),
+                    &qualifying_data.into(),
+                    &signing_scheme.into(),
+                    &mut audit_info_ptr,
+                    &mut signature_ptr,
+                )
+            },
+            |ret| {
+                error!("Error in getting command audit digest: {:#010X}", ret);
+            },
+        )?;
+
+        let audit_info = Context::ffi_data_to_owned(audit_info_ptr);
+        let signature = Context::ffi_data_to_owned(signature_ptr);
+        Ok((
+            Attest::try_from(AttestBuffer::try_from(audit_info)?)?,
+            Signature::try_from(signature)?,
+        ))
+    }
+
+    /// Get a signed attestation of the current time and clock values of the TPM.
+    ///
+    /// # Arguments
+    /// * `signing_key_handle` - Handle of the key used to sign the attestation buffer
+    /// * `qualifying_data` - Qualifying data
+    /// * `signing_scheme` - Signing scheme to use if the scheme for `signing_key_handle` is `Null`.
+    ///
+    /// The command requires two authorization sessions, the first one for the
+    /// privacy administrator (the endorsement hierarchy) and the second one for
+    /// the `signing_key_handle`.
+    ///
+    /// # Returns
+    /// The command returns a tuple consisting of:
+    /// * `attest_data` - TPM-generated attestation data containing the
+    ///                   [TimeAttestInfo](crate::structures::TimeAttestInfo).
+    /// * `signature` - Signature for the attestation data.
+    ///
+    /// # Errors
+    /// * if the qualifying data provided is too long, a `WrongParamSize` wrapper error will be returned
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use tss_esapi::{Context, TctiNameConf};
+    /// # use std::convert::TryFrom;
+    /// # use tss_esapi::{
+    /// #     interface_types::{
+    /// #         algorithm::{HashingAlgorithm, RsaSchemeAlgorithm},
+    /// #         key_bits::RsaKeyBits,
+    /// #         resource_handles::Hierarchy,
+    /// #     },
+    /// #     structures::{RsaExponent, RsaScheme},
+    /// #     utils::create_unrestricted_signing_rsa_public,
+    /// # };
+    /// use tss_esapi::{
+    ///     structures::{AttestInfo, Data, SignatureScheme},
+    ///     interface_types::session_handles::AuthSession,
+    /// };
+    /// # let mut context =
+    /// #     Context::new(
+    /// #         TctiNameConf::from_environment_variable().expect("Failed to get TCTI"),
+    /// #     ).expect("Failed to create Context");
+    /// # let signing_key_pub = create_unrestricted_signing_rsa_public(
+    /// #         RsaScheme::create(RsaSchemeAlgorithm::RsaSsa, Some(HashingAlgorithm::Sha256))
+    /// #         .expect("Failed to create RSA scheme"),
+    /// #     RsaKeyBits::Rsa2048,
+    /// #     RsaExponent::default(),
+    /// # )
+    /// # .expect("Failed to create an unrestricted signing rsa public structure");
+    /// # let sign_key_handle = context
+    /// #     .execute_with_nullauth_session(|ctx| {
+    /// #         ctx.create_primary(Hierarchy::Owner, signing_key_pub, None, None, None, None)
+    /// #     })
+    /// #     .unwrap()
+    /// #     .key_handle;
+    /// let (attest, signature) = context
+    ///     .execute_with_sessions(
+    ///         (
+    ///             Some(AuthSession::Password),
+    ///             Some(AuthSession::Password),
+    ///             None,
+    ///         ),
+    ///         |ctx| {
+    ///             ctx.get_time(
+    ///                 sign_key_handle,
+    ///                 Data::try_from(vec![0xff; 16]).unwrap(),
+    ///                 SignatureScheme::Null,
+    ///             )
+    ///         },
+    ///     )
+    ///     .expect("Failed to get time");
+    /// if let AttestInfo::Time { info } = attest.attested() {
+    ///     println!("Time: {}", info.time_info().time());
+    /// }
+    /// ```
+    pub fn get_time(
+        &mut self,
+        signing_key_handle: KeyHandle,
+        qualifying_data: Data,
+        signing_scheme: SignatureScheme,
+    ) -> Result<(Attest, Signature)> {
+        let mut time_info_ptr = null_mut();
+        let mut signature_ptr = null_mut();
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_GetTime(
+                    self.mut_context(),
+                    ObjectHandle::from(Endorsement::Endorsement).into(),
+                    signing_key_handle.into(),
+                    self.required_session_1()?,
+                    self.required_session_2()?,
+                    self.optional_session_3(),
+                    &qualifying_data.into(),
+                    &signing_scheme.into(),
+                    &mut time_info_ptr,
+                    &mut signature_ptr,
+                )
+            },
+            |ret| {
+                error!("Error in getting time: {:#010X}", ret);
+            },
+        )?;
+
+        let time_info = Context::ffi_data_to_owned(time_info_ptr);
+        let signature = Context::ffi_data_to_owned(signature_ptr);
+        Ok((
+            Attest::try_from(AttestBuffer::try_from(time_info)?)?,
+            Signature::try_from(signature)?,
+        ))
+    }
+
+    /// Prove that an object is loaded in the TPM by producing an X.509 certificate
+    ///
+    /// # Arguments
+    /// * `object_handle` - Handle of the object to be certified
+    /// * `signing_key_handle` - Handle of the key used to sign the certificate
+    /// * `signing_scheme` - Signing scheme to use if the scheme for `signing_key_handle` is `Null`.
+    /// * `partial_certificate` - DER encoded SEQUENCE containing the fields of the
+    ///                           `TBSCertificate` that are provided by the caller.
+    ///
+    /// # Details
+    /// The `partial_certificate` contains the issuer, validity, subject and extensions
+    /// of the certificate. The TPM adds the version, serial number, signature algorithm
+    /// identifier, which is derived from the signing key and `signing_scheme`, and the
+    /// subject public key info of the object, and signs the resulting `TBSCertificate`.
+    ///
+    /// Both `object_handle` and `signing_key_handle` require an authorization session.
+    ///
+    /// The [x509](crate::abstraction::x509) abstraction can be used in order to build
+    /// the partial certificate and to assemble the resulting certificate.
+    ///
+    /// # Returns
+    /// The command returns a tuple consisting of:
+    /// * `added_to_certificate` - DER encoded SEQUENCE containing the fields added by the TPM.
+    /// * `tbs_digest` - Digest of the complete `TBSCertificate`.
+    /// * `signature` - Signature over the `tbs_digest`.
+    #[cfg(has_esys_certify_x509)]
+    pub fn certify_x509(
+        &mut self,
+        object_handle: ObjectHandle,
+        signing_key_handle: KeyHandle,
+        signing_scheme: SignatureScheme,
+        partial_certificate: crate::structures::MaxBuffer,
+    ) -> Result<(crate::structures::MaxBuffer, Digest, Signature)> {
+        let mut added_to_certificate_ptr = null_mut();
+        let mut tbs_digest_ptr = null_mut();
+        let mut signature_ptr = null_mut();
+        ReturnCode::ensure_success(
+            unsafe {
+                crate::tss2_esys::Esys_CertifyX509(
+                    self.mut_context(),
+                    object_handle.into(),
+                    signing_key_handle.into(),
+                    self.required_session_1()?,
+                    self.required_session_2()?,
+                    self.optional_session_3(),
+                    &Data::default().into(),
+                    &signing_scheme.into(),
+                    &partial_certificate.into(),
+                    &mut added_to_certificate_ptr,
+                    &mut tbs_digest_ptr,
+                    &mut signature_ptr,
+                )
+            },
+            |ret| {
+                error!("Error in certifying X.509: {:#010X}", ret);
+            },
+        )?;
+
+        let added_to_certificate = Context::ffi_data_to_owned(added_to_certificate_ptr);
+        let tbs_digest = Context::ffi_data_to_owned(tbs_digest_ptr);
+        let signature = Context::ffi_data_to_owned(signature_ptr);
+        Ok((
+            crate::structures::MaxBuffer::try_from(added_to_certificate)?,
+            Digest::try_from(tbs_digest)?,
+            Signature::try_from(signature)?,
+        ))
+    }
 }
@@ -1,7 +1,59 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
 use crate::Context;
+#[cfg(has_esys_act)]
+use crate::{handles::ObjectHandle, interface_types::resource_handles::Act, Result, ReturnCode};
+#[cfg(has_esys_act)]
+use log::error;
 
 impl Context {
-    // Missing function: ACT_SetTimeout
+    /// Sets the timeout of an authenticated countdown timer (ACT).
+    ///
+    /// # Details
+    /// The timer starts counting down from `start_timeout` seconds and the
+    /// action associated with the timer is triggered when it reaches zero.
+    /// Setting `start_timeout` to zero signals the timer immediately.
+    ///
+    /// Please beware that this method requires an authorization
+    /// session handle to be present.
+    ///
+    /// # Arguments
+    /// * `act_handle` - The [Act] whose timeout is set.
+    /// * `start_timeout` - The start timeout value for the timer in seconds.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// # use tss_esapi::{Context, TctiNameConf};
+    /// use tss_esapi::interface_types::{resource_handles::Act, session_handles::AuthSession};
+    /// # let mut context =
+    /// #     Context::new(
+    /// #         TctiNameConf::from_environment_variable().expect("Failed to get TCTI"),
+    /// #     ).expect("Failed to create Context");
+    /// context
+    ///     .execute_with_session(Some(AuthSession::Password), |ctx| {
+    ///         ctx.act_set_timeout(Act::Act0, 60)
+    ///     })
+    ///     .expect("Call to act_set_timeout failed");
+    /// ```
+    #[cfg(has_esys_act)]
+    pub fn act_set_timeout(&mut self, act_handle: Act, start_timeout: u32) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe {
+                crate::tss2_esys::Esys_ACT_SetTimeout(
+                    self.mut_context(),
+                    ObjectHandle::from(act_handle).into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    start_timeout,
+                )
+            },
+            |ret| {
+                error!(
+                    "Error when setting the authenticated countdown timer timeout: {:#010X}",
+                    ret
+                );
+            },
+        )
+    }
 }
@@ -1,9 +1,148 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
-use crate::Context;
+use crate::{
+    constants::ClockAdjust,
+    handles::AuthHandle,
+    interface_types::resource_handles::Provision,
+    structures::TimeInfo,
+    tss2_esys::{Esys_ClockRateAdjust, Esys_ClockSet, Esys_ReadClock},
+    Context, Result, ReturnCode,
+};
+use log::error;
+use std::convert::TryFrom;
+use std::ptr::null_mut;
 
 impl Context {
-    // Missing function: ReadClock
-    // Missing function: ClockSet
-    // Missing function: ClockRateAdjust
+    /// Reads the current time and clock values of the TPM.
+    ///
+    /// # Returns
+    /// The current [TimeInfo] of the TPM. The `safe` value of the
+    /// contained [ClockInfo](crate::structures::ClockInfo) indicates
+    /// if the clock value could have been reported before.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use tss_esapi::{Context, TctiNameConf};
+    /// # let mut context =
+    /// #     Context::new(
+    /// #         TctiNameConf::from_environment_variable().expect("Failed to get TCTI"),
+    /// #     ).expect("Failed to create Context");
+    /// let time_info = context.read_clock().expect("Call to read_clock failed");
+    /// println!("Clock: {}", time_info.clock_info().clock());
+    /// ```
+    pub fn read_clock(&mut self) -> Result<TimeInfo> {
+        let mut current_time_ptr = null_mut();
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_ReadClock(
+                    self.mut_context(),
+                    self.optional_session_1(),
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    &mut current_time_ptr,
+                )
+            },
+            |ret| {
+                error!("Error when reading clock: {:#010X}", ret);
+            },
+        )?;
+        TimeInfo::try_from(Context::ffi_data_to_owned(current_time_ptr))
+    }
+
+    /// Sets the clock of the TPM.
+    ///
+    /// # Details
+    /// The clock can only be advanced, attempting to set
+    /// a value lower than the current clock value will fail.
+    ///
+    /// Please beware that this method requires an authorization
+    /// session handle to be present.
+    ///
+    /// # Arguments
+    /// * `auth` - The [Provision] used for authorization.
+    /// * `new_time` - The new clock value in milliseconds.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use tss_esapi::{Context, TctiNameConf};
+    /// use tss_esapi::interface_types::{
+    ///     resource_handles::Provision, session_handles::AuthSession,
+    /// };
+    /// # let mut context =
+    /// #     Context::new(
+    /// #         TctiNameConf::from_environment_variable().expect("Failed to get TCTI"),
+    /// #     ).expect("Failed to create Context");
+    /// let current_clock = context
+    ///     .read_clock()
+    ///     .expect("Call to read_clock failed")
+    ///     .clock_info()
+    ///     .clock();
+    /// context
+    ///     .execute_with_session(Some(AuthSession::Password), |ctx| {
+    ///         ctx.clock_set(Provision::Owner, current_clock + 1000)
+    ///     })
+    ///     .expect("Call to clock_set failed");
+    /// ```
+    pub fn clock_set(&mut self, auth: Provision, new_time: u64) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_ClockSet(
+                    self.mut_context(),
+                    AuthHandle::from(auth).into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    new_time,
+                )
+            },
+            |ret| {
+                error!("Error when setting clock: {:#010X}", ret);
+            },
+        )
+    }
+
+    /// Adjusts the rate of advance of the clock and timer of the TPM.
+    ///
+    /// # Details
+    /// Please beware that this method requires an authorization
+    /// session handle to be present.
+    ///
+    /// # Arguments
+    /// * `auth` - The [Provision] used for authorization.
+    /// * `rate_adjust` - The [ClockAdjust] that is to be applied.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use tss_esapi::{Context, TctiNameConf};
+    /// use tss_esapi::{
+    ///     constants::ClockAdjust,
+    ///     interface_types::{resource_handles::Provision, session_handles::AuthSession},
+    /// };
+    /// # let mut context =
+    /// #     Context::new(
+    /// #         TctiNameConf::from_environment_variable().expect("Failed to get TCTI"),
+    /// #     ).expect("Failed to create Context");
+    /// context
+    ///     .execute_with_session(Some(AuthSession::Password), |ctx| {
+    ///         ctx.clock_rate_adjust(Provision::Owner, ClockAdjust::NoChange)
+    ///     })
+    ///     .expect("Call to clock_rate_adjust failed");
+    /// ```
+    pub fn clock_rate_adjust(&mut self, auth: Provision, rate_adjust: ClockAdjust) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_ClockRateAdjust(
+                    self.mut_context(),
+                    AuthHandle::from(auth).into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    rate_adjust.into(),
+                )
+            },
+            |ret| {
+                error!("Error when adjusting clock rate: {:#010X}", ret);
+            },
+        )
+    }
 }
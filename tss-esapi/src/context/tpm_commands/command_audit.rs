@@ -1,7 +1,96 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
-use crate::Context;
+use crate::{
+    handles::AuthHandle,
+    interface_types::{algorithm::HashingAlgorithm, resource_handles::Provision},
+    structures::CommandCodeList,
+    tss2_esys::Esys_SetCommandCodeAuditStatus,
+    Context, Result, ReturnCode,
+};
+use log::error;
 
 impl Context {
-    // Missing function: SetCommandCodeAuditStatus
+    /// Changes the list of commands that are audited by the TPM.
+    ///
+    /// # Details
+    /// The command codes in `set_list` are added to the list of audited
+    /// commands and the command codes in `clear_list` are removed from it.
+    ///
+    /// If `audit_algorithm` is not [HashingAlgorithm::Null] the hashing algorithm
+    /// used for the command audit digest is changed and the lists are ignored.
+    /// Changing the algorithm clears the command audit digest.
+    ///
+    /// Please beware that this method requires an authorization
+    /// session handle to be present.
+    ///
+    /// # Arguments
+    /// * `auth` - The [Provision] used for authorization.
+    /// * `audit_algorithm` - The new hashing algorithm of the audit digest.
+    /// * `set_list` - The commands that are to be added to the list of audited commands.
+    /// * `clear_list` - The commands that are to be removed from the list of audited commands.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use tss_esapi::{Context, TctiNameConf};
+    /// # use std::convert::TryFrom;
+    /// use tss_esapi::{
+    ///     constants::CommandCode,
+    ///     interface_types::{
+    ///         algorithm::HashingAlgorithm, resource_handles::Provision,
+    ///         session_handles::AuthSession,
+    ///     },
+    ///     structures::CommandCodeList,
+    /// };
+    /// # let mut context =
+    /// #     Context::new(
+    /// #         TctiNameConf::from_environment_variable().expect("Failed to get TCTI"),
+    /// #     ).expect("Failed to create Context");
+    /// let audited_commands = CommandCodeList::try_from(vec![CommandCode::GetRandom])
+    ///     .expect("Failed to create command code list");
+    /// context
+    ///     .execute_with_session(Some(AuthSession::Password), |ctx| {
+    ///         ctx.set_command_code_audit_status(
+    ///             Provision::Owner,
+    ///             HashingAlgorithm::Null,
+    ///             audited_commands.clone(),
+    ///             CommandCodeList::new(),
+    ///         )?;
+    ///         // Stop auditing the commands again.
+    ///         ctx.set_command_code_audit_status(
+    ///             Provision::Owner,
+    ///             HashingAlgorithm::Null,
+    ///             CommandCodeList::new(),
+    ///             audited_commands,
+    ///         )
+    ///     })
+    ///     .expect("Call to set_command_code_audit_status failed");
+    /// ```
+    pub fn set_command_code_audit_status(
+        &mut self,
+        auth: Provision,
+        audit_algorithm: HashingAlgorithm,
+        set_list: CommandCodeList,
+        clear_list: CommandCodeList,
+    ) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_SetCommandCodeAuditStatus(
+                    self.mut_context(),
+                    AuthHandle::from(auth).into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    audit_algorithm.into(),
+                    &set_list.into(),
+                    &clear_list.into(),
+                )
+            },
+            |ret| {
+                error!(
+                    "Error when setting command code audit status: {:#010X}",
+                    ret
+                );
+            },
+        )
+    }
 }
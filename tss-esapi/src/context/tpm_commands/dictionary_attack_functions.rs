@@ -1,8 +1,137 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
-use crate::Context;
+use crate::{
+    handles::ObjectHandle,
+    interface_types::resource_handles::Lockout,
+    tss2_esys::{Esys_DictionaryAttackLockReset, Esys_DictionaryAttackParameters},
+    Context, Result, ReturnCode,
+};
+use log::error;
 
 impl Context {
-    // Missing function: DictionaryAttackLockReset
-    // Missing function: DictionaryAttackParameters
+    /// Cancels the effect of a TPM lockout due to a number of successive
+    /// authorization failures.
+    ///
+    /// # Details
+    /// This resets the lockout counter of the TPM to zero. If the lockout
+    /// authorization fails, the lockout authorization can not be used again
+    /// until the lockout recovery time has expired.
+    ///
+    /// Please beware that this method requires an authorization
+    /// session handle to be present.
+    ///
+    /// # Arguments
+    /// * `lock_handle` - The [Lockout] handle used for authorization.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use tss_esapi::{Context, TctiNameConf};
+    /// use tss_esapi::interface_types::{
+    ///     resource_handles::Lockout, session_handles::AuthSession,
+    /// };
+    /// # let mut context =
+    /// #     Context::new(
+    /// #         TctiNameConf::from_environment_variable().expect("Failed to get TCTI"),
+    /// #     ).expect("Failed to create Context");
+    /// context
+    ///     .execute_with_session(Some(AuthSession::Password), |ctx| {
+    ///         ctx.dictionary_attack_lock_reset(Lockout::Lockout)
+    ///     })
+    ///     .expect("Call to dictionary_attack_lock_reset failed");
+    /// ```
+    pub fn dictionary_attack_lock_reset(&mut self, lock_handle: Lockout) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_DictionaryAttackLockReset(
+                    self.mut_context(),
+                    ObjectHandle::from(lock_handle).into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                )
+            },
+            |ret| {
+                error!(
+                    "Error when resetting dictionary attack lockout: {:#010X}",
+                    ret
+                );
+            },
+        )
+    }
+
+    /// Changes the dictionary attack lockout parameters of the TPM.
+    ///
+    /// # Details
+    /// Setting `new_max_tries` to zero puts the TPM in lockout, setting
+    /// `new_recovery_time` to zero disables the dictionary attack protection
+    /// and setting `lockout_recovery` to zero means that the lockout
+    /// authorization can only be retried after a TPM reset.
+    ///
+    /// Please beware that this method requires an authorization
+    /// session handle to be present.
+    ///
+    /// # Arguments
+    /// * `lock_handle` - The [Lockout] handle used for authorization.
+    /// * `new_max_tries` - The number of authorization failures before lockout is imposed.
+    /// * `new_recovery_time` - The time in seconds before the authorization failure count
+    ///                         is decremented.
+    /// * `lockout_recovery` - The time in seconds after a failed lockout authorization
+    ///                        before the lockout authorization can be used again.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use tss_esapi::{Context, TctiNameConf, abstraction::dictionary_attack::LockoutStatus};
+    /// use tss_esapi::interface_types::{
+    ///     resource_handles::Lockout, session_handles::AuthSession,
+    /// };
+    /// # let mut context =
+    /// #     Context::new(
+    /// #         TctiNameConf::from_environment_variable().expect("Failed to get TCTI"),
+    /// #     ).expect("Failed to create Context");
+    /// # let lockout_status = LockoutStatus::read(&mut context)
+    /// #     .expect("Failed to read the lockout status");
+    /// # let (max_tries, recovery_time, lockout_recovery) = (
+    /// #     lockout_status.max_auth_fail(),
+    /// #     lockout_status.lockout_interval().as_secs() as u32,
+    /// #     lockout_status.lockout_recovery().as_secs() as u32,
+    /// # );
+    /// context
+    ///     .execute_with_session(Some(AuthSession::Password), |ctx| {
+    ///         ctx.dictionary_attack_parameters(
+    ///             Lockout::Lockout,
+    ///             max_tries,
+    ///             recovery_time,
+    ///             lockout_recovery,
+    ///         )
+    ///     })
+    ///     .expect("Call to dictionary_attack_parameters failed");
+    /// ```
+    pub fn dictionary_attack_parameters(
+        &mut self,
+        lock_handle: Lockout,
+        new_max_tries: u32,
+        new_recovery_time: u32,
+        lockout_recovery: u32,
+    ) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_DictionaryAttackParameters(
+                    self.mut_context(),
+                    ObjectHandle::from(lock_handle).into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    new_max_tries,
+                    new_recovery_time,
+                    lockout_recovery,
+                )
+            },
+            |ret| {
+                error!(
+                    "Error when setting dictionary attack parameters: {:#010X}",
+                    ret
+                );
+            },
+        )
+    }
 }
@@ -3,8 +3,8 @@
 use crate::Context;
 use crate::{
     handles::ObjectHandle,
-    structures::{Data, EncryptedSecret, Private, Public, SymmetricDefinitionObject},
-    tss2_esys::{Esys_Duplicate, Esys_Import},
+    structures::{Data, EncryptedSecret, Name, Private, Public, SymmetricDefinitionObject},
+    tss2_esys::{Esys_Duplicate, Esys_Import, Esys_Rewrap},
     Result, ReturnCode,
 };
 use log::error;
@@ -333,7 +333,66 @@ impl Context {
         ))
     }
 
-    // Missing function: Rewrap
+    /// Changes the outer wrapper of a duplicated object from one parent to another.
+    ///
+    /// # Details
+    /// This command allows the TPM to serve as a duplication authority. The
+    /// duplicate, that is protected by the seed of `old_parent`, is decrypted
+    /// and re-encrypted with a seed of `new_parent`. Only the outer wrapper
+    /// is changed, an inner wrapper is left untouched.
+    ///
+    /// # Arguments
+    /// * `old_parent` - An [ObjectHandle] of the parent that currently protects the duplicate.
+    ///                  If this is [ObjectHandle::Null] the duplicate is not encrypted.
+    /// * `new_parent` - An [ObjectHandle] of the new parent. If this is [ObjectHandle::Null]
+    ///                  the returned duplicate will not be encrypted.
+    /// * `in_duplicate` - The duplicate protected by `old_parent`.
+    /// * `name` - The [Name] of the duplicated object.
+    /// * `in_sym_seed` - The seed protected by `old_parent`.
+    ///
+    /// Please beware that this method requires an authorization
+    /// session handle to be present for `old_parent`.
+    ///
+    /// # Returns
+    /// The command returns a tuple consisting of:
+    /// * `out_duplicate` - The duplicate protected by `new_parent`.
+    /// * `out_sym_seed` - The seed protected by `new_parent`.
+    pub fn rewrap(
+        &mut self,
+        old_parent: ObjectHandle,
+        new_parent: ObjectHandle,
+        in_duplicate: Private,
+        name: Name,
+        in_sym_seed: EncryptedSecret,
+    ) -> Result<(Private, EncryptedSecret)> {
+        let mut out_duplicate_ptr = null_mut();
+        let mut out_sym_seed_ptr = null_mut();
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_Rewrap(
+                    self.mut_context(),
+                    old_parent.into(),
+                    new_parent.into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    &in_duplicate.into(),
+                    name.as_ref(),
+                    &in_sym_seed.into(),
+                    &mut out_duplicate_ptr,
+                    &mut out_sym_seed_ptr,
+                )
+            },
+            |ret| {
+                error!("Error when performing rewrap: {:#010X}", ret);
+            },
+        )?;
+
+        Ok((
+            Private::try_from(Context::ffi_data_to_owned(out_duplicate_ptr))?,
+            EncryptedSecret::try_from(Context::ffi_data_to_owned(out_sym_seed_ptr))?,
+        ))
+    }
 
     /// Import attaches imported object to a new parent.
     ///
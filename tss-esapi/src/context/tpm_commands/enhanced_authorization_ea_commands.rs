@@ -2,19 +2,20 @@
 // SPDX-License-Identifier: Apache-2.0
 use crate::{
     attributes::LocalityAttributes,
-    constants::CommandCode,
-    handles::{AuthHandle, ObjectHandle, SessionHandle},
-    interface_types::{session_handles::PolicySession, YesNo},
+    constants::{ArithmeticOperation, CommandCode},
+    handles::{AuthHandle, NvIndexHandle, ObjectHandle, SessionHandle},
+    interface_types::{resource_handles::NvAuth, session_handles::PolicySession, YesNo},
     structures::{
-        AuthTicket, Digest, DigestList, Name, Nonce, PcrSelectionList, Signature, Timeout,
+        AuthTicket, Digest, DigestList, Name, Nonce, Operand, PcrSelectionList, Signature, Timeout,
         VerifiedTicket,
     },
     tss2_esys::{
-        Esys_PolicyAuthValue, Esys_PolicyAuthorize, Esys_PolicyCommandCode, Esys_PolicyCpHash,
-        Esys_PolicyDuplicationSelect, Esys_PolicyGetDigest, Esys_PolicyLocality,
-        Esys_PolicyNameHash, Esys_PolicyNvWritten, Esys_PolicyOR, Esys_PolicyPCR,
-        Esys_PolicyPassword, Esys_PolicyPhysicalPresence, Esys_PolicySecret, Esys_PolicySigned,
-        Esys_PolicyTemplate,
+        Esys_PolicyAuthValue, Esys_PolicyAuthorize, Esys_PolicyAuthorizeNV, Esys_PolicyCommandCode,
+        Esys_PolicyCounterTimer, Esys_PolicyCpHash, Esys_PolicyDuplicationSelect,
+        Esys_PolicyGetDigest, Esys_PolicyLocality, Esys_PolicyNV, Esys_PolicyNameHash,
+        Esys_PolicyNvWritten, Esys_PolicyOR, Esys_PolicyPCR, Esys_PolicyPassword,
+        Esys_PolicyPhysicalPresence, Esys_PolicySecret, Esys_PolicySigned, Esys_PolicyTemplate,
+        Esys_PolicyTicket,
     },
     Context, Error, Result, ReturnCode, WrapperErrorKind as ErrorKind,
 };
@@ -111,7 +112,49 @@ impl Context {
         ))
     }
 
-    // Missing function: PolicyTicket
+    /// Cause the policy to include an authorization ticket
+    ///
+    /// # Details
+    /// The ticket is produced by a previous call to [Context::policy_signed]
+    /// or [Context::policy_secret] with an expiration time and allows the
+    /// authorization to be reused without the signature or secret.
+    ///
+    /// # Arguments
+    /// * `policy_session` - The [policy session][PolicySession] being extended.
+    /// * `timeout` - The [Timeout] returned together with the ticket.
+    /// * `cp_hash_a` - The command parameter hash used when the ticket was produced.
+    /// * `policy_ref` - The policy qualifier used when the ticket was produced.
+    /// * `auth_name` - The [Name] of the object that provided the authorization.
+    /// * `ticket` - The [AuthTicket] to be verified.
+    pub fn policy_ticket(
+        &mut self,
+        policy_session: PolicySession,
+        timeout: Timeout,
+        cp_hash_a: Digest,
+        policy_ref: Nonce,
+        auth_name: Name,
+        ticket: AuthTicket,
+    ) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_PolicyTicket(
+                    self.mut_context(),
+                    SessionHandle::from(policy_session).into(),
+                    self.optional_session_1(),
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    &timeout.into(),
+                    &cp_hash_a.into(),
+                    &policy_ref.into(),
+                    auth_name.as_ref(),
+                    &ticket.try_into()?,
+                )
+            },
+            |ret| {
+                error!("Error when sending policy ticket: {:#010X}", ret);
+            },
+        )
+    }
 
     /// Cause conditional gating of a policy based on an OR'd condition.
     ///
@@ -216,8 +259,89 @@ impl Context {
         )
     }
 
-    // Missing function: PolicyNV
-    // Missing function: PolicyCounterTimer
+    /// Cause conditional gating of a policy based on the contents of an NV index.
+    ///
+    /// # Details
+    /// The TPM compares the contents of the NV index at `offset` (A) with
+    /// `operand_b` (B) using `operation`.
+    ///
+    /// # Arguments
+    /// * `auth_handle` - The [NvAuth] handle used for authorizing the read of the index.
+    /// * `nv_index_handle` - The [NvIndexHandle] of the index that is compared.
+    /// * `policy_session` - The [policy session][PolicySession] being extended.
+    /// * `operand_b` - The [Operand] to compare with.
+    /// * `offset` - The octet offset in the NV index at which the comparison starts.
+    /// * `operation` - The [ArithmeticOperation] used for the comparison.
+    ///
+    /// Please beware that this method requires an authorization
+    /// session handle to be present.
+    pub fn policy_nv(
+        &mut self,
+        auth_handle: NvAuth,
+        nv_index_handle: NvIndexHandle,
+        policy_session: PolicySession,
+        operand_b: Operand,
+        offset: u16,
+        operation: ArithmeticOperation,
+    ) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_PolicyNV(
+                    self.mut_context(),
+                    AuthHandle::from(auth_handle).into(),
+                    nv_index_handle.into(),
+                    SessionHandle::from(policy_session).into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    &operand_b.into(),
+                    offset,
+                    operation.into(),
+                )
+            },
+            |ret| {
+                error!("Error when sending policy NV: {:#010X}", ret);
+            },
+        )
+    }
+
+    /// Cause conditional gating of a policy based on the time or restart count of the TPM.
+    ///
+    /// # Details
+    /// The TPM compares the marshalled `TPMS_TIME_INFO` structure at `offset` (A)
+    /// with `operand_b` (B) using `operation`.
+    ///
+    /// # Arguments
+    /// * `policy_session` - The [policy session][PolicySession] being extended.
+    /// * `operand_b` - The [Operand] to compare with.
+    /// * `offset` - The octet offset in the `TPMS_TIME_INFO` structure at which
+    ///              the comparison starts.
+    /// * `operation` - The [ArithmeticOperation] used for the comparison.
+    pub fn policy_counter_timer(
+        &mut self,
+        policy_session: PolicySession,
+        operand_b: Operand,
+        offset: u16,
+        operation: ArithmeticOperation,
+    ) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_PolicyCounterTimer(
+                    self.mut_context(),
+                    SessionHandle::from(policy_session).into(),
+                    self.optional_session_1(),
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    &operand_b.into(),
+                    offset,
+                    operation.into(),
+                )
+            },
+            |ret| {
+                error!("Error when sending policy counter timer: {:#010X}", ret);
+            },
+        )
+    }
 
     /// Cause conditional gating of a policy based on command code of authorized command.
     ///
@@ -593,5 +717,46 @@ impl Context {
             },
         )
     }
-    // Missing function: PolicyAuthorizeNV
+
+    /// Cause the policy to be authorized by a policy digest stored in an NV index.
+    ///
+    /// # Details
+    /// The policy digest of the session is compared with the digest stored
+    /// in the NV index and if they match the policy digest is replaced by
+    /// the name of the NV index.
+    ///
+    /// The NV index must have been written with a marshalled `TPMT_HA`,
+    /// i.e. the hashing algorithm of the policy followed by the policy digest,
+    /// otherwise the TPM returns an error.
+    ///
+    /// # Arguments
+    /// * `auth_handle` - The [NvAuth] handle used for authorizing the read of the index.
+    /// * `nv_index_handle` - The [NvIndexHandle] of the index containing the policy.
+    /// * `policy_session` - The [policy session][PolicySession] being extended.
+    ///
+    /// Please beware that this method requires an authorization
+    /// session handle to be present.
+    pub fn policy_authorize_nv(
+        &mut self,
+        auth_handle: NvAuth,
+        nv_index_handle: NvIndexHandle,
+        policy_session: PolicySession,
+    ) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_PolicyAuthorizeNV(
+                    self.mut_context(),
+                    AuthHandle::from(auth_handle).into(),
+                    nv_index_handle.into(),
+                    SessionHandle::from(policy_session).into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                )
+            },
+            |ret| {
+                error!("Error when sending policy authorize NV: {:#010X}", ret);
+            },
+        )
+    }
 }
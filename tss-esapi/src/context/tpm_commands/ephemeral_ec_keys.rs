@@ -1,8 +1,180 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
-use crate::Context;
+use crate::{
+    handles::KeyHandle,
+    interface_types::ecc::EccCurve,
+    structures::{EccParameter, EccPoint, SensitiveData},
+    tss2_esys::{Esys_Commit, Esys_EC_Ephemeral, TPM2B_ECC_POINT},
+    Context, Result, ReturnCode,
+};
+use log::error;
+use std::convert::TryFrom;
+use std::ptr::null_mut;
 
 impl Context {
-    // Missing function: Commit
-    // Missing function: EC_Ephemeral
+    /// Performs the first part of an ECC anonymous signing operation.
+    ///
+    /// # Arguments
+    /// * `sign_handle` - A [KeyHandle] of the ECC key that will be used in the signing operation.
+    /// * `p1` - An optional [EccPoint] on the curve of the key.
+    /// * `s2` - Optional octet array used to derive the x coordinate of a base point.
+    /// * `y2` - Optional y coordinate of the point associated with `s2`.
+    ///
+    /// # Details
+    /// The TPM generates an ephemeral key that is bound to the returned counter
+    /// and which will be used by a subsequent signing operation with a split
+    /// signing scheme (e.g. ECDAA). Either both `s2` and `y2` are provided or
+    /// none of them.
+    ///
+    /// # Returns
+    /// The points K, L and E and the counter of the commit. K and L are empty
+    /// if `s2` is not provided and E is empty if `p1` is not provided.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use tss_esapi::{
+    /// #     Context, TctiNameConf,
+    /// #     attributes::ObjectAttributesBuilder,
+    /// #     interface_types::{
+    /// #         algorithm::{HashingAlgorithm, PublicAlgorithm},
+    /// #         ecc::EccCurve,
+    /// #         resource_handles::Hierarchy,
+    /// #         session_handles::AuthSession,
+    /// #     },
+    /// #     structures::{
+    /// #         EcDaaScheme, EccPoint, EccScheme, KeyDerivationFunctionScheme, PublicBuilder,
+    /// #         PublicEccParametersBuilder,
+    /// #     },
+    /// # };
+    /// # // Create context
+    /// # let mut context =
+    /// #     Context::new(
+    /// #         TctiNameConf::from_environment_variable().expect("Failed to get TCTI"),
+    /// #     ).expect("Failed to create Context");
+    /// # context.set_sessions((Some(AuthSession::Password), None, None));
+    /// # let ecc_parms = PublicEccParametersBuilder::new()
+    /// #     .with_ecc_scheme(EccScheme::EcDaa(EcDaaScheme::new(HashingAlgorithm::Sha256, 0)))
+    /// #     .with_curve(EccCurve::BnP256)
+    /// #     .with_is_signing_key(true)
+    /// #     .with_is_decryption_key(false)
+    /// #     .with_restricted(false)
+    /// #     .with_key_derivation_function_scheme(KeyDerivationFunctionScheme::Null)
+    /// #     .build()
+    /// #     .unwrap();
+    /// # let object_attributes = ObjectAttributesBuilder::new()
+    /// #     .with_fixed_tpm(true)
+    /// #     .with_fixed_parent(true)
+    /// #     .with_sensitive_data_origin(true)
+    /// #     .with_user_with_auth(true)
+    /// #     .with_sign_encrypt(true)
+    /// #     .build()
+    /// #     .unwrap();
+    /// # let public = PublicBuilder::new()
+    /// #     .with_public_algorithm(PublicAlgorithm::Ecc)
+    /// #     .with_name_hashing_algorithm(HashingAlgorithm::Sha256)
+    /// #     .with_object_attributes(object_attributes)
+    /// #     .with_ecc_parameters(ecc_parms)
+    /// #     .with_ecc_unique_identifier(EccPoint::default())
+    /// #     .build()
+    /// #     .unwrap();
+    /// # let key_handle = context
+    /// #     .create_primary(Hierarchy::Owner, public, None, None, None, None)
+    /// #     .unwrap()
+    /// #     .key_handle;
+    /// // Commit to an ephemeral key for an ECDAA signature
+    /// let (_, _, _, counter) = context
+    ///     .commit(key_handle, None, None, None)
+    ///     .expect("Call to commit failed");
+    /// ```
+    pub fn commit(
+        &mut self,
+        sign_handle: KeyHandle,
+        p1: Option<EccPoint>,
+        s2: Option<SensitiveData>,
+        y2: Option<EccParameter>,
+    ) -> Result<(EccPoint, EccPoint, EccPoint, u16)> {
+        let mut k_ptr = null_mut();
+        let mut l_ptr = null_mut();
+        let mut e_ptr = null_mut();
+        let mut counter = 0;
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_Commit(
+                    self.mut_context(),
+                    sign_handle.into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    &p1.map(TPM2B_ECC_POINT::from).unwrap_or_default(),
+                    &s2.unwrap_or_default().into(),
+                    &y2.unwrap_or_default().into(),
+                    &mut k_ptr,
+                    &mut l_ptr,
+                    &mut e_ptr,
+                    &mut counter,
+                )
+            },
+            |ret| {
+                error!("Error when performing commit: {:#010X}", ret);
+            },
+        )?;
+        let k = Context::ffi_data_to_owned(k_ptr);
+        let l = Context::ffi_data_to_owned(l_ptr);
+        let e = Context::ffi_data_to_owned(e_ptr);
+        Ok((
+            EccPoint::try_from(k.point)?,
+            EccPoint::try_from(l.point)?,
+            EccPoint::try_from(e.point)?,
+            counter,
+        ))
+    }
+
+    /// Creates an ephemeral key for use in a two-phase key exchange protocol.
+    ///
+    /// # Arguments
+    /// * `curve` - The [EccCurve] on which the ephemeral key is created.
+    ///
+    /// # Details
+    /// The TPM returns the public part of the ephemeral key together with
+    /// the counter that is used to regenerate the private part in a
+    /// subsequent call to [Context::zgen_2phase].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use tss_esapi::{Context, TctiNameConf};
+    /// # // Create context
+    /// # let mut context =
+    /// #     Context::new(
+    /// #         TctiNameConf::from_environment_variable().expect("Failed to get TCTI"),
+    /// #     ).expect("Failed to create Context");
+    /// use tss_esapi::interface_types::ecc::EccCurve;
+    ///
+    /// let (q, counter) = context
+    ///     .ec_ephemeral(EccCurve::NistP256)
+    ///     .expect("Call to ec_ephemeral failed");
+    /// ```
+    pub fn ec_ephemeral(&mut self, curve: EccCurve) -> Result<(EccPoint, u16)> {
+        let mut q_ptr = null_mut();
+        let mut counter = 0;
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_EC_Ephemeral(
+                    self.mut_context(),
+                    self.optional_session_1(),
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    curve.into(),
+                    &mut q_ptr,
+                    &mut counter,
+                )
+            },
+            |ret| {
+                error!("Error when creating ephemeral ECC key: {:#010X}", ret);
+            },
+        )?;
+        let q = Context::ffi_data_to_owned(q_ptr);
+        Ok((EccPoint::try_from(q.point)?, counter))
+    }
 }
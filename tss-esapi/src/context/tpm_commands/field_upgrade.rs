@@ -1,9 +1,142 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
-use crate::Context;
+use crate::{
+    constants::tss::TPM2_ALG_NULL,
+    handles::{AuthHandle, KeyHandle},
+    interface_types::resource_handles::Platform,
+    structures::{Digest, HashAgile, MaxBuffer, Signature},
+    tss2_esys::{Esys_FieldUpgradeData, Esys_FieldUpgradeStart, Esys_FirmwareRead, TPMT_HA},
+    Context, Result, ReturnCode,
+};
+use log::error;
+use std::convert::{TryFrom, TryInto};
+use std::ptr::null_mut;
 
 impl Context {
-    // Missing function: FieldUpgradeStart
-    // Missing function: FieldUpgradeData
-    // Missing function: FirmwareRead
+    /// Starts a field upgrade of the TPM firmware.
+    ///
+    /// # Details
+    /// The TPM verifies that `manifest_signature` is a signature of `fu_digest`
+    /// made with the key referenced by `key_handle`. If the verification
+    /// succeeds the TPM enters field upgrade mode and accepts firmware data
+    /// through [Context::field_upgrade_data].
+    ///
+    /// Please beware that this method requires an authorization
+    /// session handle to be present.
+    ///
+    /// # Arguments
+    /// * `authorization` - The [Platform] handle used for authorization.
+    /// * `key_handle` - A [KeyHandle] of the public key used to verify the manifest signature.
+    /// * `fu_digest` - The digest of the first block of the firmware upgrade.
+    /// * `manifest_signature` - The signature of `fu_digest`.
+    pub fn field_upgrade_start(
+        &mut self,
+        authorization: Platform,
+        key_handle: KeyHandle,
+        fu_digest: Digest,
+        manifest_signature: Signature,
+    ) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_FieldUpgradeStart(
+                    self.mut_context(),
+                    AuthHandle::from(authorization).into(),
+                    key_handle.into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    &fu_digest.into(),
+                    &manifest_signature.try_into()?,
+                )
+            },
+            |ret| {
+                error!("Error when starting field upgrade: {:#010X}", ret);
+            },
+        )
+    }
+
+    /// Sends a block of firmware data to the TPM.
+    ///
+    /// # Details
+    /// The field upgrade must have been started with [Context::field_upgrade_start]
+    /// and the digest of `fu_data` must match the digest of the next expected block.
+    ///
+    /// # Arguments
+    /// * `fu_data` - The block of firmware data.
+    ///
+    /// # Returns
+    /// The digest of the next expected block, which is `None` when the field
+    /// upgrade is complete, and the digest of the first block of the upgrade.
+    pub fn field_upgrade_data(
+        &mut self,
+        fu_data: MaxBuffer,
+    ) -> Result<(Option<HashAgile>, HashAgile)> {
+        let mut next_digest_ptr = null_mut();
+        let mut first_digest_ptr = null_mut();
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_FieldUpgradeData(
+                    self.mut_context(),
+                    self.optional_session_1(),
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    &fu_data.into(),
+                    &mut next_digest_ptr,
+                    &mut first_digest_ptr,
+                )
+            },
+            |ret| {
+                error!("Error when sending field upgrade data: {:#010X}", ret);
+            },
+        )?;
+
+        let next_digest = Context::ffi_data_to_owned(next_digest_ptr);
+        let first_digest = Context::ffi_data_to_owned(first_digest_ptr);
+        Ok((
+            Self::optional_hash_agile(next_digest)?,
+            HashAgile::try_from(first_digest)?,
+        ))
+    }
+
+    /// Reads the current TPM firmware.
+    ///
+    /// # Details
+    /// The firmware is read in blocks. The first block is read
+    /// with `sequence_number` set to zero and the following blocks
+    /// are read by incrementing the `sequence_number`.
+    ///
+    /// # Arguments
+    /// * `sequence_number` - The number of the block to read.
+    ///
+    /// # Returns
+    /// The block of firmware data.
+    pub fn firmware_read(&mut self, sequence_number: u32) -> Result<MaxBuffer> {
+        let mut fu_data_ptr = null_mut();
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_FirmwareRead(
+                    self.mut_context(),
+                    self.optional_session_1(),
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    sequence_number,
+                    &mut fu_data_ptr,
+                )
+            },
+            |ret| {
+                error!("Error when reading firmware: {:#010X}", ret);
+            },
+        )?;
+        MaxBuffer::try_from(Context::ffi_data_to_owned(fu_data_ptr))
+    }
+
+    /// Private function that converts a TPMT_HA in which the
+    /// hashing algorithm can be TPM2_ALG_NULL.
+    fn optional_hash_agile(tpmt_ha: TPMT_HA) -> Result<Option<HashAgile>> {
+        if tpmt_ha.hashAlg == TPM2_ALG_NULL {
+            Ok(None)
+        } else {
+            HashAgile::try_from(tpmt_ha).map(Some)
+        }
+    }
 }
@@ -1,12 +1,375 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
-use crate::Context;
+use crate::{
+    context::handle_manager::HandleDropAction,
+    handles::{ObjectHandle, PcrHandle, TpmHandle},
+    interface_types::{algorithm::HashingAlgorithm, resource_handles::Hierarchy},
+    structures::{Auth, Digest, DigestValues, HashcheckTicket, MaxBuffer},
+    tss2_esys::{
+        Esys_EventSequenceComplete, Esys_HMAC_Start, Esys_HashSequenceStart, Esys_SequenceComplete,
+        Esys_SequenceUpdate,
+    },
+    Context, Result, ReturnCode,
+};
+use log::error;
+use std::convert::TryFrom;
+use std::ptr::null_mut;
 
 impl Context {
-    // Missing function: HMAC_Start
-    // Missing function: MAC_Start
-    // Missing function: HashSequenceStart
-    // Missing function: SequenceUpdate
-    // Missing function: SequenceComplete
-    // Missing function: EventSequenceComplete
+    /// Starts an HMAC sequence.
+    ///
+    /// # Arguments
+    /// * `handle` - A handle to a loaded keyed hash key that is going to be used
+    ///              for the HMAC computation.
+    /// * `auth` - The authorization value that will be required in order to use the
+    ///            returned sequence object.
+    /// * `hashing_algorithm` - The [HashingAlgorithm] to use for the HMAC. If the key
+    ///                         has a scheme that is not `Null` then this needs to be
+    ///                         `Null` or match the hashing algorithm of the scheme.
+    ///
+    /// # Details
+    /// This command requires an authorization session for the key.
+    ///
+    /// # Returns
+    /// An [ObjectHandle] to the HMAC sequence object. The sequence object is
+    /// flushed by the TPM when the sequence is completed using [Self::sequence_complete].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use tss_esapi::{
+    /// #     attributes::ObjectAttributesBuilder,
+    /// #     structures::{MaxBuffer, PublicKeyedHashParameters, KeyedHashScheme, PublicBuilder, Digest},
+    /// #     interface_types::{
+    /// #           resource_handles::Hierarchy,
+    /// #           algorithm::{HashingAlgorithm, PublicAlgorithm},
+    /// #     },
+    /// #     Context, tcti_ldr::TctiNameConf,
+    /// # };
+    /// # use std::convert::TryFrom;
+    /// # // Create context
+    /// # let mut context =
+    /// #     Context::new(
+    /// #         TctiNameConf::from_environment_variable().expect("Failed to get TCTI"),
+    /// #     ).expect("Failed to create Context");
+    /// # let object_attributes = ObjectAttributesBuilder::new()
+    /// #     .with_sign_encrypt(true)
+    /// #     .with_sensitive_data_origin(true)
+    /// #     .with_user_with_auth(true)
+    /// #     .build()
+    /// #     .expect("Failed to build object attributes");
+    /// # let key_pub = PublicBuilder::new()
+    /// #     .with_public_algorithm(PublicAlgorithm::KeyedHash)
+    /// #     .with_name_hashing_algorithm(HashingAlgorithm::Sha256)
+    /// #     .with_object_attributes(object_attributes)
+    /// #     .with_keyed_hash_parameters(PublicKeyedHashParameters::new(KeyedHashScheme::HMAC_SHA_256))
+    /// #     .with_keyed_hash_unique_identifier(Digest::default())
+    /// #     .build()
+    /// #     .expect("Failed to build public for the HMAC key");
+    /// let input_data = MaxBuffer::try_from("There is no spoon".as_bytes().to_vec())
+    ///     .expect("Failed to create buffer for input data.");
+    ///
+    /// let hmac = context.execute_with_nullauth_session(|ctx| {
+    ///     let key_handle = ctx
+    ///         .create_primary(Hierarchy::Owner, key_pub, None, None, None, None)?
+    ///         .key_handle;
+    ///     let sequence_handle = ctx.hmac_start(key_handle.into(), None, HashingAlgorithm::Sha256)?;
+    ///     ctx.sequence_update(sequence_handle, input_data)?;
+    ///     let (hmac, _) = ctx.sequence_complete(sequence_handle, MaxBuffer::default(), Hierarchy::Null)?;
+    ///     ctx.flush_context(key_handle.into())?;
+    ///     Ok::<_, tss_esapi::Error>(hmac)
+    /// }).expect("Failed to compute HMAC using a sequence");
+    /// ```
+    pub fn hmac_start(
+        &mut self,
+        handle: ObjectHandle,
+        auth: Option<Auth>,
+        hashing_algorithm: HashingAlgorithm,
+    ) -> Result<ObjectHandle> {
+        let mut sequence_handle = ObjectHandle::None.into();
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_HMAC_Start(
+                    self.mut_context(),
+                    handle.into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    &auth.unwrap_or_default().into(),
+                    hashing_algorithm.into(),
+                    &mut sequence_handle,
+                )
+            },
+            |ret| {
+                error!("Error failed to start HMAC sequence: {:#010X}", ret);
+            },
+        )?;
+        let sequence_handle = ObjectHandle::from(sequence_handle);
+        self.handle_manager
+            .add_handle(sequence_handle, HandleDropAction::Flush)?;
+        Ok(sequence_handle)
+    }
+
+    /// Starts a MAC sequence.
+    ///
+    /// # Arguments
+    /// * `handle` - A handle to a loaded key that is going to be used
+    ///              for the MAC computation.
+    /// * `auth` - The authorization value that will be required in order to use the
+    ///            returned sequence object.
+    /// * `mac_scheme` - The [MacSchemeAlgorithm](crate::interface_types::algorithm::MacSchemeAlgorithm)
+    ///                  to use.
+    ///
+    /// # Details
+    /// A hashing algorithm as `mac_scheme` starts an HMAC sequence, which
+    /// requires a keyed hash key, while `Cmac` starts a CMAC sequence, which
+    /// requires a symmetric cipher key. The sequence is completed using
+    /// [Context::sequence_complete].
+    ///
+    /// This command requires an authorization session for the key.
+    ///
+    /// # Returns
+    /// An [ObjectHandle] to the MAC sequence object.
+    #[cfg(has_esys_mac)]
+    pub fn mac_start(
+        &mut self,
+        handle: ObjectHandle,
+        auth: Option<Auth>,
+        mac_scheme: crate::interface_types::algorithm::MacSchemeAlgorithm,
+    ) -> Result<ObjectHandle> {
+        let mut sequence_handle = ObjectHandle::None.into();
+        ReturnCode::ensure_success(
+            unsafe {
+                crate::tss2_esys::Esys_MAC_Start(
+                    self.mut_context(),
+                    handle.into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    &auth.unwrap_or_default().into(),
+                    mac_scheme.into(),
+                    &mut sequence_handle,
+                )
+            },
+            |ret| {
+                error!("Error failed to start MAC sequence: {:#010X}", ret);
+            },
+        )?;
+        let sequence_handle = ObjectHandle::from(sequence_handle);
+        self.handle_manager
+            .add_handle(sequence_handle, HandleDropAction::Flush)?;
+        Ok(sequence_handle)
+    }
+
+    /// Starts a hash or an event sequence.
+    ///
+    /// # Arguments
+    /// * `hashing_algorithm` - The [HashingAlgorithm] to use for the hash sequence.
+    ///                         If this is `Null` then an event sequence is started.
+    /// * `auth` - The authorization value that will be required in order to use the
+    ///            returned sequence object.
+    ///
+    /// # Returns
+    /// An [ObjectHandle] to the sequence object.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use tss_esapi::{Context, tcti_ldr::TctiNameConf,
+    /// #     structures::{MaxBuffer, Ticket},
+    /// #     interface_types::{
+    /// #         algorithm::HashingAlgorithm,
+    /// #         resource_handles::Hierarchy,
+    /// #         session_handles::AuthSession,
+    /// #     },
+    /// # };
+    /// # use std::convert::TryFrom;
+    /// # // Create context
+    /// # let mut context =
+    /// #     Context::new(
+    /// #         TctiNameConf::from_environment_variable().expect("Failed to get TCTI"),
+    /// #     ).expect("Failed to create Context");
+    /// let sequence_handle = context
+    ///     .hash_sequence_start(HashingAlgorithm::Sha256, None)
+    ///     .expect("Call to hash_sequence_start failed");
+    /// let (digest, ticket) = context.execute_with_session(Some(AuthSession::Password), |ctx| {
+    ///     ctx.sequence_update(
+    ///         sequence_handle,
+    ///         MaxBuffer::try_from("There is no".as_bytes().to_vec())
+    ///             .expect("Failed to create buffer"),
+    ///     )
+    ///     .expect("Call to sequence_update failed");
+    ///     ctx.sequence_complete(
+    ///         sequence_handle,
+    ///         MaxBuffer::try_from(" spoon".as_bytes().to_vec())
+    ///             .expect("Failed to create buffer"),
+    ///         Hierarchy::Owner,
+    ///     )
+    ///     .expect("Call to sequence_complete failed")
+    /// });
+    /// let expected_hashed_data: [u8; 32] = [
+    ///     0x6b, 0x38, 0x4d, 0x2b, 0xfb, 0x0e, 0x0d, 0xfb, 0x64, 0x89, 0xdb, 0xf4, 0xf8, 0xe9,
+    ///     0xe5, 0x2f, 0x71, 0xee, 0xb1, 0x0d, 0x06, 0x4c, 0x56, 0x59, 0x70, 0xcd, 0xd9, 0x44,
+    ///     0x43, 0x18, 0x5d, 0xc1,
+    /// ];
+    /// assert_eq!(&expected_hashed_data[..], digest.as_bytes());
+    /// assert_eq!(ticket.hierarchy(), Hierarchy::Owner);
+    /// ```
+    pub fn hash_sequence_start(
+        &mut self,
+        hashing_algorithm: HashingAlgorithm,
+        auth: Option<Auth>,
+    ) -> Result<ObjectHandle> {
+        let mut sequence_handle = ObjectHandle::None.into();
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_HashSequenceStart(
+                    self.mut_context(),
+                    self.optional_session_1(),
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    &auth.unwrap_or_default().into(),
+                    hashing_algorithm.into(),
+                    &mut sequence_handle,
+                )
+            },
+            |ret| {
+                error!("Error failed to start hash sequence: {:#010X}", ret);
+            },
+        )?;
+        let sequence_handle = ObjectHandle::from(sequence_handle);
+        self.handle_manager
+            .add_handle(sequence_handle, HandleDropAction::Flush)?;
+        Ok(sequence_handle)
+    }
+
+    /// Adds data to a hash, HMAC, MAC or event sequence.
+    ///
+    /// # Arguments
+    /// * `sequence_handle` - A handle to the sequence object.
+    /// * `data` - The data that is going to be added to the sequence.
+    ///
+    /// # Details
+    /// This command requires an authorization session for the sequence object.
+    pub fn sequence_update(
+        &mut self,
+        sequence_handle: ObjectHandle,
+        data: MaxBuffer,
+    ) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_SequenceUpdate(
+                    self.mut_context(),
+                    sequence_handle.into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    &data.into(),
+                )
+            },
+            |ret| {
+                error!("Error failed to update sequence: {:#010X}", ret);
+            },
+        )
+    }
+
+    /// Adds the last part of the data to a hash, HMAC or MAC sequence
+    /// and returns the result.
+    ///
+    /// # Arguments
+    /// * `sequence_handle` - A handle to the sequence object.
+    /// * `data` - The data that is going to be added to the sequence.
+    /// * `hierarchy` - The hierarchy of the ticket for a hash sequence.
+    ///
+    /// # Details
+    /// This command requires an authorization session for the sequence object.
+    /// The sequence object is flushed by the TPM when the command succeeds.
+    ///
+    /// # Returns
+    /// A tuple containing the resulting [Digest] and a [HashcheckTicket]. The ticket
+    /// indicates if the digest can be used in a signing operation that uses a restricted
+    /// signing key. For HMAC and MAC sequences the ticket will be a `Null` ticket.
+    pub fn sequence_complete(
+        &mut self,
+        sequence_handle: ObjectHandle,
+        data: MaxBuffer,
+        hierarchy: Hierarchy,
+    ) -> Result<(Digest, HashcheckTicket)> {
+        let mut result_ptr = null_mut();
+        let mut validation_ptr = null_mut();
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_SequenceComplete(
+                    self.mut_context(),
+                    sequence_handle.into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    &data.into(),
+                    if cfg!(hierarchy_is_esys_tr) {
+                        ObjectHandle::from(hierarchy).into()
+                    } else {
+                        TpmHandle::from(hierarchy).into()
+                    },
+                    &mut result_ptr,
+                    &mut validation_ptr,
+                )
+            },
+            |ret| {
+                error!("Error failed to complete sequence: {:#010X}", ret);
+            },
+        )?;
+        self.handle_manager.set_as_flushed(sequence_handle)?;
+        Ok((
+            Digest::try_from(Context::ffi_data_to_owned(result_ptr))?,
+            HashcheckTicket::try_from(Context::ffi_data_to_owned(validation_ptr))?,
+        ))
+    }
+
+    /// Adds the last part of the data to an event sequence, extends
+    /// the result into a PCR and returns the digests.
+    ///
+    /// # Arguments
+    /// * `pcr_handle` - A [PcrHandle] to the PCR that is going to be extended.
+    /// * `sequence_handle` - A handle to the event sequence object.
+    /// * `data` - The data that is going to be added to the sequence.
+    ///
+    /// # Details
+    /// This command requires authorization sessions for both the PCR and
+    /// the sequence object. If `pcr_handle` is `None` then no PCR is extended
+    /// and the authorization for it is done using an empty password.
+    /// The sequence object is flushed by the TPM when the command succeeds.
+    ///
+    /// # Returns
+    /// The [DigestValues] computed for each of the implemented hashing algorithms.
+    pub fn event_sequence_complete(
+        &mut self,
+        pcr_handle: Option<PcrHandle>,
+        sequence_handle: ObjectHandle,
+        data: MaxBuffer,
+    ) -> Result<DigestValues> {
+        let mut results_ptr = null_mut();
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_EventSequenceComplete(
+                    self.mut_context(),
+                    pcr_handle
+                        .map(ObjectHandle::from)
+                        .unwrap_or(ObjectHandle::Null)
+                        .into(),
+                    sequence_handle.into(),
+                    self.required_session_1()?,
+                    self.required_session_2()?,
+                    self.optional_session_3(),
+                    &data.into(),
+                    &mut results_ptr,
+                )
+            },
+            |ret| {
+                error!("Error failed to complete event sequence: {:#010X}", ret);
+            },
+        )?;
+        self.handle_manager.set_as_flushed(sequence_handle)?;
+        DigestValues::try_from(Context::ffi_data_to_owned(results_ptr))
+    }
 }
@@ -2,13 +2,20 @@
 // SPDX-License-Identifier: Apache-2.0
 use crate::{
     context::handle_manager::HandleDropAction,
-    handles::{AuthHandle, KeyHandle, ObjectHandle},
-    interface_types::{resource_handles::Hierarchy, YesNo},
+    handles::{AuthHandle, KeyHandle, ObjectHandle, TpmHandle},
+    interface_types::{
+        algorithm::HashingAlgorithm,
+        resource_handles::{Enables, Hierarchy, HierarchyAuth, Platform},
+        YesNo,
+    },
     structures::{
         Auth, CreatePrimaryKeyResult, CreationData, CreationTicket, Data, Digest, PcrSelectionList,
         Public, SensitiveCreate, SensitiveData,
     },
-    tss2_esys::{Esys_Clear, Esys_ClearControl, Esys_CreatePrimary, Esys_HierarchyChangeAuth},
+    tss2_esys::{
+        Esys_ChangeEPS, Esys_ChangePPS, Esys_Clear, Esys_ClearControl, Esys_CreatePrimary,
+        Esys_HierarchyChangeAuth, Esys_HierarchyControl, Esys_SetPrimaryPolicy,
+    },
     Context, Result, ReturnCode,
 };
 use log::error;
@@ -87,10 +94,187 @@ impl Context {
         })
     }
 
-    // Missing function: HierarchyControl
-    // Missing function: SetPrimaryPolicy
-    // Missing function: ChangePPS
-    // Missing function: ChangeEPS
+    /// Enable or disable the use of a hierarchy and its associated NV storage.
+    ///
+    /// # Arguments
+    /// * `auth_handle` - The [Hierarchy] used for authorization.
+    /// * `enable` - The [Enables] that is to be enabled or disabled.
+    /// * `state` - `true` if the hierarchy is to be enabled, `false` if
+    ///             it is to be disabled.
+    ///
+    /// # Details
+    /// A hierarchy can only be enabled using the platform authorization.
+    /// The platform hierarchy can only be disabled using the platform
+    /// authorization, the other hierarchies can be disabled using the
+    /// platform authorization or their own authorization.
+    ///
+    /// A disabled hierarchy stays disabled until the next TPM reset or
+    /// restart, unless it is enabled again using the platform authorization.
+    ///
+    /// Please beware that this method requires an authorization
+    /// session handle to be present.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use tss_esapi::{Context, TctiNameConf};
+    /// use tss_esapi::interface_types::{
+    ///     resource_handles::{Enables, Hierarchy},
+    ///     session_handles::AuthSession,
+    /// };
+    /// # let mut context =
+    /// #     Context::new(
+    /// #         TctiNameConf::from_environment_variable().expect("Failed to get TCTI"),
+    /// #     ).expect("Failed to create Context");
+    /// context
+    ///     .execute_with_session(Some(AuthSession::Password), |ctx| {
+    ///         ctx.hierarchy_control(Hierarchy::Platform, Enables::Endorsement, true)
+    ///     })
+    ///     .expect("Call to hierarchy_control failed");
+    /// ```
+    pub fn hierarchy_control(
+        &mut self,
+        auth_handle: Hierarchy,
+        enable: Enables,
+        state: bool,
+    ) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_HierarchyControl(
+                    self.mut_context(),
+                    ObjectHandle::from(auth_handle).into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    if cfg!(hierarchy_is_esys_tr) {
+                        ObjectHandle::from(enable).into()
+                    } else {
+                        TpmHandle::from(enable).into()
+                    },
+                    YesNo::from(state).into(),
+                )
+            },
+            |ret| {
+                error!("Error in controlling hierarchy: {:#010X}", ret);
+            },
+        )
+    }
+
+    /// Set the authorization policy of a hierarchy.
+    ///
+    /// # Arguments
+    /// * `auth_handle` - The [HierarchyAuth] of the hierarchy whose policy is set,
+    ///                   which is also used for authorization.
+    /// * `auth_policy` - The policy digest, an empty digest removes the policy.
+    /// * `hashing_algorithm` - The [HashingAlgorithm] used to compute the policy
+    ///                         digest, this can be `Null` if `auth_policy` is empty.
+    ///
+    /// # Details
+    /// Please beware that this method requires an authorization
+    /// session handle to be present.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use tss_esapi::{Context, TctiNameConf};
+    /// use tss_esapi::{
+    ///     interface_types::{
+    ///         algorithm::HashingAlgorithm, resource_handles::HierarchyAuth,
+    ///         session_handles::AuthSession,
+    ///     },
+    ///     structures::Digest,
+    /// };
+    /// # let mut context =
+    /// #     Context::new(
+    /// #         TctiNameConf::from_environment_variable().expect("Failed to get TCTI"),
+    /// #     ).expect("Failed to create Context");
+    /// // Remove the policy of the owner hierarchy
+    /// context
+    ///     .execute_with_session(Some(AuthSession::Password), |ctx| {
+    ///         ctx.set_primary_policy(HierarchyAuth::Owner, Digest::default(), HashingAlgorithm::Null)
+    ///     })
+    ///     .expect("Call to set_primary_policy failed");
+    /// ```
+    pub fn set_primary_policy(
+        &mut self,
+        auth_handle: HierarchyAuth,
+        auth_policy: Digest,
+        hashing_algorithm: HashingAlgorithm,
+    ) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_SetPrimaryPolicy(
+                    self.mut_context(),
+                    ObjectHandle::from(auth_handle).into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    &auth_policy.into(),
+                    hashing_algorithm.into(),
+                )
+            },
+            |ret| {
+                error!("Error in setting primary policy: {:#010X}", ret);
+            },
+        )
+    }
+
+    /// Replace the platform primary seed with a new value.
+    ///
+    /// # Arguments
+    /// * `auth_handle` - The [Platform] handle used for authorization.
+    ///
+    /// # Details
+    /// All the objects in the platform hierarchy are flushed and the
+    /// platform policy is reset, primary objects created in the platform
+    /// hierarchy after this command will be different.
+    ///
+    /// Please beware that this method requires an authorization
+    /// session handle to be present.
+    pub fn change_pps(&mut self, auth_handle: Platform) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_ChangePPS(
+                    self.mut_context(),
+                    AuthHandle::from(auth_handle).into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                )
+            },
+            |ret| {
+                error!("Error in changing platform primary seed: {:#010X}", ret);
+            },
+        )
+    }
+
+    /// Replace the endorsement primary seed with a new value.
+    ///
+    /// # Arguments
+    /// * `auth_handle` - The [Platform] handle used for authorization.
+    ///
+    /// # Details
+    /// All the objects in the endorsement hierarchy are flushed and the
+    /// endorsement authorization value and policy are reset. Primary objects,
+    /// such as the endorsement key, created in the endorsement hierarchy after
+    /// this command will be different.
+    ///
+    /// Please beware that this method requires an authorization
+    /// session handle to be present.
+    pub fn change_eps(&mut self, auth_handle: Platform) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_ChangeEPS(
+                    self.mut_context(),
+                    AuthHandle::from(auth_handle).into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                )
+            },
+            |ret| {
+                error!("Error in changing endorsement primary seed: {:#010X}", ret);
+            },
+        )
+    }
 
     /// Clear all TPM context associated with a specific Owner
     pub fn clear(&mut self, auth_handle: AuthHandle) -> Result<()> {
@@ -1,9 +1,15 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
 use crate::{
-    handles::PcrHandle,
-    structures::{DigestList, DigestValues, PcrSelectionList},
-    tss2_esys::{Esys_PCR_Extend, Esys_PCR_Read, Esys_PCR_Reset},
+    handles::{AuthHandle, PcrHandle, PcrTpmHandle},
+    interface_types::{algorithm::HashingAlgorithm, resource_handles::Platform},
+    structures::{
+        Auth, Digest, DigestList, DigestValues, Event, PcrAllocationResult, PcrSelectionList,
+    },
+    tss2_esys::{
+        Esys_PCR_Allocate, Esys_PCR_Event, Esys_PCR_Extend, Esys_PCR_Read, Esys_PCR_Reset,
+        Esys_PCR_SetAuthPolicy, Esys_PCR_SetAuthValue, ESYS_TR, TPM2_HANDLE,
+    },
     Context, Result, ReturnCode,
 };
 use log::error;
@@ -105,7 +111,60 @@ impl Context {
         )
     }
 
-    // Missing function: PCR_Event
+    /// Extends a PCR with the digests of event data.
+    ///
+    /// # Arguments
+    /// * `pcr_handle`- A [PcrHandle] to the PCR slot that is to be extended.
+    /// * `event_data` - The [Event] data that is to be hashed.
+    ///
+    /// # Details
+    /// The TPM hashes the event data with the hashing algorithm of every
+    /// active PCR bank and extends the slot in each bank with the resulting
+    /// digest. The digests are returned in a [DigestValues].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use tss_esapi::{Context, TctiNameConf};
+    /// # // Create context
+    /// # let mut context =
+    /// #     Context::new(
+    /// #         TctiNameConf::from_environment_variable().expect("Failed to get TCTI"),
+    /// #     ).expect("Failed to create Context");
+    /// use std::convert::TryFrom;
+    /// use tss_esapi::{
+    ///     handles::PcrHandle,
+    ///     interface_types::session_handles::AuthSession,
+    ///     structures::Event,
+    /// };
+    /// let event_data = Event::try_from(b"measured event".to_vec())
+    ///     .expect("Failed to create event data");
+    /// let digests = context
+    ///     .execute_with_session(Some(AuthSession::Password), |ctx| {
+    ///         ctx.pcr_event(PcrHandle::Pcr16, event_data)
+    ///     })
+    ///     .expect("Call to pcr_event failed");
+    /// ```
+    pub fn pcr_event(&mut self, pcr_handle: PcrHandle, event_data: Event) -> Result<DigestValues> {
+        let mut digests_ptr = null_mut();
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_PCR_Event(
+                    self.mut_context(),
+                    pcr_handle.into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    &event_data.into(),
+                    &mut digests_ptr,
+                )
+            },
+            |ret| {
+                error!("Error when extending PCR with event: {:#010X}", ret);
+            },
+        )?;
+        DigestValues::try_from(Context::ffi_data_to_owned(digests_ptr))
+    }
 
     /// Reads the values of a PCR.
     ///
@@ -180,9 +239,173 @@ impl Context {
         ))
     }
 
-    // Missing function: PCR_Allocate
-    // Missing function: PCR_SetAuthPolicy
-    // Missing function: PCR_SetAuthValue
+    /// Sets the desired PCR allocation of the TPM.
+    ///
+    /// # Arguments
+    /// * `auth_handle` - The [Platform] handle used for authorization.
+    /// * `pcr_allocation` - A [PcrSelectionList] with the requested allocation.
+    ///
+    /// # Details
+    /// A bank is allocated with the selected PCRs if it is present in
+    /// `pcr_allocation` and it is left unchanged if it is not. In order
+    /// to deallocate a bank, e.g. the SHA-1 bank, it has to be present
+    /// with an empty selection. The new allocation takes effect after
+    /// the next TPM reset.
+    ///
+    /// If the TPM is unable to satisfy the request, the `allocation_success`
+    /// field of the returned [PcrAllocationResult] is `false` and the
+    /// remaining fields indicate what is needed.
+    ///
+    /// Please beware that this method requires an authorization
+    /// session handle to be present.
+    ///
+    /// # Example
+    ///
+    /// ```rust, no_run
+    /// # use tss_esapi::{Context, TctiNameConf};
+    /// # // Create context
+    /// # let mut context =
+    /// #     Context::new(
+    /// #         TctiNameConf::from_environment_variable().expect("Failed to get TCTI"),
+    /// #     ).expect("Failed to create Context");
+    /// use tss_esapi::{
+    ///     interface_types::{
+    ///         algorithm::HashingAlgorithm, resource_handles::Platform,
+    ///         session_handles::AuthSession,
+    ///     },
+    ///     structures::{PcrSelectionListBuilder, PcrSlot},
+    /// };
+    /// // Deallocate the SHA-1 bank and allocate all PCRs in the SHA-256 bank.
+    /// let pcr_allocation = PcrSelectionListBuilder::new()
+    ///     .with_selection(HashingAlgorithm::Sha1, &[])
+    ///     .with_selection(
+    ///         HashingAlgorithm::Sha256,
+    ///         &[
+    ///             PcrSlot::Slot0, PcrSlot::Slot1, PcrSlot::Slot2, PcrSlot::Slot3,
+    ///             PcrSlot::Slot4, PcrSlot::Slot5, PcrSlot::Slot6, PcrSlot::Slot7,
+    ///             PcrSlot::Slot8, PcrSlot::Slot9, PcrSlot::Slot10, PcrSlot::Slot11,
+    ///             PcrSlot::Slot12, PcrSlot::Slot13, PcrSlot::Slot14, PcrSlot::Slot15,
+    ///             PcrSlot::Slot16, PcrSlot::Slot17, PcrSlot::Slot18, PcrSlot::Slot19,
+    ///             PcrSlot::Slot20, PcrSlot::Slot21, PcrSlot::Slot22, PcrSlot::Slot23,
+    ///         ],
+    ///     )
+    ///     .build()
+    ///     .expect("Failed to build PcrSelectionList");
+    /// let allocation_result = context
+    ///     .execute_with_session(Some(AuthSession::Password), |ctx| {
+    ///         ctx.pcr_allocate(Platform::Platform, pcr_allocation)
+    ///     })
+    ///     .expect("Call to pcr_allocate failed");
+    /// assert!(allocation_result.allocation_success);
+    /// ```
+    pub fn pcr_allocate(
+        &mut self,
+        auth_handle: Platform,
+        pcr_allocation: PcrSelectionList,
+    ) -> Result<PcrAllocationResult> {
+        let mut allocation_success = 0;
+        let mut max_pcr = 0;
+        let mut size_needed = 0;
+        let mut size_available = 0;
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_PCR_Allocate(
+                    self.mut_context(),
+                    AuthHandle::from(auth_handle).into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    &pcr_allocation.into(),
+                    &mut allocation_success,
+                    &mut max_pcr,
+                    &mut size_needed,
+                    &mut size_available,
+                )
+            },
+            |ret| {
+                error!("Error when allocating PCR banks: {:#010X}", ret);
+            },
+        )?;
+        Ok(PcrAllocationResult {
+            allocation_success: allocation_success != 0,
+            max_pcr,
+            size_needed,
+            size_available,
+        })
+    }
+
+    /// Sets the authorization policy of a PCR.
+    ///
+    /// # Arguments
+    /// * `auth_handle` - The [Platform] handle used for authorization.
+    /// * `auth_policy` - The policy digest, an empty digest removes the policy.
+    /// * `hashing_algorithm` - The [HashingAlgorithm] used to compute the policy.
+    /// * `pcr_handle` - A [PcrHandle] to the PCR slot of which the policy is set.
+    ///
+    /// # Details
+    /// The policy is set for all PCRs in the same authorization policy
+    /// group as `pcr_handle`. Only PCRs that belong to a policy group
+    /// can be given a policy.
+    ///
+    /// Please beware that this method requires an authorization
+    /// session handle to be present.
+    pub fn pcr_set_auth_policy(
+        &mut self,
+        auth_handle: Platform,
+        auth_policy: Digest,
+        hashing_algorithm: HashingAlgorithm,
+        pcr_handle: PcrHandle,
+    ) -> Result<()> {
+        // The ESYS_TR values of the PCRs are identical to their TPM handles.
+        let pcr_num = TPM2_HANDLE::from(PcrTpmHandle::new(ESYS_TR::from(pcr_handle))?);
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_PCR_SetAuthPolicy(
+                    self.mut_context(),
+                    AuthHandle::from(auth_handle).into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    &auth_policy.into(),
+                    hashing_algorithm.into(),
+                    pcr_num,
+                )
+            },
+            |ret| {
+                error!("Error when setting PCR auth policy: {:#010X}", ret);
+            },
+        )
+    }
+
+    /// Sets the authorization value of a PCR.
+    ///
+    /// # Arguments
+    /// * `pcr_handle` - A [PcrHandle] to the PCR slot of which the auth value is set.
+    /// * `auth` - The new [Auth] value.
+    ///
+    /// # Details
+    /// The value is set for all PCRs in the same authorization value
+    /// group as `pcr_handle`.
+    ///
+    /// Please beware that this method requires an authorization
+    /// session handle to be present.
+    pub fn pcr_set_auth_value(&mut self, pcr_handle: PcrHandle, auth: Auth) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_PCR_SetAuthValue(
+                    self.mut_context(),
+                    pcr_handle.into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    &auth.into(),
+                )
+            },
+            |ret| {
+                error!("Error when setting PCR auth value: {:#010X}", ret);
+            },
+        )
+    }
 
     /// Resets the value in a PCR.
     ///
@@ -1,8 +1,111 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
-use crate::Context;
+use crate::{
+    handles::AuthHandle,
+    interface_types::resource_handles::Platform,
+    structures::CommandCodeList,
+    tss2_esys::{Esys_PP_Commands, Esys_SetAlgorithmSet},
+    Context, Result, ReturnCode,
+};
+use log::error;
 
 impl Context {
-    // Missing function: PP_Commands
-    // Missing function: SetAlgorithmSet
+    /// Changes the list of commands that require physical presence.
+    ///
+    /// # Details
+    /// The commands in `set_list` are added to the list of commands that
+    /// require the assertion of physical presence and the commands in
+    /// `clear_list` are removed from it. The TPM processes `set_list`
+    /// before `clear_list`, so commands that are in both lists end up
+    /// not requiring physical presence.
+    ///
+    /// The platform authorization must be provided together with
+    /// the assertion of physical presence.
+    ///
+    /// Please beware that this method requires an authorization
+    /// session handle to be present.
+    ///
+    /// # Arguments
+    /// * `auth_handle` - The [Platform] handle used for authorization.
+    /// * `set_list` - A [CommandCodeList] of commands that are to require physical presence.
+    /// * `clear_list` - A [CommandCodeList] of commands that are to no longer require
+    ///                  physical presence.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// # use tss_esapi::{Context, TctiNameConf};
+    /// use std::convert::TryFrom;
+    /// use tss_esapi::{
+    ///     constants::CommandCode,
+    ///     interface_types::{resource_handles::Platform, session_handles::AuthSession},
+    ///     structures::CommandCodeList,
+    /// };
+    /// # let mut context =
+    /// #     Context::new(
+    /// #         TctiNameConf::from_environment_variable().expect("Failed to get TCTI"),
+    /// #     ).expect("Failed to create Context");
+    /// let set_list = CommandCodeList::try_from(vec![CommandCode::Clear])
+    ///     .expect("Failed to create set list");
+    /// context
+    ///     .execute_with_session(Some(AuthSession::Password), |ctx| {
+    ///         ctx.pp_commands(Platform::Platform, set_list, CommandCodeList::new())
+    ///     })
+    ///     .expect("Call to pp_commands failed");
+    /// ```
+    pub fn pp_commands(
+        &mut self,
+        auth_handle: Platform,
+        set_list: CommandCodeList,
+        clear_list: CommandCodeList,
+    ) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_PP_Commands(
+                    self.mut_context(),
+                    AuthHandle::from(auth_handle).into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    &set_list.into(),
+                    &clear_list.into(),
+                )
+            },
+            |ret| {
+                error!(
+                    "Error when setting the physical presence commands: {:#010X}",
+                    ret
+                );
+            },
+        )
+    }
+
+    /// Selects the set of algorithms used by the TPM.
+    ///
+    /// # Details
+    /// The meaning of `algorithm_set` is vendor dependent. The new
+    /// algorithm set takes effect after the next TPM reset.
+    ///
+    /// Please beware that this method requires an authorization
+    /// session handle to be present.
+    ///
+    /// # Arguments
+    /// * `auth_handle` - The [Platform] handle used for authorization.
+    /// * `algorithm_set` - A vendor dependent value that selects the algorithm set.
+    pub fn set_algorithm_set(&mut self, auth_handle: Platform, algorithm_set: u32) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_SetAlgorithmSet(
+                    self.mut_context(),
+                    AuthHandle::from(auth_handle).into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    algorithm_set,
+                )
+            },
+            |ret| {
+                error!("Error when setting the algorithm set: {:#010X}", ret);
+            },
+        )
+    }
 }
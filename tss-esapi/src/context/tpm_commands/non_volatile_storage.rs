@@ -2,12 +2,16 @@
 // SPDX-License-Identifier: Apache-2.0
 use crate::{
     context::handle_manager::HandleDropAction,
-    handles::{AuthHandle, NvIndexHandle, ObjectHandle},
-    interface_types::resource_handles::{NvAuth, Provision},
-    structures::{Auth, MaxNvBuffer, Name, NvPublic},
+    handles::{AuthHandle, KeyHandle, NvIndexHandle, ObjectHandle},
+    interface_types::resource_handles::{NvAuth, Platform, Provision},
+    structures::{
+        Attest, AttestBuffer, Auth, Data, MaxNvBuffer, Name, NvPublic, Signature, SignatureScheme,
+    },
     tss2_esys::{
-        Esys_NV_DefineSpace, Esys_NV_Increment, Esys_NV_Read, Esys_NV_ReadPublic,
-        Esys_NV_UndefineSpace, Esys_NV_Write,
+        Esys_NV_Certify, Esys_NV_ChangeAuth, Esys_NV_DefineSpace, Esys_NV_Extend,
+        Esys_NV_GlobalWriteLock, Esys_NV_Increment, Esys_NV_Read, Esys_NV_ReadLock,
+        Esys_NV_ReadPublic, Esys_NV_SetBits, Esys_NV_UndefineSpace, Esys_NV_UndefineSpaceSpecial,
+        Esys_NV_Write, Esys_NV_WriteLock,
     },
     Context, Result, ReturnCode,
 };
@@ -226,7 +230,150 @@ impl Context {
         self.handle_manager.set_as_closed(nv_index_handle.into())
     }
 
-    // Missing function: UndefineSpaceSpecial
+    /// Deletes an index in the non volatile storage that has the
+    /// `policy_delete` attribute set.
+    ///
+    /// # Details
+    /// The method will instruct the TPM to remove a nv index
+    /// that can only be deleted using both the authorization policy
+    /// of the index and platform authorization.
+    ///
+    /// Please beware that this method requires two authorization
+    /// session handles to be present. The first session must be a
+    /// policy session that satisfies the authorization policy of the
+    /// nv index and the second session is used to authorize the
+    /// platform hierarchy.
+    ///
+    /// # Arguments
+    /// * `nv_index_handle`- The [NvIndexHandle] associated with
+    ///                      the nv area that is to be removed.
+    /// * `platform` - The [Platform] hierarchy used for authorization.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// # use std::convert::TryFrom;
+    /// # use tss_esapi::{
+    /// #     Context, TctiNameConf, attributes::NvIndexAttributes,
+    /// #     handles::NvIndexTpmHandle, interface_types::algorithm::HashingAlgorithm,
+    /// #     structures::{SymmetricDefinition, NvPublic},
+    /// # };
+    /// use tss_esapi::{
+    ///     constants::{CommandCode, SessionType},
+    ///     interface_types::{
+    ///         resource_handles::{Platform, Provision},
+    ///         session_handles::{AuthSession, PolicySession},
+    ///     },
+    /// };
+    ///
+    /// # // Create context
+    /// # let mut context =
+    /// #     Context::new(
+    /// #         TctiNameConf::from_environment_variable().expect("Failed to get TCTI"),
+    /// #     ).expect("Failed to create Context");
+    /// #
+    /// # // Compute the policy of the nv index in a trial session
+    /// # let trial_session = context
+    /// #     .start_auth_session(
+    /// #         None,
+    /// #         None,
+    /// #         None,
+    /// #         SessionType::Trial,
+    /// #         SymmetricDefinition::AES_256_CFB,
+    /// #         HashingAlgorithm::Sha256,
+    /// #     )
+    /// #     .expect("Failed to create trial session")
+    /// #     .expect("Received invalid handle");
+    /// # let trial_session = PolicySession::try_from(trial_session)
+    /// #     .expect("Failed to convert auth session into policy session");
+    /// # context
+    /// #     .policy_command_code(trial_session, CommandCode::NvUndefineSpaceSpecial)
+    /// #     .expect("Call to policy_command_code failed");
+    /// # let policy_digest = context
+    /// #     .policy_get_digest(trial_session)
+    /// #     .expect("Call to policy_get_digest failed");
+    /// #
+    /// # let nv_index = NvIndexTpmHandle::new(0x01500038)
+    /// #     .expect("Failed to create NV index tpm handle");
+    /// #
+    /// # // Create NV index attributes
+    /// # let platform_nv_index_attributes = NvIndexAttributes::builder()
+    /// #     .with_pp_write(true)
+    /// #     .with_pp_read(true)
+    /// #     .with_platform_create(true)
+    /// #     .with_policy_delete(true)
+    /// #     .build()
+    /// #     .expect("Failed to create platform nv index attributes");
+    /// #
+    /// # // Create platform nv public.
+    /// # let platform_nv_public = NvPublic::builder()
+    /// #     .with_nv_index(nv_index)
+    /// #     .with_index_name_algorithm(HashingAlgorithm::Sha256)
+    /// #     .with_index_attributes(platform_nv_index_attributes)
+    /// #     .with_index_auth_policy(policy_digest)
+    /// #     .with_data_area_size(32)
+    /// #     .build()
+    /// #     .expect("Failed to build NvPublic for platform");
+    /// #
+    /// let nv_index_handle = context
+    ///     .execute_with_session(Some(AuthSession::Password), |ctx| {
+    ///         ctx.nv_define_space(Provision::Platform, None, platform_nv_public)
+    ///     })
+    ///     .expect("Call to nv_define_space failed");
+    ///
+    /// // Satisfy the policy of the nv index in a policy session.
+    /// let policy_session = context
+    ///     .start_auth_session(
+    ///         None,
+    ///         None,
+    ///         None,
+    ///         SessionType::Policy,
+    ///         SymmetricDefinition::AES_256_CFB,
+    ///         HashingAlgorithm::Sha256,
+    ///     )
+    ///     .expect("Failed to create policy session")
+    ///     .expect("Received invalid handle");
+    /// let policy_session = PolicySession::try_from(policy_session)
+    ///     .expect("Failed to convert auth session into policy session");
+    /// context
+    ///     .policy_command_code(policy_session, CommandCode::NvUndefineSpaceSpecial)
+    ///     .expect("Call to policy_command_code failed");
+    ///
+    /// // The policy session authorizes the nv index and
+    /// // the password session authorizes the platform hierarchy.
+    /// context
+    ///     .execute_with_sessions(
+    ///         (
+    ///             Some(AuthSession::PolicySession(policy_session)),
+    ///             Some(AuthSession::Password),
+    ///             None,
+    ///         ),
+    ///         |ctx| ctx.nv_undefine_space_special(nv_index_handle, Platform::Platform),
+    ///     )
+    ///     .expect("Call to nv_undefine_space_special failed");
+    /// ```
+    pub fn nv_undefine_space_special(
+        &mut self,
+        nv_index_handle: NvIndexHandle,
+        platform: Platform,
+    ) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_NV_UndefineSpaceSpecial(
+                    self.mut_context(),
+                    nv_index_handle.into(),
+                    AuthHandle::from(platform).into(),
+                    self.required_session_1()?,
+                    self.required_session_2()?,
+                    self.optional_session_3(),
+                )
+            },
+            |ret| {
+                error!("Error when undefining NV space special: {:#010X}", ret);
+            },
+        )?;
+
+        self.handle_manager.set_as_closed(nv_index_handle.into())
+    }
 
     /// Reads the public part of an nv index.
     ///
@@ -556,16 +703,14 @@ impl Context {
         )
     }
 
-    // Missing function: NV_Extend
-    // Missing function: NV_SetBits
-    // Missing function: NV_WriteLock
-    // Missing function: NV_GlobalWriteLock
-
-    /// Reads data from the nv index.
+    /// Extends data into the NV memory associated with a nv index.
     ///
     /// # Details
-    /// This method is used to read a value from an area in
-    /// NV memory of the TPM.
+    /// This method is used to extend a value into an nv index
+    /// of type [Extend](crate::constants::nv_index_type::NvIndexType::Extend).
+    /// The new value of the index will be the hash, using the name algorithm
+    /// of the index, of the current value of the index concatenated with
+    /// the data.
     ///
     /// Please beware that this method requires an authorization
     /// session handle to be present.
@@ -573,9 +718,8 @@ impl Context {
     /// # Arguments
     /// * `auth_handle` - Handle indicating the source of authorization value.
     /// * `nv_index_handle` - The [NvIndexHandle] associated with NV memory
-    ///                       where data is to be written.
-    /// * `size` -  The number of octets to read.
-    /// * `offset`- Octet offset into the NV area.
+    ///                       that is to be extended.
+    /// * `data` - The data, in the form of a [MaxNvBuffer], that is to be extended.
     ///
     /// # Example
     /// ```rust
@@ -583,6 +727,7 @@ impl Context {
     /// #     Context, TctiNameConf, attributes::{SessionAttributes, NvIndexAttributes},
     /// #     handles::NvIndexTpmHandle, interface_types::algorithm::HashingAlgorithm,
     /// #     structures::{SymmetricDefinition, NvPublic}, constants::SessionType,
+    /// #     constants::nv_index_type::NvIndexType,
     /// # };
     /// use tss_esapi::{
     ///       interface_types::resource_handles::{Provision, NvAuth}, structures::MaxNvBuffer,
@@ -614,13 +759,14 @@ impl Context {
     /// #     .expect("Failed to set attributes on session");
     /// # context.set_sessions((Some(session), None, None));
     /// #
-    /// # let nv_index = NvIndexTpmHandle::new(0x01500027)
+    /// # let nv_index = NvIndexTpmHandle::new(0x01500028)
     /// #     .expect("Failed to create NV index tpm handle");
     /// #
     /// # // Create NV index attributes
     /// # let owner_nv_index_attributes = NvIndexAttributes::builder()
     /// #     .with_owner_write(true)
     /// #     .with_owner_read(true)
+    /// #     .with_nv_index_type(NvIndexType::Extend)
     /// #     .build()
     /// #     .expect("Failed to create owner nv index attributes");
     /// #
@@ -634,59 +780,858 @@ impl Context {
     /// #     .expect("Failed to build NvPublic for owner");
     /// #
     /// let data = MaxNvBuffer::try_from(vec![1, 2, 3, 4, 5, 6, 7])
-    ///    .expect("Failed to create MaxNvBuffer from vec");
+    ///     .expect("Failed to create MaxNvBuffer from vec");
     ///
     /// let nv_index_handle = context
-    ///    .nv_define_space(Provision::Owner, None, owner_nv_public)
-    ///    .expect("Call to nv_define_space failed");
+    ///     .nv_define_space(Provision::Owner, None, owner_nv_public)
+    ///     .expect("Call to nv_define_space failed");
     ///
-    /// // Write data using owner authorization
-    /// let nv_write_result = context.nv_write(NvAuth::Owner, nv_index_handle, data.clone(), 0);
+    /// let nv_extend_result = context.nv_extend(NvAuth::Owner, nv_index_handle, data);
     ///
-    /// // Read data using owner authorization
-    /// let data_len = u16::try_from(data.len()).expect("Failed to retrieve length of data");
-    /// let nv_read_result = context
-    ///     .nv_read(NvAuth::Owner, nv_index_handle, data_len, 0);
+    /// context
+    ///     .nv_undefine_space(Provision::Owner, nv_index_handle)
+    ///     .expect("Call to nv_undefine_space failed");
+    ///
+    /// // Process result
+    /// nv_extend_result.expect("Call to nv_extend failed");
+    /// ```
+    pub fn nv_extend(
+        &mut self,
+        auth_handle: NvAuth,
+        nv_index_handle: NvIndexHandle,
+        data: MaxNvBuffer,
+    ) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_NV_Extend(
+                    self.mut_context(),
+                    AuthHandle::from(auth_handle).into(),
+                    nv_index_handle.into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    &data.into(),
+                )
+            },
+            |ret| {
+                error!("Error when extending NV: {:#010X}", ret);
+            },
+        )
+    }
+
+    /// Sets bits in the NV memory associated with a nv index.
+    ///
+    /// # Details
+    /// This method is used to OR bits into an nv index
+    /// of type [Bits](crate::constants::nv_index_type::NvIndexType::Bits).
+    ///
+    /// Please beware that this method requires an authorization
+    /// session handle to be present.
+    ///
+    /// # Arguments
+    /// * `auth_handle` - Handle indicating the source of authorization value.
+    /// * `nv_index_handle` - The [NvIndexHandle] associated with NV memory
+    ///                       where the bits are to be set.
+    /// * `bits` - The bits that are to be ORed with the current value of the index.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use tss_esapi::{
+    /// #     Context, TctiNameConf, attributes::{SessionAttributes, NvIndexAttributes},
+    /// #     handles::NvIndexTpmHandle, interface_types::algorithm::HashingAlgorithm,
+    /// #     structures::{SymmetricDefinition, NvPublic}, constants::SessionType,
+    /// #     constants::nv_index_type::NvIndexType,
+    /// # };
+    /// use tss_esapi::interface_types::resource_handles::{Provision, NvAuth};
+    ///
+    /// # // Create context
+    /// # let mut context =
+    /// #     Context::new(
+    /// #         TctiNameConf::from_environment_variable().expect("Failed to get TCTI"),
+    /// #     ).expect("Failed to create Context");
+    /// #
+    /// # let session = context
+    /// #     .start_auth_session(
+    /// #         None,
+    /// #         None,
+    /// #         None,
+    /// #         SessionType::Hmac,
+    /// #         SymmetricDefinition::AES_256_CFB,
+    /// #         tss_esapi::interface_types::algorithm::HashingAlgorithm::Sha256,
+    /// #     )
+    /// #     .expect("Failed to create session")
+    /// #     .expect("Received invalid handle");
+    /// # let (session_attributes, session_attributes_mask) = SessionAttributes::builder()
+    /// #     .with_decrypt(true)
+    /// #     .with_encrypt(true)
+    /// #     .build();
+    /// # context.tr_sess_set_attributes(session, session_attributes, session_attributes_mask)
+    /// #     .expect("Failed to set attributes on session");
+    /// # context.set_sessions((Some(session), None, None));
+    /// #
+    /// # let nv_index = NvIndexTpmHandle::new(0x01500029)
+    /// #     .expect("Failed to create NV index tpm handle");
+    /// #
+    /// # // Create NV index attributes
+    /// # let owner_nv_index_attributes = NvIndexAttributes::builder()
+    /// #     .with_owner_write(true)
+    /// #     .with_owner_read(true)
+    /// #     .with_nv_index_type(NvIndexType::Bits)
+    /// #     .build()
+    /// #     .expect("Failed to create owner nv index attributes");
+    /// #
+    /// # // Create owner nv public.
+    /// # let owner_nv_public = NvPublic::builder()
+    /// #     .with_nv_index(nv_index)
+    /// #     .with_index_name_algorithm(HashingAlgorithm::Sha256)
+    /// #     .with_index_attributes(owner_nv_index_attributes)
+    /// #     .with_data_area_size(8)
+    /// #     .build()
+    /// #     .expect("Failed to build NvPublic for owner");
+    /// #
+    /// let nv_index_handle = context
+    ///     .nv_define_space(Provision::Owner, None, owner_nv_public)
+    ///     .expect("Call to nv_define_space failed");
+    ///
+    /// let nv_set_bits_result = context.nv_set_bits(NvAuth::Owner, nv_index_handle, 0b1010);
     ///
     /// context
     ///     .nv_undefine_space(Provision::Owner, nv_index_handle)
     ///     .expect("Call to nv_undefine_space failed");
     ///
     /// // Process result
-    /// nv_write_result.expect("Call to nv_write failed");
-    /// let read_data = nv_read_result.expect("Call to nv_read failed");
-    /// assert_eq!(data, read_data);
+    /// nv_set_bits_result.expect("Call to nv_set_bits failed");
     /// ```
-    pub fn nv_read(
+    pub fn nv_set_bits(
         &mut self,
         auth_handle: NvAuth,
         nv_index_handle: NvIndexHandle,
-        size: u16,
-        offset: u16,
-    ) -> Result<MaxNvBuffer> {
-        let mut data_ptr = null_mut();
+        bits: u64,
+    ) -> Result<()> {
         ReturnCode::ensure_success(
             unsafe {
-                Esys_NV_Read(
+                Esys_NV_SetBits(
                     self.mut_context(),
                     AuthHandle::from(auth_handle).into(),
                     nv_index_handle.into(),
                     self.required_session_1()?,
                     self.optional_session_2(),
                     self.optional_session_3(),
-                    size,
-                    offset,
-                    &mut data_ptr,
+                    bits,
                 )
             },
             |ret| {
-                error!("Error when reading NV: {:#010X}", ret);
+                error!("Error when setting bits in NV: {:#010X}", ret);
             },
-        )?;
-        MaxNvBuffer::try_from(Context::ffi_data_to_owned(data_ptr))
+        )
     }
 
-    // Missing function: NV_ReadLock
-    // Missing function: NV_ChangeAuth
-    // Missing function: NV_Certify
+    /// Prevents further writes to a nv index.
+    ///
+    /// # Details
+    /// This method is used to set the write lock of an nv index
+    /// that has either the `write_define` or the `write_stclear`
+    /// attribute set.
+    ///
+    /// Please beware that this method requires an authorization
+    /// session handle to be present.
+    ///
+    /// # Arguments
+    /// * `auth_handle` - Handle indicating the source of authorization value.
+    /// * `nv_index_handle` - The [NvIndexHandle] associated with NV memory
+    ///                       that is to be write locked.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use tss_esapi::{
+    /// #     Context, TctiNameConf, attributes::{SessionAttributes, NvIndexAttributes},
+    /// #     handles::NvIndexTpmHandle, interface_types::algorithm::HashingAlgorithm,
+    /// #     structures::{SymmetricDefinition, NvPublic}, constants::SessionType,
+    /// # };
+    /// use tss_esapi::interface_types::resource_handles::{Provision, NvAuth};
+    ///
+    /// # // Create context
+    /// # let mut context =
+    /// #     Context::new(
+    /// #         TctiNameConf::from_environment_variable().expect("Failed to get TCTI"),
+    /// #     ).expect("Failed to create Context");
+    /// #
+    /// # let session = context
+    /// #     .start_auth_session(
+    /// #         None,
+    /// #         None,
+    /// #         None,
+    /// #         SessionType::Hmac,
+    /// #         SymmetricDefinition::AES_256_CFB,
+    /// #         tss_esapi::interface_types::algorithm::HashingAlgorithm::Sha256,
+    /// #     )
+    /// #     .expect("Failed to create session")
+    /// #     .expect("Received invalid handle");
+    /// # let (session_attributes, session_attributes_mask) = SessionAttributes::builder()
+    /// #     .with_decrypt(true)
+    /// #     .with_encrypt(true)
+    /// #     .build();
+    /// # context.tr_sess_set_attributes(session, session_attributes, session_attributes_mask)
+    /// #     .expect("Failed to set attributes on session");
+    /// # context.set_sessions((Some(session), None, None));
+    /// #
+    /// # let nv_index = NvIndexTpmHandle::new(0x01500030)
+    /// #     .expect("Failed to create NV index tpm handle");
+    /// #
+    /// # // Create NV index attributes
+    /// # let owner_nv_index_attributes = NvIndexAttributes::builder()
+    /// #     .with_owner_write(true)
+    /// #     .with_owner_read(true)
+    /// #     .with_write_stclear(true)
+    /// #     .build()
+    /// #     .expect("Failed to create owner nv index attributes");
+    /// #
+    /// # // Create owner nv public.
+    /// # let owner_nv_public = NvPublic::builder()
+    /// #     .with_nv_index(nv_index)
+    /// #     .with_index_name_algorithm(HashingAlgorithm::Sha256)
+    /// #     .with_index_attributes(owner_nv_index_attributes)
+    /// #     .with_data_area_size(32)
+    /// #     .build()
+    /// #     .expect("Failed to build NvPublic for owner");
+    /// #
+    /// let nv_index_handle = context
+    ///     .nv_define_space(Provision::Owner, None, owner_nv_public)
+    ///     .expect("Call to nv_define_space failed");
+    ///
+    /// let nv_write_lock_result = context.nv_write_lock(NvAuth::Owner, nv_index_handle);
+    ///
+    /// context
+    ///     .nv_undefine_space(Provision::Owner, nv_index_handle)
+    ///     .expect("Call to nv_undefine_space failed");
+    ///
+    /// // Process result
+    /// nv_write_lock_result.expect("Call to nv_write_lock failed");
+    /// ```
+    pub fn nv_write_lock(
+        &mut self,
+        auth_handle: NvAuth,
+        nv_index_handle: NvIndexHandle,
+    ) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_NV_WriteLock(
+                    self.mut_context(),
+                    AuthHandle::from(auth_handle).into(),
+                    nv_index_handle.into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                )
+            },
+            |ret| {
+                error!("Error when write locking NV: {:#010X}", ret);
+            },
+        )
+    }
+
+    /// Prevents further writes to all nv indices that have
+    /// the `global_lock` attribute set.
+    ///
+    /// # Details
+    /// The write lock of the affected nv indices is cleared
+    /// on the next TPM Reset or TPM Restart.
+    ///
+    /// Please beware that this method requires an authorization
+    /// session handle to be present.
+    ///
+    /// # Arguments
+    /// * `nv_auth` - The [Provision] used for authorization.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use tss_esapi::{
+    /// #     Context, TctiNameConf, attributes::SessionAttributes, constants::SessionType,
+    /// #     structures::SymmetricDefinition,
+    /// # };
+    /// use tss_esapi::interface_types::resource_handles::Provision;
+    ///
+    /// # // Create context
+    /// # let mut context =
+    /// #     Context::new(
+    /// #         TctiNameConf::from_environment_variable().expect("Failed to get TCTI"),
+    /// #     ).expect("Failed to create Context");
+    /// #
+    /// # let session = context
+    /// #     .start_auth_session(
+    /// #         None,
+    /// #         None,
+    /// #         None,
+    /// #         SessionType::Hmac,
+    /// #         SymmetricDefinition::AES_256_CFB,
+    /// #         tss_esapi::interface_types::algorithm::HashingAlgorithm::Sha256,
+    /// #     )
+    /// #     .expect("Failed to create session")
+    /// #     .expect("Received invalid handle");
+    /// # let (session_attributes, session_attributes_mask) = SessionAttributes::builder()
+    /// #     .with_decrypt(true)
+    /// #     .with_encrypt(true)
+    /// #     .build();
+    /// # context.tr_sess_set_attributes(session, session_attributes, session_attributes_mask)
+    /// #     .expect("Failed to set attributes on session");
+    /// # context.set_sessions((Some(session), None, None));
+    /// #
+    /// context
+    ///     .nv_global_write_lock(Provision::Owner)
+    ///     .expect("Call to nv_global_write_lock failed");
+    /// ```
+    pub fn nv_global_write_lock(&mut self, nv_auth: Provision) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_NV_GlobalWriteLock(
+                    self.mut_context(),
+                    AuthHandle::from(nv_auth).into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                )
+            },
+            |ret| {
+                error!("Error when global write locking NV: {:#010X}", ret);
+            },
+        )
+    }
+
+    /// Reads data from the nv index.
+    ///
+    /// # Details
+    /// This method is used to read a value from an area in
+    /// NV memory of the TPM.
+    ///
+    /// Please beware that this method requires an authorization
+    /// session handle to be present.
+    ///
+    /// # Arguments
+    /// * `auth_handle` - Handle indicating the source of authorization value.
+    /// * `nv_index_handle` - The [NvIndexHandle] associated with NV memory
+    ///                       where data is to be written.
+    /// * `size` -  The number of octets to read.
+    /// * `offset`- Octet offset into the NV area.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use tss_esapi::{
+    /// #     Context, TctiNameConf, attributes::{SessionAttributes, NvIndexAttributes},
+    /// #     handles::NvIndexTpmHandle, interface_types::algorithm::HashingAlgorithm,
+    /// #     structures::{SymmetricDefinition, NvPublic}, constants::SessionType,
+    /// # };
+    /// use tss_esapi::{
+    ///       interface_types::resource_handles::{Provision, NvAuth}, structures::MaxNvBuffer,
+    /// };
+    /// use std::convert::TryFrom;
+    ///
+    /// # // Create context
+    /// # let mut context =
+    /// #     Context::new(
+    /// #         TctiNameConf::from_environment_variable().expect("Failed to get TCTI"),
+    /// #     ).expect("Failed to create Context");
+    /// #
+    /// # let session = context
+    /// #     .start_auth_session(
+    /// #         None,
+    /// #         None,
+    /// #         None,
+    /// #         SessionType::Hmac,
+    /// #         SymmetricDefinition::AES_256_CFB,
+    /// #         tss_esapi::interface_types::algorithm::HashingAlgorithm::Sha256,
+    /// #     )
+    /// #     .expect("Failed to create session")
+    /// #     .expect("Received invalid handle");
+    /// # let (session_attributes, session_attributes_mask) = SessionAttributes::builder()
+    /// #     .with_decrypt(true)
+    /// #     .with_encrypt(true)
+    /// #     .build();
+    /// # context.tr_sess_set_attributes(session, session_attributes, session_attributes_mask)
+    /// #     .expect("Failed to set attributes on session");
+    /// # context.set_sessions((Some(session), None, None));
+    /// #
+    /// # let nv_index = NvIndexTpmHandle::new(0x01500027)
+    /// #     .expect("Failed to create NV index tpm handle");
+    /// #
+    /// # // Create NV index attributes
+    /// # let owner_nv_index_attributes = NvIndexAttributes::builder()
+    /// #     .with_owner_write(true)
+    /// #     .with_owner_read(true)
+    /// #     .build()
+    /// #     .expect("Failed to create owner nv index attributes");
+    /// #
+    /// # // Create owner nv public.
+    /// # let owner_nv_public = NvPublic::builder()
+    /// #     .with_nv_index(nv_index)
+    /// #     .with_index_name_algorithm(HashingAlgorithm::Sha256)
+    /// #     .with_index_attributes(owner_nv_index_attributes)
+    /// #     .with_data_area_size(32)
+    /// #     .build()
+    /// #     .expect("Failed to build NvPublic for owner");
+    /// #
+    /// let data = MaxNvBuffer::try_from(vec![1, 2, 3, 4, 5, 6, 7])
+    ///    .expect("Failed to create MaxNvBuffer from vec");
+    ///
+    /// let nv_index_handle = context
+    ///    .nv_define_space(Provision::Owner, None, owner_nv_public)
+    ///    .expect("Call to nv_define_space failed");
+    ///
+    /// // Write data using owner authorization
+    /// let nv_write_result = context.nv_write(NvAuth::Owner, nv_index_handle, data.clone(), 0);
+    ///
+    /// // Read data using owner authorization
+    /// let data_len = u16::try_from(data.len()).expect("Failed to retrieve length of data");
+    /// let nv_read_result = context
+    ///     .nv_read(NvAuth::Owner, nv_index_handle, data_len, 0);
+    ///
+    /// context
+    ///     .nv_undefine_space(Provision::Owner, nv_index_handle)
+    ///     .expect("Call to nv_undefine_space failed");
+    ///
+    /// // Process result
+    /// nv_write_result.expect("Call to nv_write failed");
+    /// let read_data = nv_read_result.expect("Call to nv_read failed");
+    /// assert_eq!(data, read_data);
+    /// ```
+    pub fn nv_read(
+        &mut self,
+        auth_handle: NvAuth,
+        nv_index_handle: NvIndexHandle,
+        size: u16,
+        offset: u16,
+    ) -> Result<MaxNvBuffer> {
+        let mut data_ptr = null_mut();
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_NV_Read(
+                    self.mut_context(),
+                    AuthHandle::from(auth_handle).into(),
+                    nv_index_handle.into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    size,
+                    offset,
+                    &mut data_ptr,
+                )
+            },
+            |ret| {
+                error!("Error when reading NV: {:#010X}", ret);
+            },
+        )?;
+        MaxNvBuffer::try_from(Context::ffi_data_to_owned(data_ptr))
+    }
+
+    /// Prevents further reads of a nv index.
+    ///
+    /// # Details
+    /// This method is used to set the read lock of an nv index
+    /// that has the `read_stclear` attribute set. The lock is
+    /// cleared on the next TPM Reset or TPM Restart.
+    ///
+    /// Please beware that this method requires an authorization
+    /// session handle to be present.
+    ///
+    /// # Arguments
+    /// * `auth_handle` - Handle indicating the source of authorization value.
+    /// * `nv_index_handle` - The [NvIndexHandle] associated with NV memory
+    ///                       that is to be read locked.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use tss_esapi::{
+    /// #     Context, TctiNameConf, attributes::{SessionAttributes, NvIndexAttributes},
+    /// #     handles::NvIndexTpmHandle, interface_types::algorithm::HashingAlgorithm,
+    /// #     structures::{SymmetricDefinition, NvPublic}, constants::SessionType,
+    /// # };
+    /// use tss_esapi::interface_types::resource_handles::{Provision, NvAuth};
+    ///
+    /// # // Create context
+    /// # let mut context =
+    /// #     Context::new(
+    /// #         TctiNameConf::from_environment_variable().expect("Failed to get TCTI"),
+    /// #     ).expect("Failed to create Context");
+    /// #
+    /// # let session = context
+    /// #     .start_auth_session(
+    /// #         None,
+    /// #         None,
+    /// #         None,
+    /// #         SessionType::Hmac,
+    /// #         SymmetricDefinition::AES_256_CFB,
+    /// #         tss_esapi::interface_types::algorithm::HashingAlgorithm::Sha256,
+    /// #     )
+    /// #     .expect("Failed to create session")
+    /// #     .expect("Received invalid handle");
+    /// # let (session_attributes, session_attributes_mask) = SessionAttributes::builder()
+    /// #     .with_decrypt(true)
+    /// #     .with_encrypt(true)
+    /// #     .build();
+    /// # context.tr_sess_set_attributes(session, session_attributes, session_attributes_mask)
+    /// #     .expect("Failed to set attributes on session");
+    /// # context.set_sessions((Some(session), None, None));
+    /// #
+    /// # let nv_index = NvIndexTpmHandle::new(0x01500031)
+    /// #     .expect("Failed to create NV index tpm handle");
+    /// #
+    /// # // Create NV index attributes
+    /// # let owner_nv_index_attributes = NvIndexAttributes::builder()
+    /// #     .with_owner_write(true)
+    /// #     .with_owner_read(true)
+    /// #     .with_read_stclear(true)
+    /// #     .build()
+    /// #     .expect("Failed to create owner nv index attributes");
+    /// #
+    /// # // Create owner nv public.
+    /// # let owner_nv_public = NvPublic::builder()
+    /// #     .with_nv_index(nv_index)
+    /// #     .with_index_name_algorithm(HashingAlgorithm::Sha256)
+    /// #     .with_index_attributes(owner_nv_index_attributes)
+    /// #     .with_data_area_size(32)
+    /// #     .build()
+    /// #     .expect("Failed to build NvPublic for owner");
+    /// #
+    /// let nv_index_handle = context
+    ///     .nv_define_space(Provision::Owner, None, owner_nv_public)
+    ///     .expect("Call to nv_define_space failed");
+    ///
+    /// let nv_read_lock_result = context.nv_read_lock(NvAuth::Owner, nv_index_handle);
+    ///
+    /// context
+    ///     .nv_undefine_space(Provision::Owner, nv_index_handle)
+    ///     .expect("Call to nv_undefine_space failed");
+    ///
+    /// // Process result
+    /// nv_read_lock_result.expect("Call to nv_read_lock failed");
+    /// ```
+    pub fn nv_read_lock(
+        &mut self,
+        auth_handle: NvAuth,
+        nv_index_handle: NvIndexHandle,
+    ) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_NV_ReadLock(
+                    self.mut_context(),
+                    AuthHandle::from(auth_handle).into(),
+                    nv_index_handle.into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                )
+            },
+            |ret| {
+                error!("Error when read locking NV: {:#010X}", ret);
+            },
+        )
+    }
+
+    /// Changes the authorization value of a nv index.
+    ///
+    /// # Details
+    /// This method is used to change the authorization value
+    /// of an nv index. The command requires ADMIN role which
+    /// means that the session must be a policy session that
+    /// satisfies the authorization policy of the nv index.
+    ///
+    /// Please beware that this method requires an authorization
+    /// session handle to be present.
+    ///
+    /// # Arguments
+    /// * `nv_index_handle` - The [NvIndexHandle] associated with NV memory
+    ///                       for which the authorization value is to be changed.
+    /// * `new_auth` - The new authorization value.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// # use std::convert::TryFrom;
+    /// # use tss_esapi::{
+    /// #     Context, TctiNameConf, attributes::NvIndexAttributes,
+    /// #     handles::NvIndexTpmHandle, interface_types::algorithm::HashingAlgorithm,
+    /// #     structures::{SymmetricDefinition, NvPublic},
+    /// # };
+    /// use tss_esapi::{
+    ///     constants::{CommandCode, SessionType},
+    ///     interface_types::{
+    ///         resource_handles::Provision,
+    ///         session_handles::{AuthSession, PolicySession},
+    ///     },
+    ///     structures::Auth,
+    /// };
+    ///
+    /// # // Create context
+    /// # let mut context =
+    /// #     Context::new(
+    /// #         TctiNameConf::from_environment_variable().expect("Failed to get TCTI"),
+    /// #     ).expect("Failed to create Context");
+    /// #
+    /// # // Compute the policy of the nv index in a trial session
+    /// # let trial_session = context
+    /// #     .start_auth_session(
+    /// #         None,
+    /// #         None,
+    /// #         None,
+    /// #         SessionType::Trial,
+    /// #         SymmetricDefinition::AES_256_CFB,
+    /// #         HashingAlgorithm::Sha256,
+    /// #     )
+    /// #     .expect("Failed to create trial session")
+    /// #     .expect("Received invalid handle");
+    /// # let trial_session = PolicySession::try_from(trial_session)
+    /// #     .expect("Failed to convert auth session into policy session");
+    /// # context
+    /// #     .policy_command_code(trial_session, CommandCode::NvChangeAuth)
+    /// #     .expect("Call to policy_command_code failed");
+    /// # let policy_digest = context
+    /// #     .policy_get_digest(trial_session)
+    /// #     .expect("Call to policy_get_digest failed");
+    /// #
+    /// # let nv_index = NvIndexTpmHandle::new(0x01500037)
+    /// #     .expect("Failed to create NV index tpm handle");
+    /// #
+    /// # // Create NV index attributes
+    /// # let nv_index_attributes = NvIndexAttributes::builder()
+    /// #     .with_auth_write(true)
+    /// #     .with_auth_read(true)
+    /// #     .build()
+    /// #     .expect("Failed to create nv index attributes");
+    /// #
+    /// # // Create nv public.
+    /// # let nv_public = NvPublic::builder()
+    /// #     .with_nv_index(nv_index)
+    /// #     .with_index_name_algorithm(HashingAlgorithm::Sha256)
+    /// #     .with_index_attributes(nv_index_attributes)
+    /// #     .with_index_auth_policy(policy_digest)
+    /// #     .with_data_area_size(32)
+    /// #     .build()
+    /// #     .expect("Failed to build NvPublic");
+    /// #
+    /// let nv_index_handle = context
+    ///     .execute_with_session(Some(AuthSession::Password), |ctx| {
+    ///         ctx.nv_define_space(Provision::Owner, None, nv_public)
+    ///     })
+    ///     .expect("Call to nv_define_space failed");
+    ///
+    /// // Satisfy the policy of the nv index in a policy session.
+    /// let policy_session = context
+    ///     .start_auth_session(
+    ///         None,
+    ///         None,
+    ///         None,
+    ///         SessionType::Policy,
+    ///         SymmetricDefinition::AES_256_CFB,
+    ///         HashingAlgorithm::Sha256,
+    ///     )
+    ///     .expect("Failed to create policy session")
+    ///     .expect("Received invalid handle");
+    /// let policy_session = PolicySession::try_from(policy_session)
+    ///     .expect("Failed to convert auth session into policy session");
+    /// context
+    ///     .policy_command_code(policy_session, CommandCode::NvChangeAuth)
+    ///     .expect("Call to policy_command_code failed");
+    ///
+    /// let new_auth = Auth::try_from(vec![1, 2, 3, 4]).expect("Failed to create auth");
+    /// let nv_change_auth_result = context
+    ///     .execute_with_session(Some(AuthSession::PolicySession(policy_session)), |ctx| {
+    ///         ctx.nv_change_auth(nv_index_handle, new_auth)
+    ///     });
+    ///
+    /// context
+    ///     .execute_with_session(Some(AuthSession::Password), |ctx| {
+    ///         ctx.nv_undefine_space(Provision::Owner, nv_index_handle)
+    ///     })
+    ///     .expect("Call to nv_undefine_space failed");
+    ///
+    /// // Process result
+    /// nv_change_auth_result.expect("Call to nv_change_auth failed");
+    /// ```
+    pub fn nv_change_auth(&mut self, nv_index_handle: NvIndexHandle, new_auth: Auth) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_NV_ChangeAuth(
+                    self.mut_context(),
+                    nv_index_handle.into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    &new_auth.into(),
+                )
+            },
+            |ret| {
+                error!("Error when changing NV auth: {:#010X}", ret);
+            },
+        )
+    }
+
+    /// Certifies the contents of a nv index.
+    ///
+    /// # Details
+    /// This method is used to have the TPM sign the
+    /// name and the contents of a region of an nv index.
+    ///
+    /// Please beware that this method requires two authorization
+    /// session handles to be present. The first session is used
+    /// to authorize the signing key and the second session is used
+    /// to authorize the reading of the nv index.
+    ///
+    /// # Arguments
+    /// * `signing_key_handle` - Handle of the key used to sign the attestation buffer.
+    /// * `auth_handle` - Handle indicating the source of authorization value.
+    /// * `nv_index_handle` - The [NvIndexHandle] associated with NV memory
+    ///                       that is to be certified.
+    /// * `qualifying_data` - Qualifying data.
+    /// * `signing_scheme` - Signing scheme to use if the scheme for `signing_key_handle` is `Null`.
+    /// * `size` - The number of octets to certify.
+    /// * `offset` - Octet offset into the NV area.
+    ///
+    /// # Returns
+    /// The command returns a tuple consisting of:
+    /// * `attest_data` - TPM-generated attestation data.
+    /// * `signature` - Signature for the attestation data.
+    ///
+    /// The certified contents of the nv index can be verified using
+    /// [verify_nv_certify_info](crate::abstraction::nv::verify_nv_certify_info).
+    ///
+    /// # Example
+    /// ```rust
+    /// # use tss_esapi::{
+    /// #     Context, TctiNameConf, attributes::NvIndexAttributes,
+    /// #     handles::NvIndexTpmHandle,
+    /// #     interface_types::{
+    /// #         algorithm::{HashingAlgorithm, RsaSchemeAlgorithm},
+    /// #         key_bits::RsaKeyBits,
+    /// #         resource_handles::Hierarchy,
+    /// #     },
+    /// #     structures::{NvPublic, RsaExponent, RsaScheme},
+    /// #     utils::create_unrestricted_signing_rsa_public,
+    /// # };
+    /// use tss_esapi::{
+    ///     interface_types::{
+    ///         resource_handles::{NvAuth, Provision},
+    ///         session_handles::AuthSession,
+    ///     },
+    ///     structures::{Data, MaxNvBuffer, SignatureScheme},
+    /// };
+    /// use std::convert::TryFrom;
+    ///
+    /// # // Create context
+    /// # let mut context =
+    /// #     Context::new(
+    /// #         TctiNameConf::from_environment_variable().expect("Failed to get TCTI"),
+    /// #     ).expect("Failed to create Context");
+    /// #
+    /// # let signing_key_pub = create_unrestricted_signing_rsa_public(
+    /// #         RsaScheme::create(RsaSchemeAlgorithm::RsaSsa, Some(HashingAlgorithm::Sha256))
+    /// #         .expect("Failed to create RSA scheme"),
+    /// #     RsaKeyBits::Rsa2048,
+    /// #     RsaExponent::default(),
+    /// # )
+    /// # .expect("Failed to create an unrestricted signing rsa public structure");
+    /// # let sign_key_handle = context
+    /// #     .execute_with_nullauth_session(|ctx| {
+    /// #         ctx.create_primary(Hierarchy::Owner, signing_key_pub, None, None, None, None)
+    /// #     })
+    /// #     .unwrap()
+    /// #     .key_handle;
+    /// #
+    /// # let nv_index = NvIndexTpmHandle::new(0x01500039)
+    /// #     .expect("Failed to create NV index tpm handle");
+    /// #
+    /// # // Create NV index attributes
+    /// # let owner_nv_index_attributes = NvIndexAttributes::builder()
+    /// #     .with_owner_write(true)
+    /// #     .with_owner_read(true)
+    /// #     .build()
+    /// #     .expect("Failed to create owner nv index attributes");
+    /// #
+    /// # // Create owner nv public.
+    /// # let owner_nv_public = NvPublic::builder()
+    /// #     .with_nv_index(nv_index)
+    /// #     .with_index_name_algorithm(HashingAlgorithm::Sha256)
+    /// #     .with_index_attributes(owner_nv_index_attributes)
+    /// #     .with_data_area_size(32)
+    /// #     .build()
+    /// #     .expect("Failed to build NvPublic for owner");
+    /// #
+    /// let data = MaxNvBuffer::try_from(vec![1, 2, 3, 4, 5, 6, 7])
+    ///     .expect("Failed to create MaxNvBuffer from vec");
+    ///
+    /// let nv_index_handle = context
+    ///     .execute_with_session(Some(AuthSession::Password), |ctx| {
+    ///         ctx.nv_define_space(Provision::Owner, None, owner_nv_public)
+    ///     })
+    ///     .expect("Call to nv_define_space failed");
+    ///
+    /// let nv_certify_result = context.execute_with_sessions(
+    ///     (
+    ///         Some(AuthSession::Password),
+    ///         Some(AuthSession::Password),
+    ///         None,
+    ///     ),
+    ///     |ctx| {
+    ///         ctx.nv_write(NvAuth::Owner, nv_index_handle, data, 0)?;
+    ///         ctx.nv_certify(
+    ///             sign_key_handle,
+    ///             NvAuth::Owner,
+    ///             nv_index_handle,
+    ///             Data::try_from(vec![0xff; 16]).expect("Failed to create qualifying data"),
+    ///             SignatureScheme::Null,
+    ///             7,
+    ///             0,
+    ///         )
+    ///     },
+    /// );
+    ///
+    /// context
+    ///     .execute_with_session(Some(AuthSession::Password), |ctx| {
+    ///         ctx.nv_undefine_space(Provision::Owner, nv_index_handle)
+    ///     })
+    ///     .expect("Call to nv_undefine_space failed");
+    ///
+    /// // Process result
+    /// let (attest, signature) = nv_certify_result.expect("Call to nv_certify failed");
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    pub fn nv_certify(
+        &mut self,
+        signing_key_handle: KeyHandle,
+        auth_handle: NvAuth,
+        nv_index_handle: NvIndexHandle,
+        qualifying_data: Data,
+        signing_scheme: SignatureScheme,
+        size: u16,
+        offset: u16,
+    ) -> Result<(Attest, Signature)> {
+        let mut certify_info_ptr = null_mut();
+        let mut signature_ptr = null_mut();
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_NV_Certify(
+                    self.mut_context(),
+                    signing_key_handle.into(),
+                    AuthHandle::from(auth_handle).into(),
+                    nv_index_handle.into(),
+                    self.required_session_1()?,
+                    self.required_session_2()?,
+                    self.optional_session_3(),
+                    &qualifying_data.into(),
+                    &signing_scheme.into(),
+                    size,
+                    offset,
+                    &mut certify_info_ptr,
+                    &mut signature_ptr,
+                )
+            },
+            |ret| {
+                error!("Error when certifying NV: {:#010X}", ret);
+            },
+        )?;
+
+        let certify_info = Context::ffi_data_to_owned(certify_info_ptr);
+        let signature = Context::ffi_data_to_owned(signature_ptr);
+        Ok((
+            Attest::try_from(AttestBuffer::try_from(certify_info)?)?,
+            Signature::try_from(signature)?,
+        ))
+    }
 }
@@ -8,12 +8,13 @@ use crate::{
     handles::{KeyHandle, ObjectHandle, TpmHandle},
     interface_types::resource_handles::Hierarchy,
     structures::{
-        Auth, CreateKeyResult, Data, Digest, EncryptedSecret, IdObject, Name, PcrSelectionList,
-        Private, Public, Sensitive, SensitiveData,
+        Auth, CreateKeyResult, CreateLoadedKeyResult, Data, Digest, EncryptedSecret, IdObject,
+        Name, PcrSelectionList, Private, Public, Sensitive, SensitiveCreate, SensitiveData,
+        Template, TemplateInput,
     },
     tss2_esys::{
-        Esys_ActivateCredential, Esys_Create, Esys_Load, Esys_LoadExternal, Esys_MakeCredential,
-        Esys_ObjectChangeAuth, Esys_ReadPublic, Esys_Unseal,
+        Esys_ActivateCredential, Esys_Create, Esys_CreateLoaded, Esys_Load, Esys_LoadExternal,
+        Esys_MakeCredential, Esys_ObjectChangeAuth, Esys_ReadPublic, Esys_Unseal,
     },
     Context, Result, ReturnCode,
 };
@@ -338,5 +339,124 @@ impl Context {
         Private::try_from(Context::ffi_data_to_owned(out_private_ptr))
     }
 
-    // Missing function: CreateLoaded
+    /// Create an object and load it into the TPM.
+    ///
+    /// # Details
+    /// Depending on `parent_handle` this creates a primary object (if it is a
+    /// hierarchy), an ordinary object (if it is a storage parent) or a derived
+    /// object (if it is a derivation parent). Derived objects are created from
+    /// a `TemplateInput::Derive` template, which requires TSS version 3.1.0 or
+    /// later, and are fully determined by the derivation parent and the label
+    /// and context of the template.
+    ///
+    /// No creation data is produced by this command.
+    ///
+    /// # Arguments
+    /// * `parent_handle` - The [ObjectHandle] of the hierarchy or the parent of the new object.
+    /// * `template` - The [TemplateInput] of the object that is being created.
+    /// * `auth_value` - The value used to be used for authorize usage of the object.
+    /// * `sensitive_data` - The data that is to be sealed or a key. Must be empty for
+    ///                      derived objects.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use tss_esapi::{
+    /// #     Context, TctiNameConf,
+    /// #     interface_types::{
+    /// #         algorithm::{HashingAlgorithm, PublicAlgorithm},
+    /// #         ecc::EccCurve,
+    /// #         resource_handles::Hierarchy,
+    /// #         session_handles::AuthSession,
+    /// #     },
+    /// #     attributes::ObjectAttributesBuilder,
+    /// #     handles::ObjectHandle,
+    /// #     structures::{
+    /// #         EccPoint, PublicBuilder, PublicEccParametersBuilder, SymmetricDefinitionObject,
+    /// #         TemplateInput,
+    /// #     },
+    /// # };
+    /// # let mut context =
+    /// #     Context::new(
+    /// #         TctiNameConf::from_environment_variable().expect("Failed to get TCTI"),
+    /// #     ).expect("Failed to create Context");
+    /// # let object_attributes = ObjectAttributesBuilder::new()
+    /// #     .with_fixed_tpm(true)
+    /// #     .with_fixed_parent(true)
+    /// #     .with_sensitive_data_origin(true)
+    /// #     .with_user_with_auth(true)
+    /// #     .with_decrypt(true)
+    /// #     .with_restricted(true)
+    /// #     .build()
+    /// #     .expect("Failed to build object attributes");
+    /// # let public = PublicBuilder::new()
+    /// #     .with_public_algorithm(PublicAlgorithm::Ecc)
+    /// #     .with_name_hashing_algorithm(HashingAlgorithm::Sha256)
+    /// #     .with_object_attributes(object_attributes)
+    /// #     .with_ecc_parameters(
+    /// #         PublicEccParametersBuilder::new_restricted_decryption_key(
+    /// #             SymmetricDefinitionObject::AES_128_CFB,
+    /// #             EccCurve::NistP256,
+    /// #         )
+    /// #         .build()
+    /// #         .expect("Failed to build ecc parameters"),
+    /// #     )
+    /// #     .with_ecc_unique_identifier(EccPoint::default())
+    /// #     .build()
+    /// #     .expect("Failed to build public");
+    /// let primary = context
+    ///     .execute_with_session(Some(AuthSession::Password), |ctx| {
+    ///         ctx.create_loaded(
+    ///             ObjectHandle::from(Hierarchy::Owner),
+    ///             TemplateInput::Public(public),
+    ///             None,
+    ///             None,
+    ///         )
+    ///     })
+    ///     .expect("Call to create_loaded failed");
+    /// ```
+    pub fn create_loaded(
+        &mut self,
+        parent_handle: ObjectHandle,
+        template: TemplateInput,
+        auth_value: Option<Auth>,
+        sensitive_data: Option<SensitiveData>,
+    ) -> Result<CreateLoadedKeyResult> {
+        let sensitive_create = SensitiveCreate::new(
+            auth_value.unwrap_or_default(),
+            sensitive_data.unwrap_or_default(),
+        );
+        let mut object_handle = ObjectHandle::None.into();
+        let mut out_private_ptr = null_mut();
+        let mut out_public_ptr = null_mut();
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_CreateLoaded(
+                    self.mut_context(),
+                    parent_handle.into(),
+                    self.optional_session_1(),
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    &sensitive_create.try_into()?,
+                    &Template::try_from(template)?.into(),
+                    &mut object_handle,
+                    &mut out_private_ptr,
+                    &mut out_public_ptr,
+                )
+            },
+            |ret| {
+                error!("Error in creating and loading object: {:#010X}", ret);
+            },
+        )?;
+        let out_private_owned = Context::ffi_data_to_owned(out_private_ptr);
+        let out_public_owned = Context::ffi_data_to_owned(out_public_ptr);
+        let key_handle = KeyHandle::from(object_handle);
+        self.handle_manager
+            .add_handle(key_handle.into(), HandleDropAction::Flush)?;
+
+        Ok(CreateLoadedKeyResult {
+            key_handle,
+            out_private: Private::try_from(out_private_owned)?,
+            out_public: Public::try_from(out_public_owned)?,
+        })
+    }
 }
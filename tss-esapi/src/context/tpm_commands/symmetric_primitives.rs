@@ -7,7 +7,7 @@ use crate::{
         resource_handles::Hierarchy,
     },
     structures::{Digest, HashcheckTicket, InitialValue, MaxBuffer},
-    tss2_esys::{Esys_EncryptDecrypt2, Esys_HMAC, Esys_Hash},
+    tss2_esys::{Esys_EncryptDecrypt, Esys_EncryptDecrypt2, Esys_HMAC, Esys_Hash},
     Context, Result, ReturnCode,
 };
 use log::error;
@@ -15,7 +15,63 @@ use std::convert::TryFrom;
 use std::ptr::null_mut;
 
 impl Context {
-    // Missing function: EncryptDecrypt, deprecated use EncryptDecrypt2 instead.
+    /// Performs symmetric encryption or decryption of the data using
+    /// the key associated with the `key_handle`
+    ///
+    /// # Arguments
+    /// * `key_handle` -  A [KeyHandle] to the key to be used.
+    /// * `decrypt` - A boolean indicating if the data should be decrypted or encrypted.
+    ///               If set to true the data will be decrypted else encrypted.
+    /// * `mode` - The [SymmetricMode] to be used.
+    /// * `in_data` - The data that is going to be decrypted or encrypted.
+    /// * `initial_value_in` - An initial value as required by the algorithm.
+    ///
+    /// # Details
+    /// This command is deprecated, use [Context::encrypt_decrypt_2] on TPMs
+    /// that support it. Because the first parameter of the command is not a
+    /// sized buffer, the command cannot be used with a session that has the
+    /// `decrypt` attribute set.
+    ///
+    /// [cipher::encrypt_decrypt](crate::abstraction::cipher::encrypt_decrypt)
+    /// selects the command that is supported by the TPM.
+    pub fn encrypt_decrypt(
+        &mut self,
+        key_handle: KeyHandle,
+        decrypt: bool,
+        mode: SymmetricMode,
+        in_data: MaxBuffer,
+        initial_value_in: InitialValue,
+    ) -> Result<(MaxBuffer, InitialValue)> {
+        let mut out_data_ptr = null_mut();
+        let mut iv_out_ptr = null_mut();
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_EncryptDecrypt(
+                    self.mut_context(),
+                    key_handle.into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    decrypt.into(),
+                    mode.into(),
+                    &initial_value_in.into(),
+                    &in_data.into(),
+                    &mut out_data_ptr,
+                    &mut iv_out_ptr,
+                )
+            },
+            |ret| {
+                error!(
+                    "Error failed to perform encrypt or decrypt operations {:#010X}",
+                    ret
+                );
+            },
+        )?;
+        Ok((
+            MaxBuffer::try_from(Context::ffi_data_to_owned(out_data_ptr))?,
+            InitialValue::try_from(Context::ffi_data_to_owned(iv_out_ptr))?,
+        ))
+    }
 
     /// Performs symmetric encryption or decryption of the data using
     /// the key associated with the `key_handle`
@@ -374,5 +430,50 @@ impl Context {
         Digest::try_from(Context::ffi_data_to_owned(out_hmac_ptr))
     }
 
-    // Missing function: MAC
+    /// Computes a MAC of the data in `buffer` using the key associated
+    /// with `handle`.
+    ///
+    /// # Arguments
+    /// * `handle` - A handle to a loaded key that is going to be used
+    ///              for the MAC computation.
+    /// * `buffer` - The data that is going to be authenticated.
+    /// * `mac_scheme` - The [MacSchemeAlgorithm](crate::interface_types::algorithm::MacSchemeAlgorithm)
+    ///                  to use.
+    ///
+    /// # Details
+    /// The `mac_scheme` selects the kind of MAC that is computed. A hashing
+    /// algorithm selects an HMAC, which requires a keyed hash key, while
+    /// `Cmac` selects a CMAC, which requires a symmetric cipher key.
+    ///
+    /// This command requires an authorization session for the key.
+    ///
+    /// # Returns
+    /// The computed MAC.
+    #[cfg(has_esys_mac)]
+    pub fn mac(
+        &mut self,
+        handle: ObjectHandle,
+        buffer: MaxBuffer,
+        mac_scheme: crate::interface_types::algorithm::MacSchemeAlgorithm,
+    ) -> Result<Digest> {
+        let mut out_mac_ptr = null_mut();
+        ReturnCode::ensure_success(
+            unsafe {
+                crate::tss2_esys::Esys_MAC(
+                    self.mut_context(),
+                    handle.into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    &buffer.into(),
+                    mac_scheme.into(),
+                    &mut out_mac_ptr,
+                )
+            },
+            |ret| {
+                error!("Error in mac: {:#010X}", ret);
+            },
+        )?;
+        Digest::try_from(Context::ffi_data_to_owned(out_mac_ptr))
+    }
 }
@@ -2,8 +2,8 @@
 // SPDX-License-Identifier: Apache-2.0
 use crate::{
     interface_types::YesNo,
-    structures::MaxBuffer,
-    tss2_esys::{Esys_GetTestResult, Esys_SelfTest},
+    structures::{AlgorithmList, MaxBuffer},
+    tss2_esys::{Esys_GetTestResult, Esys_IncrementalSelfTest, Esys_SelfTest},
     Context, Result, ReturnCode,
 };
 use log::error;
@@ -29,7 +29,55 @@ impl Context {
         )
     }
 
-    // Missing function: incremental_self_test
+    /// Execute the TPM self test for the provided algorithms
+    ///
+    /// # Details
+    /// The TPM tests the algorithms in `to_test` that have not yet been tested,
+    /// instead of performing a full self test. The tests may be performed in
+    /// the background, in which case commands that use an algorithm under test
+    /// return a `Testing` [TpmFormatZeroWarning](crate::constants::return_code::TpmFormatZeroWarning)
+    /// until the test completes.
+    ///
+    /// # Arguments
+    /// * `to_test` - The [AlgorithmList] of algorithms that are to be tested.
+    ///
+    /// # Returns
+    /// The [AlgorithmList] of algorithms that still need to be tested.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// # use tss_esapi::{Context, TctiNameConf};
+    /// use std::convert::TryFrom;
+    /// use tss_esapi::{constants::AlgorithmIdentifier, structures::AlgorithmList};
+    /// # let mut context =
+    /// #     Context::new(
+    /// #         TctiNameConf::from_environment_variable().expect("Failed to get TCTI"),
+    /// #     ).expect("Failed to create Context");
+    /// let to_test = AlgorithmList::try_from(vec![AlgorithmIdentifier::Sha256])
+    ///     .expect("Failed to create algorithm list");
+    /// let to_do_list = context
+    ///     .incremental_self_test(to_test)
+    ///     .expect("Call to incremental_self_test failed");
+    /// ```
+    pub fn incremental_self_test(&mut self, to_test: AlgorithmList) -> Result<AlgorithmList> {
+        let mut to_do_list_ptr = null_mut();
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_IncrementalSelfTest(
+                    self.mut_context(),
+                    self.optional_session_1(),
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    &to_test.into(),
+                    &mut to_do_list_ptr,
+                )
+            },
+            |ret| {
+                error!("Error in incremental self-test: {:#010X}", ret);
+            },
+        )?;
+        AlgorithmList::try_from(Context::ffi_data_to_owned(to_do_list_ptr))
+    }
 
     /// Get the TPM self test result
     ///
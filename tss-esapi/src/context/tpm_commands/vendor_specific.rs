@@ -1,7 +1,247 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
-use crate::Context;
+use crate::{
+    constants::tss::{TPM2_RS_PW, TPM2_ST_NO_SESSIONS, TPM2_ST_SESSIONS},
+    handles::TpmHandle,
+    interface_types::session_handles::AuthSession,
+    structures::{Auth, Data},
+    tss2_esys::{
+        Esys_GetTcti, Esys_Vendor_TCG_Test, TPM2_MAX_COMMAND_SIZE, TPM2_MAX_RESPONSE_SIZE,
+        TSS2_TCTI_CONTEXT_COMMON_V1, TSS2_TCTI_TIMEOUT_BLOCK,
+    },
+    Context, Error, Result, ReturnCode, WrapperErrorKind,
+};
+use log::error;
+use std::convert::TryFrom;
+use std::ptr::null_mut;
+
+/// The bit in a command code that marks it as vendor specific.
+const VENDOR_SPECIFIC_BIT: u32 = 1 << 29;
+/// The size of the header of a command or response.
+const HEADER_SIZE: usize = 10;
+/// The maximum number of authorizations in a command.
+const MAX_AUTHORIZATIONS: usize = 3;
 
 impl Context {
-    // Missing function: Vendor_TCG_Test
+    /// Executes the vendor specific TCG test command.
+    ///
+    /// # Details
+    /// The command is intended for testing and its behaviour is vendor
+    /// dependent. The reference implementation returns `input_data`.
+    ///
+    /// # Arguments
+    /// * `input_data` - The [Data] sent to the TPM.
+    ///
+    /// # Returns
+    /// The [Data] returned by the TPM.
+    pub fn vendor_tcg_test(&mut self, input_data: Data) -> Result<Data> {
+        let mut output_data_ptr = null_mut();
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_Vendor_TCG_Test(
+                    self.mut_context(),
+                    self.optional_session_1(),
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    &input_data.into(),
+                    &mut output_data_ptr,
+                )
+            },
+            |ret| {
+                error!("Error in vendor TCG test: {:#010X}", ret);
+            },
+        )?;
+        Data::try_from(Context::ffi_data_to_owned(output_data_ptr))
+    }
+
+    /// Sends a vendor specific command to the TPM.
+    ///
+    /// # Details
+    /// The command is marshalled from the provided values and sent to the
+    /// TPM through the TCTI of the context, bypassing ESAPI which has no
+    /// knowledge of vendor specific commands.
+    ///
+    /// Each of the `authorizations` is sent as a password authorization for
+    /// the handle in the same position in `handles`. HMAC and policy sessions
+    /// cannot be used, because their state is only available to ESAPI, so
+    /// the sessions of the context must either be unset or be password
+    /// sessions. Password sessions of the context are ignored, the auth
+    /// values are always taken from `authorizations`.
+    ///
+    /// The response of the command is expected to not contain any handles.
+    ///
+    /// # Arguments
+    /// * `command_code` - The command code, which must have the vendor specific bit set.
+    /// * `handles` - The handles of the handle area of the command.
+    /// * `authorizations` - The auth values used for password authorization.
+    /// * `parameters` - The marshalled parameters of the command.
+    ///
+    /// # Returns
+    /// The marshalled parameters of the response.
+    ///
+    /// # Errors
+    /// * if the vendor specific bit is not set in `command_code`, or if there are
+    ///   more `authorizations` than `handles`, an `InvalidParam` wrapper error is returned.
+    /// * if an HMAC or policy session is set in the context, an `UnsupportedParam`
+    ///   wrapper error is returned.
+    /// * if the command exceeds the maximum command size an `WrongParamSize` wrapper
+    ///   error is returned.
+    pub fn vendor_command(
+        &mut self,
+        command_code: u32,
+        handles: &[TpmHandle],
+        authorizations: &[Auth],
+        parameters: &[u8],
+    ) -> Result<Vec<u8>> {
+        if command_code & VENDOR_SPECIFIC_BIT == 0 {
+            error!(
+                "The command code {:#010X} is not vendor specific",
+                command_code
+            );
+            return Err(Error::local_error(WrapperErrorKind::InvalidParam));
+        }
+        if authorizations.len() > handles.len() || authorizations.len() > MAX_AUTHORIZATIONS {
+            error!("Too many authorizations provided for the vendor specific command");
+            return Err(Error::local_error(WrapperErrorKind::InvalidParam));
+        }
+        let (session_1, session_2, session_3) = self.sessions();
+        if [session_1, session_2, session_3]
+            .iter()
+            .flatten()
+            .any(|session| *session != AuthSession::Password)
+        {
+            error!("Only password sessions can be used with vendor specific commands");
+            return Err(Error::local_error(WrapperErrorKind::UnsupportedParam));
+        }
+
+        let mut command = Vec::<u8>::with_capacity(TPM2_MAX_COMMAND_SIZE as usize);
+        let tag = if authorizations.is_empty() {
+            TPM2_ST_NO_SESSIONS
+        } else {
+            TPM2_ST_SESSIONS
+        };
+        command.extend_from_slice(&tag.to_be_bytes());
+        // The command size is filled in when the command is complete.
+        command.extend_from_slice(&0u32.to_be_bytes());
+        command.extend_from_slice(&command_code.to_be_bytes());
+        for handle in handles {
+            command.extend_from_slice(&u32::from(*handle).to_be_bytes());
+        }
+        if !authorizations.is_empty() {
+            let mut authorization_area = Vec::<u8>::new();
+            for auth in authorizations {
+                authorization_area.extend_from_slice(&TPM2_RS_PW.to_be_bytes());
+                // Empty nonce and no session attributes.
+                authorization_area.extend_from_slice(&0u16.to_be_bytes());
+                authorization_area.push(0);
+                authorization_area.extend_from_slice(&(auth.len() as u16).to_be_bytes());
+                authorization_area.extend_from_slice(auth.as_bytes());
+            }
+            command.extend_from_slice(&(authorization_area.len() as u32).to_be_bytes());
+            command.extend_from_slice(&authorization_area);
+        }
+        command.extend_from_slice(parameters);
+        if command.len() > TPM2_MAX_COMMAND_SIZE as usize {
+            error!(
+                "The vendor specific command exceeds the maximum command size(> {})",
+                TPM2_MAX_COMMAND_SIZE
+            );
+            return Err(Error::local_error(WrapperErrorKind::WrongParamSize));
+        }
+        let command_size = (command.len() as u32).to_be_bytes();
+        command[2..6].copy_from_slice(&command_size);
+
+        let response = self.transmit_and_receive(&command)?;
+        Self::response_parameters(&response)
+    }
+
+    /// Private function that sends a marshalled command through
+    /// the TCTI and returns the marshalled response.
+    fn transmit_and_receive(&mut self, command: &[u8]) -> Result<Vec<u8>> {
+        let mut tcti_context_ptr = null_mut();
+        ReturnCode::ensure_success(
+            unsafe { Esys_GetTcti(self.mut_context(), &mut tcti_context_ptr) },
+            |ret| {
+                error!("Error when getting the TCTI context: {:#010X}", ret);
+            },
+        )?;
+        // The TCTI context always starts with the common fields.
+        let tcti_common = unsafe { &*(tcti_context_ptr as *const TSS2_TCTI_CONTEXT_COMMON_V1) };
+        let (transmit, receive) = match (tcti_common.transmit, tcti_common.receive) {
+            (Some(transmit), Some(receive)) => (transmit, receive),
+            _ => {
+                error!("The TCTI does not support sending commands");
+                return Err(Error::local_error(WrapperErrorKind::InternalError));
+            }
+        };
+
+        ReturnCode::ensure_success(
+            unsafe { transmit(tcti_context_ptr, command.len() as _, command.as_ptr()) },
+            |ret| {
+                error!(
+                    "Error when transmitting vendor specific command: {:#010X}",
+                    ret
+                );
+            },
+        )?;
+        let mut response = vec![0u8; TPM2_MAX_RESPONSE_SIZE as usize];
+        let mut response_size = response.len() as _;
+        ReturnCode::ensure_success(
+            unsafe {
+                receive(
+                    tcti_context_ptr,
+                    &mut response_size,
+                    response.as_mut_ptr(),
+                    TSS2_TCTI_TIMEOUT_BLOCK,
+                )
+            },
+            |ret| {
+                error!(
+                    "Error when receiving vendor specific response: {:#010X}",
+                    ret
+                );
+            },
+        )?;
+        response.truncate(response_size as usize);
+        Ok(response)
+    }
+
+    /// Private function that extracts the parameters from a
+    /// marshalled response without response handles.
+    fn response_parameters(response: &[u8]) -> Result<Vec<u8>> {
+        let invalid_response = || {
+            error!("Received an invalid response to the vendor specific command");
+            Error::local_error(WrapperErrorKind::WrongValueFromTpm)
+        };
+        if response.len() < HEADER_SIZE {
+            return Err(invalid_response());
+        }
+        let tag = u16::from_be_bytes([response[0], response[1]]);
+        let response_size =
+            u32::from_be_bytes([response[2], response[3], response[4], response[5]]);
+        let response_code =
+            u32::from_be_bytes([response[6], response[7], response[8], response[9]]);
+        if response_size as usize != response.len() {
+            return Err(invalid_response());
+        }
+        ReturnCode::ensure_success(response_code, |ret| {
+            error!("Error in vendor specific command: {:#010X}", ret);
+        })?;
+
+        let body = &response[HEADER_SIZE..];
+        if tag == TPM2_ST_SESSIONS {
+            if body.len() < 4 {
+                return Err(invalid_response());
+            }
+            let parameter_size = u32::from_be_bytes([body[0], body[1], body[2], body[3]]) as usize;
+            let parameters_end = 4usize
+                .checked_add(parameter_size)
+                .ok_or_else(invalid_response)?;
+            body.get(4..parameters_end)
+                .map(<[u8]>::to_vec)
+                .ok_or_else(invalid_response)
+        } else {
+            Ok(body.to_vec())
+        }
+    }
 }
@@ -3,9 +3,10 @@
 use crate::{
     constants::AlgorithmIdentifier,
     tss2_esys::{
-        TPMI_ALG_ASYM, TPMI_ALG_ECC_SCHEME, TPMI_ALG_HASH, TPMI_ALG_KDF, TPMI_ALG_KEYEDHASH_SCHEME,
-        TPMI_ALG_PUBLIC, TPMI_ALG_RSA_DECRYPT, TPMI_ALG_RSA_SCHEME, TPMI_ALG_SIG_SCHEME,
-        TPMI_ALG_SYM, TPMI_ALG_SYM_MODE, TPMI_ALG_SYM_OBJECT,
+        TPM2_ALG_ID, TPMI_ALG_ASYM, TPMI_ALG_ECC_SCHEME, TPMI_ALG_HASH, TPMI_ALG_KDF,
+        TPMI_ALG_KEYEDHASH_SCHEME, TPMI_ALG_PUBLIC, TPMI_ALG_RSA_DECRYPT, TPMI_ALG_RSA_SCHEME,
+        TPMI_ALG_SIG_SCHEME, TPMI_ALG_SYM, TPMI_ALG_SYM_MODE, TPMI_ALG_SYM_OBJECT,
+        TPMI_ECC_KEY_EXCHANGE,
     },
     Error, Result, WrapperErrorKind,
 };
@@ -684,3 +685,141 @@ impl TryFrom<TPMI_ALG_RSA_DECRYPT> for RsaDecryptAlgorithm {
         RsaDecryptAlgorithm::try_from(AlgorithmIdentifier::try_from(tpmi_alg_rsa_decrypt)?)
     }
 }
+
+/// Enum representing the mac scheme interface type
+///
+/// # Details
+/// This corresponds to TPMI_ALG_MAC_SCHEME
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MacSchemeAlgorithm {
+    Sha1,
+    Sha256,
+    Sha384,
+    Sha512,
+    Sm3_256,
+    Sha3_256,
+    Sha3_384,
+    Sha3_512,
+    Cmac,
+    Null,
+}
+
+impl From<MacSchemeAlgorithm> for AlgorithmIdentifier {
+    fn from(mac_scheme_algorithm: MacSchemeAlgorithm) -> Self {
+        match mac_scheme_algorithm {
+            MacSchemeAlgorithm::Sha1 => AlgorithmIdentifier::Sha1,
+            MacSchemeAlgorithm::Sha256 => AlgorithmIdentifier::Sha256,
+            MacSchemeAlgorithm::Sha384 => AlgorithmIdentifier::Sha384,
+            MacSchemeAlgorithm::Sha512 => AlgorithmIdentifier::Sha512,
+            MacSchemeAlgorithm::Sm3_256 => AlgorithmIdentifier::Sm3_256,
+            MacSchemeAlgorithm::Sha3_256 => AlgorithmIdentifier::Sha3_256,
+            MacSchemeAlgorithm::Sha3_384 => AlgorithmIdentifier::Sha3_384,
+            MacSchemeAlgorithm::Sha3_512 => AlgorithmIdentifier::Sha3_512,
+            MacSchemeAlgorithm::Cmac => AlgorithmIdentifier::Cmac,
+            MacSchemeAlgorithm::Null => AlgorithmIdentifier::Null,
+        }
+    }
+}
+
+impl TryFrom<AlgorithmIdentifier> for MacSchemeAlgorithm {
+    type Error = Error;
+
+    fn try_from(algorithm_identifier: AlgorithmIdentifier) -> Result<Self> {
+        match algorithm_identifier {
+            AlgorithmIdentifier::Sha1 => Ok(MacSchemeAlgorithm::Sha1),
+            AlgorithmIdentifier::Sha256 => Ok(MacSchemeAlgorithm::Sha256),
+            AlgorithmIdentifier::Sha384 => Ok(MacSchemeAlgorithm::Sha384),
+            AlgorithmIdentifier::Sha512 => Ok(MacSchemeAlgorithm::Sha512),
+            AlgorithmIdentifier::Sm3_256 => Ok(MacSchemeAlgorithm::Sm3_256),
+            AlgorithmIdentifier::Sha3_256 => Ok(MacSchemeAlgorithm::Sha3_256),
+            AlgorithmIdentifier::Sha3_384 => Ok(MacSchemeAlgorithm::Sha3_384),
+            AlgorithmIdentifier::Sha3_512 => Ok(MacSchemeAlgorithm::Sha3_512),
+            AlgorithmIdentifier::Cmac => Ok(MacSchemeAlgorithm::Cmac),
+            AlgorithmIdentifier::Null => Ok(MacSchemeAlgorithm::Null),
+            _ => Err(Error::local_error(WrapperErrorKind::InvalidParam)),
+        }
+    }
+}
+
+impl From<HashingAlgorithm> for MacSchemeAlgorithm {
+    fn from(hashing_algorithm: HashingAlgorithm) -> Self {
+        match hashing_algorithm {
+            HashingAlgorithm::Sha1 => MacSchemeAlgorithm::Sha1,
+            HashingAlgorithm::Sha256 => MacSchemeAlgorithm::Sha256,
+            HashingAlgorithm::Sha384 => MacSchemeAlgorithm::Sha384,
+            HashingAlgorithm::Sha512 => MacSchemeAlgorithm::Sha512,
+            HashingAlgorithm::Sm3_256 => MacSchemeAlgorithm::Sm3_256,
+            HashingAlgorithm::Sha3_256 => MacSchemeAlgorithm::Sha3_256,
+            HashingAlgorithm::Sha3_384 => MacSchemeAlgorithm::Sha3_384,
+            HashingAlgorithm::Sha3_512 => MacSchemeAlgorithm::Sha3_512,
+            HashingAlgorithm::Null => MacSchemeAlgorithm::Null,
+        }
+    }
+}
+
+// TPMI_ALG_MAC_SCHEME is not available in all versions of
+// the TSS headers so the underlying TPM2_ALG_ID is used instead.
+impl From<MacSchemeAlgorithm> for TPM2_ALG_ID {
+    fn from(mac_scheme_algorithm: MacSchemeAlgorithm) -> Self {
+        AlgorithmIdentifier::from(mac_scheme_algorithm).into()
+    }
+}
+
+impl TryFrom<TPM2_ALG_ID> for MacSchemeAlgorithm {
+    type Error = Error;
+
+    fn try_from(tpm2_alg_id: TPM2_ALG_ID) -> Result<Self> {
+        MacSchemeAlgorithm::try_from(AlgorithmIdentifier::try_from(tpm2_alg_id)?)
+    }
+}
+
+/// Enum representing the ecc key exchange interface type
+///
+/// # Details
+/// This corresponds to TPMI_ECC_KEY_EXCHANGE
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EccKeyExchangeAlgorithm {
+    EcDh,
+    EcMqv,
+    Sm2,
+    Null,
+}
+
+impl From<EccKeyExchangeAlgorithm> for AlgorithmIdentifier {
+    fn from(ecc_key_exchange_algorithm: EccKeyExchangeAlgorithm) -> Self {
+        match ecc_key_exchange_algorithm {
+            EccKeyExchangeAlgorithm::EcDh => AlgorithmIdentifier::EcDh,
+            EccKeyExchangeAlgorithm::EcMqv => AlgorithmIdentifier::EcMqv,
+            EccKeyExchangeAlgorithm::Sm2 => AlgorithmIdentifier::Sm2,
+            EccKeyExchangeAlgorithm::Null => AlgorithmIdentifier::Null,
+        }
+    }
+}
+
+impl TryFrom<AlgorithmIdentifier> for EccKeyExchangeAlgorithm {
+    type Error = Error;
+
+    fn try_from(algorithm_identifier: AlgorithmIdentifier) -> Result<Self> {
+        match algorithm_identifier {
+            AlgorithmIdentifier::EcDh => Ok(EccKeyExchangeAlgorithm::EcDh),
+            AlgorithmIdentifier::EcMqv => Ok(EccKeyExchangeAlgorithm::EcMqv),
+            AlgorithmIdentifier::Sm2 => Ok(EccKeyExchangeAlgorithm::Sm2),
+            AlgorithmIdentifier::Null => Ok(EccKeyExchangeAlgorithm::Null),
+            _ => Err(Error::local_error(WrapperErrorKind::InvalidParam)),
+        }
+    }
+}
+
+impl From<EccKeyExchangeAlgorithm> for TPMI_ECC_KEY_EXCHANGE {
+    fn from(ecc_key_exchange_algorithm: EccKeyExchangeAlgorithm) -> Self {
+        AlgorithmIdentifier::from(ecc_key_exchange_algorithm).into()
+    }
+}
+
+impl TryFrom<TPMI_ECC_KEY_EXCHANGE> for EccKeyExchangeAlgorithm {
+    type Error = Error;
+
+    fn try_from(tpmi_ecc_key_exchange: TPMI_ECC_KEY_EXCHANGE) -> Result<Self> {
+        EccKeyExchangeAlgorithm::try_from(AlgorithmIdentifier::try_from(tpmi_ecc_key_exchange)?)
+    }
+}
@@ -1,10 +1,12 @@
 // Copyright 2020 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
 use crate::{
+    constants::tss::TPM2_RH_ACT_0,
     handles::{
         AttachedComponentTpmHandle, AuthHandle, NvIndexHandle, NvIndexTpmHandle, ObjectHandle,
         PermanentTpmHandle, TpmHandle,
     },
+    tss2_esys::TPM2_HANDLE,
     Error, Result, WrapperErrorKind,
 };
 use std::convert::TryFrom;
@@ -451,7 +453,103 @@ impl From<AttachedComponent> for AttachedComponentTpmHandle {
 }
 
 //////////////////////////////////////////////////////////////////////////////////
-// Act (authenticated timers)
-//
-// TODO: Figure out how to implement this. This is some kind of counter.
+/// Act
+///
+/// Enum describing the authenticated countdown timers (ACT) in a TPM 2.0.
 //////////////////////////////////////////////////////////////////////////////////
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Act {
+    Act0,
+    Act1,
+    Act2,
+    Act3,
+    Act4,
+    Act5,
+    Act6,
+    Act7,
+    Act8,
+    Act9,
+    ActA,
+    ActB,
+    ActC,
+    ActD,
+    ActE,
+    ActF,
+}
+
+impl Act {
+    const ACTS: [Act; 16] = [
+        Act::Act0,
+        Act::Act1,
+        Act::Act2,
+        Act::Act3,
+        Act::Act4,
+        Act::Act5,
+        Act::Act6,
+        Act::Act7,
+        Act::Act8,
+        Act::Act9,
+        Act::ActA,
+        Act::ActB,
+        Act::ActC,
+        Act::ActD,
+        Act::ActE,
+        Act::ActF,
+    ];
+
+    /// Returns the number of the authenticated countdown timer.
+    pub const fn index(&self) -> u32 {
+        *self as u32
+    }
+
+    /// Private function for looking up the authenticated
+    /// countdown timer with the number `index`.
+    fn from_index(index: u32) -> Result<Act> {
+        usize::try_from(index)
+            .ok()
+            .and_then(|index| Self::ACTS.get(index))
+            .copied()
+            .ok_or_else(|| Error::local_error(WrapperErrorKind::InvalidParam))
+    }
+}
+
+#[cfg(has_esys_act)]
+impl From<Act> for ObjectHandle {
+    fn from(act: Act) -> ObjectHandle {
+        ObjectHandle::from(crate::tss2_esys::ESYS_TR_RH_ACT_FIRST + act.index())
+    }
+}
+
+impl From<Act> for TpmHandle {
+    fn from(act: Act) -> TpmHandle {
+        // Cannot fail because all the ACT handles are in the permanent handle range.
+        TpmHandle::Permanent(PermanentTpmHandle::new(TPM2_RH_ACT_0 + act.index()).unwrap())
+    }
+}
+
+#[cfg(has_esys_act)]
+impl TryFrom<ObjectHandle> for Act {
+    type Error = Error;
+
+    fn try_from(object_handle: ObjectHandle) -> Result<Act> {
+        object_handle
+            .value()
+            .checked_sub(crate::tss2_esys::ESYS_TR_RH_ACT_FIRST)
+            .ok_or_else(|| Error::local_error(WrapperErrorKind::InvalidParam))
+            .and_then(Act::from_index)
+    }
+}
+
+impl TryFrom<TpmHandle> for Act {
+    type Error = Error;
+
+    fn try_from(tpm_handle: TpmHandle) -> Result<Act> {
+        match tpm_handle {
+            TpmHandle::Permanent(permanent_handle) => TPM2_HANDLE::from(permanent_handle)
+                .checked_sub(TPM2_RH_ACT_0)
+                .ok_or_else(|| Error::local_error(WrapperErrorKind::InvalidParam))
+                .and_then(Act::from_index),
+            _ => Err(Error::local_error(WrapperErrorKind::InvalidParam)),
+        }
+    }
+}
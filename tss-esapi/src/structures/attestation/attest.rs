@@ -109,6 +109,11 @@ impl TryFrom<TPMS_ATTEST> for Attest {
                 AttestationType::Nv => AttestInfo::Nv {
                     info: unsafe { tpms_attest.attested.nv }.try_into()?,
                 },
+                #[cfg(has_tpms_nv_digest_certify_info)]
+                AttestationType::NvDigest => AttestInfo::NvDigest {
+                    info: unsafe { tpms_attest.attested.nvDigest }.try_into()?,
+                },
+                #[cfg(not(has_tpms_nv_digest_certify_info))]
                 AttestationType::NvDigest => {
                     error!("NvDigest attestation type is currently not supported");
                     return Err(Error::local_error(WrapperErrorKind::UnsupportedParam));
@@ -18,15 +18,33 @@ use crate::{
 /// included.
 #[derive(Debug, Clone)]
 pub enum AttestInfo {
-    Certify { info: CertifyInfo },
-    Quote { info: QuoteInfo },
-    SessionAudit { info: SessionAuditInfo },
-    CommandAudit { info: CommandAuditInfo },
-    Time { info: TimeAttestInfo },
-    Creation { info: CreationInfo },
-    Nv { info: NvCertifyInfo },
+    Certify {
+        info: CertifyInfo,
+    },
+    Quote {
+        info: QuoteInfo,
+    },
+    SessionAudit {
+        info: SessionAuditInfo,
+    },
+    CommandAudit {
+        info: CommandAuditInfo,
+    },
+    Time {
+        info: TimeAttestInfo,
+    },
+    Creation {
+        info: CreationInfo,
+    },
+    Nv {
+        info: NvCertifyInfo,
+    },
     // NvDigest, the TPMS_NV_DIGEST_CERTIFY_INFO,
     // was first added in the 3.1.0 version of the tpm2-tss
+    #[cfg(has_tpms_nv_digest_certify_info)]
+    NvDigest {
+        info: crate::structures::NvDigestCertifyInfo,
+    },
 }
 
 impl From<AttestInfo> for TPMU_ATTEST {
@@ -47,6 +65,10 @@ impl From<AttestInfo> for TPMU_ATTEST {
                 creation: info.into(),
             },
             AttestInfo::Nv { info } => TPMU_ATTEST { nv: info.into() },
+            #[cfg(has_tpms_nv_digest_certify_info)]
+            AttestInfo::NvDigest { info } => TPMU_ATTEST {
+                nvDigest: info.into(),
+            },
         }
     }
 }
@@ -2,6 +2,10 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::structures::{Digest, Name};
+#[cfg(has_tpms_nv_digest_certify_info)]
+use crate::{tss2_esys::TPMS_NV_DIGEST_CERTIFY_INFO, Error, Result};
+#[cfg(has_tpms_nv_digest_certify_info)]
+use std::convert::{TryFrom, TryInto};
 
 /// This structure contains the Name and hash of the
 /// contents of the selected NV Index that is certified by
@@ -26,3 +30,25 @@ impl NvDigestCertifyInfo {
         &self.nv_digest
     }
 }
+
+#[cfg(has_tpms_nv_digest_certify_info)]
+impl From<NvDigestCertifyInfo> for TPMS_NV_DIGEST_CERTIFY_INFO {
+    fn from(nv_digest_certify_info: NvDigestCertifyInfo) -> Self {
+        TPMS_NV_DIGEST_CERTIFY_INFO {
+            indexName: nv_digest_certify_info.index_name.into(),
+            nvDigest: nv_digest_certify_info.nv_digest.into(),
+        }
+    }
+}
+
+#[cfg(has_tpms_nv_digest_certify_info)]
+impl TryFrom<TPMS_NV_DIGEST_CERTIFY_INFO> for NvDigestCertifyInfo {
+    type Error = Error;
+
+    fn try_from(tpms_nv_digest_certify_info: TPMS_NV_DIGEST_CERTIFY_INFO) -> Result<Self> {
+        Ok(NvDigestCertifyInfo {
+            index_name: tpms_nv_digest_certify_info.indexName.try_into()?,
+            nv_digest: tpms_nv_digest_certify_info.nvDigest.try_into()?,
+        })
+    }
+}
@@ -218,6 +218,10 @@ pub mod encrypted_secret {
     named_field_buffer_type!(EncryptedSecret, 256, TPM2B_ENCRYPTED_SECRET, secret);
 }
 
+pub mod event {
+    buffer_type!(Event, 1024, TPM2B_EVENT);
+}
+
 pub mod id_object {
     named_field_buffer_type!(IdObject, 256, TPM2B_ID_OBJECT, credential);
 }
@@ -230,6 +234,12 @@ pub mod initial_value {
     );
 }
 
+#[cfg(has_tpm2b_label)]
+pub mod label {
+    use crate::tss2_esys::TPM2_LABEL_MAX_BUFFER;
+    buffer_type!(Label, TPM2_LABEL_MAX_BUFFER as usize, TPM2B_LABEL);
+}
+
 pub mod max_buffer {
     use crate::tss2_esys::TPM2_MAX_DIGEST_BUFFER;
     buffer_type!(MaxBuffer, TPM2_MAX_DIGEST_BUFFER as usize, TPM2B_MAX_BUFFER);
@@ -248,6 +258,10 @@ pub mod nonce {
     buffer_type!(Nonce, 64, TPM2B_NONCE);
 }
 
+pub mod operand {
+    buffer_type!(Operand, 64, TPM2B_OPERAND);
+}
+
 pub mod private {
     use tss_esapi_sys::_PRIVATE;
     buffer_type!(Private, ::std::mem::size_of::<_PRIVATE>(), TPM2B_PRIVATE);
@@ -374,6 +388,15 @@ pub mod symmetric_key {
     buffer_type!(SymmetricKey, TPM2_MAX_SYM_KEY_BYTES as usize, TPM2B_SYM_KEY);
 }
 
+pub mod template {
+    use tss_esapi_sys::TPMT_PUBLIC;
+    buffer_type!(
+        Template,
+        ::std::mem::size_of::<TPMT_PUBLIC>(),
+        TPM2B_TEMPLATE
+    );
+}
+
 pub mod timeout {
     buffer_type!(Timeout, 8, TPM2B_TIMEOUT);
 }
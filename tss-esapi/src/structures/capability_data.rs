@@ -1,5 +1,11 @@
 // Copyright 2020 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
+#[cfg(has_esys_act)]
+use crate::structures::ActDataList;
+#[cfg(has_tpml_tagged_policy)]
+use crate::structures::TaggedPolicyList;
+#[cfg(any(not(has_tpml_tagged_policy), not(has_esys_act)))]
+use crate::WrapperErrorKind;
 use crate::{
     constants::CapabilityType,
     structures::{
@@ -7,8 +13,9 @@ use crate::{
         HandleList, PcrSelectionList, TaggedPcrPropertyList, TaggedTpmPropertyList,
     },
     tss2_esys::{TPM2_CAP, TPM2_MAX_CAP_BUFFER, TPMS_CAPABILITY_DATA, TPMU_CAPABILITIES},
-    Error, Result, WrapperErrorKind,
+    Error, Result,
 };
+#[cfg(any(not(has_tpml_tagged_policy), not(has_esys_act)))]
 use log::error;
 use std::convert::{TryFrom, TryInto};
 use std::mem::size_of;
@@ -31,8 +38,10 @@ pub enum CapabilityData {
     PcrProperties(TaggedPcrPropertyList),
     EccCurves(EccCurveList),
     // These are in the TPM TPMU_CAPABILITIES, but are not defined by esapi-2.4.1
-    // AuthPolicies(),
-    // ActData(),
+    #[cfg(has_tpml_tagged_policy)]
+    AuthPolicies(TaggedPolicyList),
+    #[cfg(has_esys_act)]
+    ActData(ActDataList),
 }
 
 pub const fn max_cap_size<T>() -> usize {
@@ -96,6 +105,20 @@ impl From<CapabilityData> for TPMS_CAPABILITY_DATA {
                     eccCurves: data.into(),
                 },
             },
+            #[cfg(has_tpml_tagged_policy)]
+            CapabilityData::AuthPolicies(data) => TPMS_CAPABILITY_DATA {
+                capability: CapabilityType::AuthPolicies.into(),
+                data: TPMU_CAPABILITIES {
+                    authPolicies: data.into(),
+                },
+            },
+            #[cfg(has_esys_act)]
+            CapabilityData::ActData(data) => TPMS_CAPABILITY_DATA {
+                capability: CapabilityType::Act.into(),
+                data: TPMU_CAPABILITIES {
+                    actData: data.into(),
+                },
+            },
         }
     }
 }
@@ -134,10 +157,20 @@ impl TryFrom<TPMS_CAPABILITY_DATA> for CapabilityData {
             CapabilityType::EccCurves => Ok(CapabilityData::EccCurves(
                 unsafe { tpms_capability_data.data.eccCurves }.try_into()?,
             )),
+            #[cfg(has_tpml_tagged_policy)]
+            CapabilityType::AuthPolicies => Ok(CapabilityData::AuthPolicies(
+                unsafe { tpms_capability_data.data.authPolicies }.try_into()?,
+            )),
+            #[cfg(not(has_tpml_tagged_policy))]
             CapabilityType::AuthPolicies => {
                 error!("AuthPolicies capability type is currently not supported");
                 Err(Error::WrapperError(WrapperErrorKind::UnsupportedParam))
             }
+            #[cfg(has_esys_act)]
+            CapabilityType::Act => Ok(CapabilityData::ActData(
+                unsafe { tpms_capability_data.data.actData }.try_into()?,
+            )),
+            #[cfg(not(has_esys_act))]
             CapabilityType::Act => {
                 error!("Act capability type is currently not supported");
                 Err(Error::WrapperError(WrapperErrorKind::UnsupportedParam))
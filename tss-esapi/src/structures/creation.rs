@@ -6,9 +6,11 @@ use crate::{
     constants::AlgorithmIdentifier,
     interface_types::algorithm::HashingAlgorithm,
     structures::{Data, Digest, Name, PcrSelectionList},
+    traits::Marshall,
     tss2_esys::{TPM2B_CREATION_DATA, TPMS_CREATION_DATA},
-    Error, Result,
+    Error, Result, ReturnCode, WrapperErrorKind,
 };
+use log::error;
 use std::convert::{TryFrom, TryInto};
 
 #[derive(Debug, Clone)]
@@ -22,6 +24,43 @@ pub struct CreationData {
     outside_info: Data,
 }
 
+impl CreationData {
+    /// Returns the PCRs that were included in the creation data
+    pub const fn pcr_select(&self) -> &PcrSelectionList {
+        &self.pcr_select
+    }
+
+    /// Returns the digest of the selected PCRs at the time of creation
+    pub const fn pcr_digest(&self) -> &Digest {
+        &self.pcr_digest
+    }
+
+    /// Returns the locality at which the object was created
+    pub const fn locality(&self) -> LocalityAttributes {
+        self.locality
+    }
+
+    /// Returns the name algorithm of the parent
+    pub const fn parent_name_alg(&self) -> Option<HashingAlgorithm> {
+        self.parent_name_alg
+    }
+
+    /// Returns the name of the parent
+    pub const fn parent_name(&self) -> &Name {
+        &self.parent_name
+    }
+
+    /// Returns the qualified name of the parent
+    pub const fn parent_qualified_name(&self) -> &Name {
+        &self.parent_qualified_name
+    }
+
+    /// Returns the outside info provided at creation
+    pub const fn outside_info(&self) -> &Data {
+        &self.outside_info
+    }
+}
+
 impl TryFrom<TPMS_CREATION_DATA> for CreationData {
     type Error = Error;
     fn try_from(tss_creation_data: TPMS_CREATION_DATA) -> Result<Self> {
@@ -63,3 +102,39 @@ impl From<CreationData> for TPMS_CREATION_DATA {
         }
     }
 }
+
+impl Marshall for CreationData {
+    const BUFFER_SIZE: usize = std::mem::size_of::<TPMS_CREATION_DATA>();
+
+    /// Produce a marshalled [`TPMS_CREATION_DATA`]
+    ///
+    /// # Details
+    /// The creation hash returned by the TPM is the digest of
+    /// this marshalled structure.
+    fn marshall(&self) -> Result<Vec<u8>> {
+        let mut buffer = vec![0; Self::BUFFER_SIZE];
+        let mut offset = 0;
+
+        ReturnCode::ensure_success(
+            unsafe {
+                crate::tss2_esys::Tss2_MU_TPMS_CREATION_DATA_Marshal(
+                    &self.clone().into(),
+                    buffer.as_mut_ptr(),
+                    Self::BUFFER_SIZE.try_into().map_err(|e| {
+                        error!("Failed to convert size of buffer to TSS size_t type: {}", e);
+                        Error::local_error(WrapperErrorKind::InvalidParam)
+                    })?,
+                    &mut offset,
+                )
+            },
+            |ret| error!("Failed to marshal CreationData: {}", ret),
+        )?;
+
+        let checked_offset = usize::try_from(offset).map_err(|e| {
+            error!("Failed to parse offset as usize: {}", e);
+            Error::local_error(WrapperErrorKind::InvalidParam)
+        })?;
+        buffer.truncate(checked_offset);
+        Ok(buffer)
+    }
+}
@@ -0,0 +1,113 @@
+// Copyright 2023 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    constants::AlgorithmIdentifier,
+    tss2_esys::{TPM2_MAX_ALG_LIST_SIZE, TPML_ALG},
+    Error, Result, WrapperErrorKind,
+};
+use log::error;
+use std::{convert::TryFrom, ops::Deref};
+
+/// A list of algorithm identifiers.
+///
+/// # Details
+/// This corresponds to TPML_ALG.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AlgorithmList {
+    algorithms: Vec<AlgorithmIdentifier>,
+}
+
+impl AlgorithmList {
+    pub const MAX_SIZE: usize = Self::calculate_max_size();
+    /// Creates a new AlgorithmList
+    pub const fn new() -> Self {
+        AlgorithmList {
+            algorithms: Vec::new(),
+        }
+    }
+
+    /// Adds an algorithm identifier to the algorithm list.
+    pub fn add(&mut self, algorithm: AlgorithmIdentifier) -> Result<()> {
+        if self.algorithms.len() + 1 > AlgorithmList::MAX_SIZE {
+            error!(
+                "Adding algorithm to list will make the list exceeded its maximum count(> {})",
+                AlgorithmList::MAX_SIZE
+            );
+            return Err(Error::local_error(WrapperErrorKind::WrongParamSize));
+        }
+        self.algorithms.push(algorithm);
+        Ok(())
+    }
+
+    /// Returns the inner type.
+    pub fn into_inner(self) -> Vec<AlgorithmIdentifier> {
+        self.algorithms
+    }
+
+    /// Private function that calculates the maximum number
+    /// elements allowed in internal storage.
+    const fn calculate_max_size() -> usize {
+        TPM2_MAX_ALG_LIST_SIZE as usize
+    }
+}
+
+impl TryFrom<TPML_ALG> for AlgorithmList {
+    type Error = Error;
+
+    fn try_from(tpml_alg: TPML_ALG) -> Result<Self> {
+        let algorithm_count = tpml_alg.count as usize;
+        if algorithm_count > Self::MAX_SIZE {
+            error!("Error: Invalid TPML_ALG count(> {})", Self::MAX_SIZE);
+            return Err(Error::local_error(WrapperErrorKind::InvalidParam));
+        }
+        tpml_alg.algorithms[..algorithm_count]
+            .iter()
+            .map(|&alg| AlgorithmIdentifier::try_from(alg))
+            .collect::<Result<Vec<AlgorithmIdentifier>>>()
+            .map(|algorithms| AlgorithmList { algorithms })
+    }
+}
+
+impl From<AlgorithmList> for TPML_ALG {
+    fn from(algorithm_list: AlgorithmList) -> Self {
+        let mut tpml_alg = TPML_ALG::default();
+        for alg in algorithm_list.algorithms {
+            tpml_alg.algorithms[tpml_alg.count as usize] = alg.into();
+            tpml_alg.count += 1;
+        }
+        tpml_alg
+    }
+}
+
+impl TryFrom<Vec<AlgorithmIdentifier>> for AlgorithmList {
+    type Error = Error;
+
+    fn try_from(algorithms: Vec<AlgorithmIdentifier>) -> Result<Self> {
+        if algorithms.len() > Self::MAX_SIZE {
+            error!("Error: Invalid TPML_ALG count(> {})", Self::MAX_SIZE);
+            return Err(Error::local_error(WrapperErrorKind::InvalidParam));
+        }
+        Ok(AlgorithmList { algorithms })
+    }
+}
+
+impl From<AlgorithmList> for Vec<AlgorithmIdentifier> {
+    fn from(algorithm_list: AlgorithmList) -> Self {
+        algorithm_list.algorithms
+    }
+}
+
+impl AsRef<[AlgorithmIdentifier]> for AlgorithmList {
+    fn as_ref(&self) -> &[AlgorithmIdentifier] {
+        self.algorithms.as_slice()
+    }
+}
+
+impl Deref for AlgorithmList {
+    type Target = Vec<AlgorithmIdentifier>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.algorithms
+    }
+}
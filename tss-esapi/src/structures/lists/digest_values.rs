@@ -4,7 +4,8 @@ use crate::interface_types::algorithm::HashingAlgorithm;
 use crate::structures::Digest;
 use crate::structures::HashAgile;
 use crate::tss2_esys::TPML_DIGEST_VALUES;
-use crate::{Error, Result};
+use crate::{Error, Result, WrapperErrorKind};
+use log::error;
 use std::collections::HashMap;
 use std::convert::{TryFrom, TryInto};
 
@@ -23,6 +24,16 @@ impl DigestValues {
     pub fn set(&mut self, alg: HashingAlgorithm, dig: Digest) {
         let _ = self.digests.insert(alg, dig);
     }
+
+    /// Returns the digest for the given hashing algorithm if one exists.
+    pub fn get(&self, alg: HashingAlgorithm) -> Option<&Digest> {
+        self.digests.get(&alg)
+    }
+
+    /// Returns the digests mapped by their hashing algorithm.
+    pub fn value(&self) -> &HashMap<HashingAlgorithm, Digest> {
+        &self.digests
+    }
 }
 
 impl TryFrom<DigestValues> for TPML_DIGEST_VALUES {
@@ -38,3 +49,24 @@ impl TryFrom<DigestValues> for TPML_DIGEST_VALUES {
         Ok(tss_digest_values)
     }
 }
+
+impl TryFrom<TPML_DIGEST_VALUES> for DigestValues {
+    type Error = Error;
+    fn try_from(tpml_digest_values: TPML_DIGEST_VALUES) -> Result<Self> {
+        let digests_count = tpml_digest_values.count as usize;
+        if digests_count > tpml_digest_values.digests.len() {
+            error!(
+                "Invalid TPML_DIGEST_VALUES count(> {})",
+                tpml_digest_values.digests.len()
+            );
+            return Err(Error::local_error(WrapperErrorKind::InvalidParam));
+        }
+
+        let mut digest_values = DigestValues::new();
+        for tpmt_ha in tpml_digest_values.digests[..digests_count].iter() {
+            let hash_agile = HashAgile::try_from(*tpmt_ha)?;
+            digest_values.set(hash_agile.algorithm(), hash_agile.digest().clone());
+        }
+        Ok(digest_values)
+    }
+}
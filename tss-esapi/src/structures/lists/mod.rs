@@ -1,5 +1,9 @@
 // Copyright 2020 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
+pub mod ac_capabilities;
+#[cfg(has_esys_act)]
+pub mod act_data;
+pub mod algorithm;
 pub mod algorithm_property;
 pub mod command_code;
 pub mod command_code_attributes;
@@ -9,4 +13,5 @@ pub mod ecc_curves;
 pub mod handles;
 pub mod pcr_selection;
 pub mod tagged_pcr_property;
+pub mod tagged_policy;
 pub mod tagged_tpm_property;
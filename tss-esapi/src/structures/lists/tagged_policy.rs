@@ -0,0 +1,112 @@
+// Copyright 2023 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    handles::TpmHandle, structures::TaggedPolicy, tss2_esys::TPMS_TAGGED_POLICY, Error, Result,
+    WrapperErrorKind,
+};
+use log::error;
+use std::{convert::TryFrom, iter::IntoIterator, ops::Deref};
+
+/// A structure holding a list of tagged policies.
+///
+/// # Details
+/// This corresponds to the TPML_TAGGED_POLICY structure.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaggedPolicyList {
+    tagged_policies: Vec<TaggedPolicy>,
+}
+
+impl TaggedPolicyList {
+    pub const MAX_SIZE: usize = Self::calculate_max_size();
+
+    /// Finds the [TaggedPolicy] in the list that belongs to the provided `handle`.
+    pub fn find(&self, handle: TpmHandle) -> Option<&TaggedPolicy> {
+        self.tagged_policies.iter().find(|tp| tp.handle() == handle)
+    }
+
+    /// Private function that calculates the maximum number
+    /// elements allowed in internal storage.
+    const fn calculate_max_size() -> usize {
+        crate::structures::capability_data::max_cap_size::<TPMS_TAGGED_POLICY>()
+    }
+}
+
+impl Deref for TaggedPolicyList {
+    type Target = Vec<TaggedPolicy>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.tagged_policies
+    }
+}
+
+impl AsRef<[TaggedPolicy]> for TaggedPolicyList {
+    fn as_ref(&self) -> &[TaggedPolicy] {
+        self.tagged_policies.as_slice()
+    }
+}
+
+impl TryFrom<Vec<TaggedPolicy>> for TaggedPolicyList {
+    type Error = Error;
+
+    fn try_from(tagged_policies: Vec<TaggedPolicy>) -> Result<Self> {
+        if tagged_policies.len() > Self::MAX_SIZE {
+            error!(
+                "Failed to convert Vec<TaggedPolicy> into TaggedPolicyList, to many items (> {})",
+                Self::MAX_SIZE
+            );
+            return Err(Error::local_error(WrapperErrorKind::InvalidParam));
+        }
+        Ok(TaggedPolicyList { tagged_policies })
+    }
+}
+
+impl IntoIterator for TaggedPolicyList {
+    type Item = TaggedPolicy;
+    type IntoIter = std::vec::IntoIter<Self::Item>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.tagged_policies.into_iter()
+    }
+}
+
+#[cfg(has_tpml_tagged_policy)]
+impl TryFrom<crate::tss2_esys::TPML_TAGGED_POLICY> for TaggedPolicyList {
+    type Error = Error;
+
+    fn try_from(tpml_tagged_policy: crate::tss2_esys::TPML_TAGGED_POLICY) -> Result<Self> {
+        let count = usize::try_from(tpml_tagged_policy.count).map_err(|e| {
+            error!(
+                "Failed to parse count in TPML_TAGGED_POLICY as usize: {}",
+                e
+            );
+            Error::local_error(WrapperErrorKind::InvalidParam)
+        })?;
+
+        if count > Self::MAX_SIZE {
+            error!(
+                "Invalid size value in TPML_TAGGED_POLICY (> {})",
+                Self::MAX_SIZE
+            );
+            return Err(Error::local_error(WrapperErrorKind::InvalidParam));
+        }
+
+        tpml_tagged_policy.policies[..count]
+            .iter()
+            .map(|&tp| TaggedPolicy::try_from(tp))
+            .collect::<Result<Vec<TaggedPolicy>>>()
+            .map(|tagged_policies| TaggedPolicyList { tagged_policies })
+    }
+}
+
+#[cfg(has_tpml_tagged_policy)]
+impl From<TaggedPolicyList> for crate::tss2_esys::TPML_TAGGED_POLICY {
+    fn from(tagged_policy_list: TaggedPolicyList) -> Self {
+        let mut tpml_tagged_policy = crate::tss2_esys::TPML_TAGGED_POLICY::default();
+        for tagged_policy in tagged_policy_list {
+            tpml_tagged_policy.policies[tpml_tagged_policy.count as usize] = tagged_policy.into();
+            tpml_tagged_policy.count += 1;
+        }
+        tpml_tagged_policy
+    }
+}
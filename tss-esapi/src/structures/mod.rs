@@ -29,19 +29,25 @@ pub use names::name::Name;
 /////////////////////////////////////////////////////////
 mod result;
 pub use result::CreateKeyResult;
+pub use result::CreateLoadedKeyResult;
 pub use result::CreatePrimaryKeyResult;
+pub use result::PcrAllocationResult;
+pub use result::SessionInfo;
 /////////////////////////////////////////////////////////
 /// The sized buffers section
 /////////////////////////////////////////////////////////
 mod buffers;
+#[cfg(has_tpm2b_label)]
+pub use self::buffers::label::Label;
 pub use self::buffers::{
     attest::AttestBuffer, auth::Auth, data::Data, digest::Digest, ecc_parameter::EccParameter,
-    encrypted_secret::EncryptedSecret, id_object::IdObject, initial_value::InitialValue,
-    max_buffer::MaxBuffer, max_nv_buffer::MaxNvBuffer, nonce::Nonce, private::Private,
-    private_key_rsa::PrivateKeyRsa, private_vendor_specific::PrivateVendorSpecific,
-    public::PublicBuffer, public_key_rsa::PublicKeyRsa, sensitive::SensitiveBuffer,
+    encrypted_secret::EncryptedSecret, event::Event, id_object::IdObject,
+    initial_value::InitialValue, max_buffer::MaxBuffer, max_nv_buffer::MaxNvBuffer, nonce::Nonce,
+    operand::Operand, private::Private, private_key_rsa::PrivateKeyRsa,
+    private_vendor_specific::PrivateVendorSpecific, public::PublicBuffer,
+    public_key_rsa::PublicKeyRsa, sensitive::SensitiveBuffer,
     sensitive_create::SensitiveCreateBuffer, sensitive_data::SensitiveData,
-    symmetric_key::SymmetricKey, timeout::Timeout,
+    symmetric_key::SymmetricKey, template::Template, timeout::Timeout,
 };
 /////////////////////////////////////////////////////////
 /// The creation section
@@ -117,6 +123,11 @@ pub mod tagged_tpm_property_list {
     pub use super::lists::tagged_tpm_property::*;
 }
 
+pub use self::algorithm_list::AlgorithmList;
+pub mod algorithm_list {
+    pub use super::lists::algorithm::*;
+}
+
 pub use algorithm_property_list::AlgorithmPropertyList;
 pub mod algorithm_property_list {
     pub use super::lists::algorithm_property::*;
@@ -131,6 +142,20 @@ pub use self::command_code_attributes_list::CommandCodeAttributesList;
 pub mod command_code_attributes_list {
     pub use super::lists::command_code_attributes::*;
 }
+pub use self::ac_capability_list::AcCapabilityList;
+pub mod ac_capability_list {
+    pub use super::lists::ac_capabilities::*;
+}
+#[cfg(has_esys_act)]
+pub use self::act_data_list::ActDataList;
+#[cfg(has_esys_act)]
+pub mod act_data_list {
+    pub use super::lists::act_data::*;
+}
+pub use self::tagged_policy_list::TaggedPolicyList;
+pub mod tagged_policy_list {
+    pub use super::lists::tagged_policy::*;
+}
 
 pub(crate) use pcr::slot_collection::PcrSlotCollection;
 /////////////////////////////////////////////////////////
@@ -148,6 +173,9 @@ pub use tickets::HashcheckTicket;
 pub use tickets::Ticket;
 pub use tickets::VerifiedTicket;
 
+mod template;
+pub use template::TemplateInput;
+
 mod schemes;
 pub use schemes::{EcDaaScheme, HashScheme, HmacScheme, XorScheme};
 
@@ -172,6 +200,7 @@ pub use tagged::{
 /// ECC structures
 /////////////////////////////////////////////////////////
 mod ecc;
+pub use ecc::algorithm_detail::AlgorithmDetailEcc;
 pub use ecc::point::EccPoint;
 /////////////////////////////////////////////////////////
 /// Signatures structures
@@ -198,7 +227,8 @@ pub use clock::{clock_info::ClockInfo, time_info::TimeInfo};
 /////////////////////////////////////////////////////////
 mod property;
 pub use property::{
-    algorithm_property::AlgorithmProperty, tagged_pcr_select::TaggedPcrSelect,
+    ac_output::AcOutput, act_data::ActData, algorithm_property::AlgorithmProperty,
+    tagged_pcr_select::TaggedPcrSelect, tagged_policy::TaggedPolicy,
     tagged_property::TaggedProperty,
 };
 
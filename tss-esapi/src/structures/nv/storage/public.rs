@@ -6,8 +6,9 @@ use crate::{
     handles::NvIndexTpmHandle,
     interface_types::algorithm::HashingAlgorithm,
     structures::Digest,
+    traits::Marshall,
     tss2_esys::{TPM2B_NV_PUBLIC, TPMS_NV_PUBLIC},
-    Error, Result, WrapperErrorKind,
+    Error, Result, ReturnCode, WrapperErrorKind,
 };
 use log::error;
 use std::convert::{TryFrom, TryInto};
@@ -92,6 +93,38 @@ impl TryFrom<NvPublic> for TPM2B_NV_PUBLIC {
     }
 }
 
+impl Marshall for NvPublic {
+    const BUFFER_SIZE: usize = std::mem::size_of::<TPMS_NV_PUBLIC>();
+
+    /// Produce a marshalled [TPMS_NV_PUBLIC]
+    fn marshall(&self) -> Result<Vec<u8>> {
+        let mut buffer = vec![0; Self::BUFFER_SIZE];
+        let mut offset = 0;
+
+        ReturnCode::ensure_success(
+            unsafe {
+                crate::tss2_esys::Tss2_MU_TPMS_NV_PUBLIC_Marshal(
+                    &TPM2B_NV_PUBLIC::try_from(self.clone())?.nvPublic,
+                    buffer.as_mut_ptr(),
+                    Self::BUFFER_SIZE.try_into().map_err(|e| {
+                        error!("Failed to convert size of buffer to TSS size_t type: {}", e);
+                        Error::local_error(WrapperErrorKind::InvalidParam)
+                    })?,
+                    &mut offset,
+                )
+            },
+            |ret| error!("Failed to marshal NvPublic: {}", ret),
+        )?;
+
+        let checked_offset = usize::try_from(offset).map_err(|e| {
+            error!("Failed to parse offset as usize: {}", e);
+            Error::local_error(WrapperErrorKind::InvalidParam)
+        })?;
+        buffer.truncate(checked_offset);
+        Ok(buffer)
+    }
+}
+
 /// Builder for NvPublic.
 ///
 ///
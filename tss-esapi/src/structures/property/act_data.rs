@@ -0,0 +1,68 @@
+// Copyright 2023 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use crate::{attributes::ActAttributes, interface_types::resource_handles::Act};
+#[cfg(has_esys_act)]
+use crate::{handles::TpmHandle, tss2_esys::TPMS_ACT_DATA, Error, Result};
+#[cfg(has_esys_act)]
+use std::convert::TryFrom;
+
+/// Struct representing the state of an authenticated countdown timer.
+///
+/// # Details
+/// This corresponds to TPMS_ACT_DATA
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ActData {
+    act: Act,
+    timeout: u32,
+    attributes: ActAttributes,
+}
+
+impl ActData {
+    /// Creates a new ActData
+    pub const fn new(act: Act, timeout: u32, attributes: ActAttributes) -> Self {
+        ActData {
+            act,
+            timeout,
+            attributes,
+        }
+    }
+
+    /// Returns the authenticated countdown timer
+    pub const fn act(&self) -> Act {
+        self.act
+    }
+
+    /// Returns the number of seconds remaining before the timer is signaled
+    pub const fn timeout(&self) -> u32 {
+        self.timeout
+    }
+
+    /// Returns the attributes of the timer
+    pub const fn attributes(&self) -> ActAttributes {
+        self.attributes
+    }
+}
+
+#[cfg(has_esys_act)]
+impl TryFrom<TPMS_ACT_DATA> for ActData {
+    type Error = Error;
+
+    fn try_from(tpms_act_data: TPMS_ACT_DATA) -> Result<Self> {
+        Ok(ActData {
+            act: Act::try_from(TpmHandle::try_from(tpms_act_data.handle)?)?,
+            timeout: tpms_act_data.timeout,
+            attributes: tpms_act_data.attributes.into(),
+        })
+    }
+}
+
+#[cfg(has_esys_act)]
+impl From<ActData> for TPMS_ACT_DATA {
+    fn from(act_data: ActData) -> Self {
+        TPMS_ACT_DATA {
+            handle: TpmHandle::from(act_data.act).into(),
+            timeout: act_data.timeout,
+            attributes: act_data.attributes.into(),
+        }
+    }
+}
@@ -0,0 +1,84 @@
+// Copyright 2023 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use crate::{
+    constants::tss::TPM2_ALG_NULL,
+    handles::TpmHandle,
+    structures::HashAgile,
+    tss2_esys::{TPMS_TAGGED_POLICY, TPMT_HA},
+    Error, Result,
+};
+use std::convert::TryFrom;
+
+/// Struct representing a tagged policy
+///
+/// # Details
+/// This corresponds to TPMS_TAGGED_POLICY
+///
+/// The TPM indicates that no policy has been set for the
+/// handle by using `TPM_ALG_NULL` as the hashing algorithm,
+/// this is represented by the policy hash being `None`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaggedPolicy {
+    handle: TpmHandle,
+    policy_hash: Option<HashAgile>,
+}
+
+impl TaggedPolicy {
+    /// Creates a new TaggedPolicy
+    ///
+    /// # Errors
+    /// * if the hashing algorithm of the `policy_hash` is not supported
+    ///   an `UnsupportedParam` wrapper error is returned.
+    pub fn new(handle: TpmHandle, policy_hash: Option<HashAgile>) -> Result<Self> {
+        if let Some(hash_agile) = &policy_hash {
+            let _ = TPMT_HA::try_from(hash_agile.clone())?;
+        }
+        Ok(TaggedPolicy {
+            handle,
+            policy_hash,
+        })
+    }
+
+    /// Returns the handle of the permanent entity the policy is associated with
+    pub const fn handle(&self) -> TpmHandle {
+        self.handle
+    }
+
+    /// Returns the policy hash, if a policy has been set
+    pub const fn policy_hash(&self) -> Option<&HashAgile> {
+        self.policy_hash.as_ref()
+    }
+}
+
+impl TryFrom<TPMS_TAGGED_POLICY> for TaggedPolicy {
+    type Error = Error;
+
+    fn try_from(tpms_tagged_policy: TPMS_TAGGED_POLICY) -> Result<Self> {
+        let policy_hash = if tpms_tagged_policy.policyHash.hashAlg == TPM2_ALG_NULL {
+            None
+        } else {
+            Some(HashAgile::try_from(tpms_tagged_policy.policyHash)?)
+        };
+        Ok(TaggedPolicy {
+            handle: TpmHandle::try_from(tpms_tagged_policy.handle)?,
+            policy_hash,
+        })
+    }
+}
+
+impl From<TaggedPolicy> for TPMS_TAGGED_POLICY {
+    fn from(tagged_policy: TaggedPolicy) -> Self {
+        TPMS_TAGGED_POLICY {
+            handle: tagged_policy.handle.into(),
+            policyHash: tagged_policy.policy_hash.map_or_else(
+                || TPMT_HA {
+                    hashAlg: TPM2_ALG_NULL,
+                    ..Default::default()
+                },
+                // Cannot fail because the hashing algorithm is validated
+                // when the TaggedPolicy is created.
+                |hash_agile| TPMT_HA::try_from(hash_agile).unwrap(),
+            ),
+        }
+    }
+}
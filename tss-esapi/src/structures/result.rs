@@ -2,8 +2,10 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{
+    attributes::SessionAttributes,
     handles::KeyHandle,
-    structures::{CreationData, CreationTicket, Digest, Private, Public},
+    interface_types::algorithm::HashingAlgorithm,
+    structures::{CreationData, CreationTicket, Digest, Nonce, Private, Public},
 };
 
 #[allow(missing_debug_implementations)]
@@ -15,6 +17,17 @@ pub struct CreateKeyResult {
     pub creation_ticket: CreationTicket,
 }
 
+/// The result of creating and loading an object with a single command.
+///
+/// # Details
+/// The `out_private` of a primary object is empty.
+#[derive(Debug, Clone)]
+pub struct CreateLoadedKeyResult {
+    pub key_handle: KeyHandle,
+    pub out_private: Private,
+    pub out_public: Public,
+}
+
 #[allow(missing_debug_implementations)]
 pub struct CreatePrimaryKeyResult {
     pub key_handle: KeyHandle,
@@ -23,3 +36,36 @@ pub struct CreatePrimaryKeyResult {
     pub creation_hash: Digest,
     pub creation_ticket: CreationTicket,
 }
+
+/// The result of a PCR allocation.
+///
+/// # Details
+/// The new allocation takes effect after the next
+/// TPM reset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PcrAllocationResult {
+    /// Indicates if the allocation succeeded
+    pub allocation_success: bool,
+    /// The maximum number of PCRs that may be in a bank
+    pub max_pcr: u32,
+    /// The number of octets required to satisfy the request
+    pub size_needed: u32,
+    /// The number of octets available for PCR banks
+    pub size_available: u32,
+}
+
+/// Information about an authorization session.
+///
+/// # Details
+/// The `nonce_tpm` changes every time the session is used, so
+/// the information only reflects the state of the session at
+/// the time it was retrieved.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SessionInfo {
+    /// The hashing algorithm used by the session
+    pub hashing_algorithm: HashingAlgorithm,
+    /// The attributes of the session
+    pub attributes: SessionAttributes,
+    /// The most recent nonce generated by the TPM for the session
+    pub nonce_tpm: Nonce,
+}
@@ -0,0 +1,71 @@
+// Copyright 2023 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+#[cfg(has_tpm2b_label)]
+use crate::structures::Label;
+use crate::{
+    structures::{Public, Template},
+    traits::Marshall,
+    Error, Result,
+};
+use std::convert::TryFrom;
+
+/// The template of an object created with
+/// [Context::create_loaded](crate::Context::create_loaded).
+///
+/// # Details
+/// For ordinary and primary objects the template is a [Public]
+/// structure. For objects created under a derivation parent the
+/// unique field of the [Public] structure is replaced by a `TPMS_DERIVE`
+/// structure holding the `label` and `context` from which the object
+/// is derived, the unique field of `public` is therefore ignored.
+/// Derivation templates require TSS version 3.1.0 or later.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TemplateInput {
+    Public(Public),
+    #[cfg(has_tpm2b_label)]
+    Derive {
+        public: Public,
+        label: Label,
+        context: Label,
+    },
+}
+
+impl From<Public> for TemplateInput {
+    fn from(public: Public) -> Self {
+        TemplateInput::Public(public)
+    }
+}
+
+impl TryFrom<TemplateInput> for Template {
+    type Error = Error;
+
+    fn try_from(template_input: TemplateInput) -> Result<Self> {
+        match template_input {
+            TemplateInput::Public(public) => Template::try_from(public.marshall()?),
+            #[cfg(has_tpm2b_label)]
+            TemplateInput::Derive {
+                public,
+                label,
+                context,
+            } => {
+                // The marshalled unique field is located at the end of the
+                // marshalled public structure.
+                let unique_size = match &public {
+                    Public::Rsa { unique, .. } => 2 + unique.len(),
+                    Public::KeyedHash { unique, .. } | Public::SymCipher { unique, .. } => {
+                        2 + unique.len()
+                    }
+                    Public::Ecc { unique, .. } => 4 + unique.x().len() + unique.y().len(),
+                };
+                let mut buffer = public.marshall()?;
+                buffer.truncate(buffer.len() - unique_size);
+                // The label and the context are marshalled as TPM2B_LABEL.
+                for label in [label, context] {
+                    buffer.extend_from_slice(&(label.len() as u16).to_be_bytes());
+                    buffer.extend_from_slice(label.as_bytes());
+                }
+                Template::try_from(buffer)
+            }
+        }
+    }
+}
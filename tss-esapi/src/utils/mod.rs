@@ -94,6 +94,39 @@ impl TryFrom<TpmsContext> for TPMS_CONTEXT {
     }
 }
 
+/// Rust native wrapper for serialized ESYS object metadata.
+///
+/// The metadata of an ESYS object, such as the public area and the name, can be serialized
+/// with [Context::tr_serialize](crate::Context::tr_serialize) and restored later with
+/// [Context::tr_deserialize](crate::Context::tr_deserialize) without communicating with the TPM.
+/// `SerializedEsysTr` implements the `Serialize` and `Deserialize` defined by `serde` so that
+/// the metadata can be persisted.
+///
+/// The format of the serialized data is defined by the TSS and is not interpreted by this crate.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct SerializedEsysTr {
+    buffer: Vec<u8>,
+}
+
+impl SerializedEsysTr {
+    /// Returns the serialized data as a slice of bytes.
+    pub fn as_bytes(&self) -> &[u8] {
+        self.buffer.as_slice()
+    }
+}
+
+impl From<Vec<u8>> for SerializedEsysTr {
+    fn from(buffer: Vec<u8>) -> Self {
+        SerializedEsysTr { buffer }
+    }
+}
+
+impl From<SerializedEsysTr> for Vec<u8> {
+    fn from(serialized_esys_tr: SerializedEsysTr) -> Self {
+        serialized_esys_tr.buffer
+    }
+}
+
 /// Create the [Public] structure for a restricted decryption key.
 ///
 /// * `symmetric` - Cipher to be used for decrypting children of the key
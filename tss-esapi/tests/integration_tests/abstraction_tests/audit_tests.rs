@@ -0,0 +1,90 @@
+// Copyright 2023 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use sha2::{Digest as _, Sha256};
+use std::convert::TryFrom;
+use tss_esapi::{
+    abstraction::audit::{compute_audit_digest, AuditEntry},
+    constants::CommandCode,
+    interface_types::algorithm::HashingAlgorithm,
+    structures::Digest,
+    Error, WrapperErrorKind,
+};
+
+fn sha256(data: &[&[u8]]) -> Digest {
+    let mut hasher = Sha256::new();
+    data.iter().for_each(|d| hasher.update(d));
+    Digest::try_from(hasher.finalize().to_vec()).unwrap()
+}
+
+#[test]
+fn test_empty_audit_digest() {
+    let audit_digest = compute_audit_digest(HashingAlgorithm::Sha256, &[])
+        .expect("Failed to compute audit digest");
+    assert_eq!(&[0u8; 32][..], audit_digest.as_bytes());
+}
+
+#[test]
+fn test_audit_digest() {
+    let first_entry = AuditEntry::new(sha256(&[&[1]]), sha256(&[&[2]]));
+    let second_entry = AuditEntry::new(sha256(&[&[3]]), sha256(&[&[4]]));
+
+    let first_digest = sha256(&[
+        &[0u8; 32],
+        first_entry.cp_hash().as_bytes(),
+        first_entry.rp_hash().as_bytes(),
+    ]);
+    let expected_digest = sha256(&[
+        first_digest.as_bytes(),
+        second_entry.cp_hash().as_bytes(),
+        second_entry.rp_hash().as_bytes(),
+    ]);
+
+    let audit_digest = compute_audit_digest(HashingAlgorithm::Sha256, &[first_entry, second_entry])
+        .expect("Failed to compute audit digest");
+    assert_eq!(expected_digest, audit_digest);
+}
+
+#[test]
+fn test_audit_digest_with_mismatched_hash_size() {
+    let entry = AuditEntry::new(sha256(&[&[1]]), sha256(&[&[2]]));
+    assert_eq!(
+        Err(Error::WrapperError(WrapperErrorKind::InconsistentParams)),
+        compute_audit_digest(HashingAlgorithm::Sha1, &[entry])
+    );
+}
+
+#[test]
+fn test_compute_audit_entry() {
+    let command_parameters = [0x00, 0x08];
+    let response_parameters = [0x00, 0x02, 0xab, 0xcd];
+    let entry = AuditEntry::compute(
+        HashingAlgorithm::Sha256,
+        CommandCode::GetRandom,
+        &[],
+        &command_parameters,
+        &response_parameters,
+    )
+    .expect("Failed to compute audit entry");
+
+    let command_code = [0x00, 0x00, 0x01, 0x7b];
+    assert_eq!(
+        &sha256(&[&command_code, &command_parameters]),
+        entry.cp_hash()
+    );
+    assert_eq!(
+        &sha256(&[
+            &[0x00, 0x00, 0x00, 0x00],
+            &command_code,
+            &response_parameters
+        ]),
+        entry.rp_hash()
+    );
+}
+
+#[test]
+fn test_unsupported_hashing_algorithm() {
+    assert_eq!(
+        Err(Error::WrapperError(WrapperErrorKind::UnsupportedParam)),
+        compute_audit_digest(HashingAlgorithm::Null, &[])
+    );
+}
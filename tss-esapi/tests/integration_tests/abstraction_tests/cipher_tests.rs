@@ -0,0 +1,133 @@
+// Copyright 2023 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use std::convert::{TryFrom, TryInto};
+use tss_esapi::{
+    abstraction::cipher::{
+        encrypt_decrypt, encrypt_decrypt_with_command, Cipher, EncryptDecryptCommand,
+    },
+    attributes::ObjectAttributesBuilder,
+    handles::KeyHandle,
+    interface_types::{
+        algorithm::{HashingAlgorithm, PublicAlgorithm, SymmetricMode},
+        resource_handles::Hierarchy,
+        session_handles::AuthSession,
+    },
+    structures::{InitialValue, MaxBuffer, PublicBuilder},
+    Context,
+};
+
+use crate::common::create_ctx_without_session;
+
+fn create_symmetric_key(context: &mut Context) -> KeyHandle {
+    let object_attributes = ObjectAttributesBuilder::new()
+        .with_user_with_auth(true)
+        .with_sign_encrypt(true)
+        .with_decrypt(true)
+        .with_sensitive_data_origin(true)
+        .build()
+        .expect("Failed to create object attributes for symmetric key");
+    let symmetric_key_public = PublicBuilder::new()
+        .with_public_algorithm(PublicAlgorithm::SymCipher)
+        .with_name_hashing_algorithm(HashingAlgorithm::Sha256)
+        .with_object_attributes(object_attributes)
+        .with_symmetric_cipher_parameters(Cipher::aes_128_cfb().try_into().unwrap())
+        .with_symmetric_cipher_unique_identifier(Default::default())
+        .build()
+        .expect("Failed to create public for symmetric key public");
+    context
+        .create_primary(
+            Hierarchy::Owner,
+            symmetric_key_public,
+            None,
+            None,
+            None,
+            None,
+        )
+        .expect("Failed to create symmetric key")
+        .key_handle
+}
+
+#[test]
+fn test_encrypt_decrypt() {
+    let mut context = create_ctx_without_session();
+    context.set_sessions((Some(AuthSession::Password), None, None));
+    let symmetric_key_handle = create_symmetric_key(&mut context);
+
+    let initial_value = InitialValue::try_from(vec![0xa5; 16]).unwrap();
+    let data = MaxBuffer::try_from("There is no spoon".as_bytes().to_vec()).unwrap();
+
+    let (encrypted_data, _) = encrypt_decrypt(
+        &mut context,
+        symmetric_key_handle,
+        false,
+        SymmetricMode::Cfb,
+        data.clone(),
+        initial_value.clone(),
+    )
+    .expect("Failed to encrypt data");
+    assert_ne!(data, encrypted_data);
+
+    let (decrypted_data, _) = encrypt_decrypt(
+        &mut context,
+        symmetric_key_handle,
+        true,
+        SymmetricMode::Cfb,
+        encrypted_data,
+        initial_value,
+    )
+    .expect("Failed to decrypt data");
+    assert_eq!(data, decrypted_data);
+}
+
+#[test]
+fn test_encrypt_decrypt_commands() {
+    let mut context = create_ctx_without_session();
+    context.set_sessions((Some(AuthSession::Password), None, None));
+    let symmetric_key_handle = create_symmetric_key(&mut context);
+
+    // The simulator supports TPM2_EncryptDecrypt2, which is the preferred command.
+    assert_eq!(
+        EncryptDecryptCommand::EncryptDecrypt2,
+        EncryptDecryptCommand::select(&mut context).expect("Failed to select the command")
+    );
+
+    let initial_value = InitialValue::try_from(vec![0xa5; 16]).unwrap();
+    let data = MaxBuffer::try_from("There is no spoon".as_bytes().to_vec()).unwrap();
+
+    // Data encrypted with the fallback command can be decrypted with the
+    // preferred one and the other way around.
+    for (encrypt_command, decrypt_command) in [
+        (
+            EncryptDecryptCommand::EncryptDecrypt,
+            EncryptDecryptCommand::EncryptDecrypt2,
+        ),
+        (
+            EncryptDecryptCommand::EncryptDecrypt2,
+            EncryptDecryptCommand::EncryptDecrypt,
+        ),
+    ] {
+        let (encrypted_data, _) = encrypt_decrypt_with_command(
+            &mut context,
+            encrypt_command,
+            symmetric_key_handle,
+            false,
+            SymmetricMode::Cfb,
+            data.clone(),
+            initial_value.clone(),
+        )
+        .expect("Failed to encrypt data");
+        assert_ne!(data, encrypted_data);
+
+        let (decrypted_data, _) = encrypt_decrypt_with_command(
+            &mut context,
+            decrypt_command,
+            symmetric_key_handle,
+            true,
+            SymmetricMode::Cfb,
+            encrypted_data,
+            initial_value.clone(),
+        )
+        .expect("Failed to decrypt data");
+        assert_eq!(data, decrypted_data);
+    }
+}
@@ -0,0 +1,132 @@
+// Copyright 2023 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use sha2::{Digest as _, Sha256};
+use std::convert::TryFrom;
+use tss_esapi::{
+    abstraction::creation::CertifiedKeyCreation,
+    interface_types::{algorithm::HashingAlgorithm, resource_handles::Hierarchy},
+    structures::{Data, Digest, Name, PcrSelectionListBuilder, PcrSlot, SignatureScheme},
+    Context, Error, WrapperErrorKind,
+};
+
+use crate::common::{create_ctx_with_session, decryption_key_pub, signing_key_pub};
+
+fn certified_key_creation(
+    context: &mut Context,
+    qualifying_data: &Data,
+) -> (CertifiedKeyCreation, Digest, Name) {
+    let pcr_selection_list = PcrSelectionListBuilder::new()
+        .with_selection(HashingAlgorithm::Sha256, &[PcrSlot::Slot0, PcrSlot::Slot1])
+        .build()
+        .expect("Failed to create PcrSelectionList");
+
+    let sign_key_handle = context
+        .create_primary(Hierarchy::Owner, signing_key_pub(), None, None, None, None)
+        .unwrap()
+        .key_handle;
+    let parent_handle = context
+        .create_primary(
+            Hierarchy::Owner,
+            decryption_key_pub(),
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap()
+        .key_handle;
+    let parent_name = context.tr_get_name(parent_handle.into()).unwrap();
+
+    let (_, _, pcr_values) = context
+        .pcr_read(pcr_selection_list.clone())
+        .expect("Failed to read PCRs");
+    let mut hasher = Sha256::new();
+    pcr_values
+        .value()
+        .iter()
+        .for_each(|pcr_value| hasher.update(pcr_value.as_bytes()));
+    let pcr_digest = Digest::try_from(hasher.finalize().to_vec()).unwrap();
+
+    let create_key_result = context
+        .create(
+            parent_handle,
+            signing_key_pub(),
+            None,
+            None,
+            None,
+            Some(pcr_selection_list),
+        )
+        .expect("Failed to create key");
+    let key_handle = context
+        .load(
+            parent_handle,
+            create_key_result.out_private.clone(),
+            create_key_result.out_public.clone(),
+        )
+        .expect("Failed to load key");
+
+    let certified_key_creation = CertifiedKeyCreation::certify(
+        context,
+        sign_key_handle,
+        key_handle,
+        create_key_result.into(),
+        qualifying_data.clone(),
+        SignatureScheme::Null,
+    )
+    .expect("Failed to certify key creation");
+
+    (certified_key_creation, pcr_digest, parent_name)
+}
+
+#[test]
+fn test_verify_certified_key_creation() {
+    let mut context = create_ctx_with_session();
+    let qualifying_data = Data::try_from(vec![0xff; 16]).unwrap();
+
+    let (certified_key_creation, pcr_digest, parent_name) =
+        certified_key_creation(&mut context, &qualifying_data);
+
+    certified_key_creation
+        .verify(&qualifying_data, &pcr_digest, &parent_name)
+        .expect("Failed to verify certified key creation");
+}
+
+#[test]
+fn test_verify_certified_key_creation_mismatch() {
+    let mut context = create_ctx_with_session();
+    let qualifying_data = Data::try_from(vec![0xff; 16]).unwrap();
+
+    let (certified_key_creation, pcr_digest, parent_name) =
+        certified_key_creation(&mut context, &qualifying_data);
+
+    assert_eq!(
+        certified_key_creation
+            .verify(
+                &Data::try_from(vec![0xee; 16]).unwrap(),
+                &pcr_digest,
+                &parent_name
+            )
+            .unwrap_err(),
+        Error::WrapperError(WrapperErrorKind::InconsistentParams)
+    );
+    assert_eq!(
+        certified_key_creation
+            .verify(
+                &qualifying_data,
+                &Digest::try_from(vec![0xab; 32]).unwrap(),
+                &parent_name
+            )
+            .unwrap_err(),
+        Error::WrapperError(WrapperErrorKind::InconsistentParams)
+    );
+    assert_eq!(
+        certified_key_creation
+            .verify(
+                &qualifying_data,
+                &pcr_digest,
+                &Name::try_from(vec![0x00, 0x0b, 0xab, 0xcd]).unwrap()
+            )
+            .unwrap_err(),
+        Error::WrapperError(WrapperErrorKind::InconsistentParams)
+    );
+}
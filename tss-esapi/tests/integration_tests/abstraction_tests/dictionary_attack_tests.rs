@@ -0,0 +1,99 @@
+// Copyright 2023 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use std::{
+    convert::{TryFrom, TryInto},
+    time::Duration,
+};
+use tss_esapi::{
+    abstraction::dictionary_attack::LockoutStatus,
+    constants::{
+        tss::{TPM2_RH_NULL, TPM2_ST_HASHCHECK},
+        PropertyTag,
+    },
+    interface_types::resource_handles::{Hierarchy, Lockout},
+    structures::{Auth, Digest, SignatureScheme},
+    tss2_esys::TPMT_TK_HASHCHECK,
+};
+
+use crate::common::{create_ctx_with_session, signing_key_pub};
+
+#[test]
+fn test_read_lockout_status() {
+    let mut context = create_ctx_with_session();
+    let mut read_property = |property: PropertyTag| {
+        context
+            .get_tpm_property(property)
+            .expect("Failed to get TPM property")
+            .expect("The TPM did not report the property")
+    };
+    let initial_max_auth_fail = read_property(PropertyTag::MaxAuthFail);
+    let initial_lockout_interval = read_property(PropertyTag::LockoutInterval);
+    let initial_lockout_recovery = read_property(PropertyTag::LockoutRecovery);
+
+    context
+        .dictionary_attack_parameters(Lockout::Lockout, 32, 1000, 2000)
+        .expect("Failed to set the dictionary attack parameters");
+    context
+        .dictionary_attack_lock_reset(Lockout::Lockout)
+        .expect("Failed to reset the dictionary attack lockout");
+
+    let lockout_status =
+        LockoutStatus::read(&mut context).expect("Failed to read the lockout status");
+    assert_eq!(lockout_status.lockout_counter(), 0);
+    assert_eq!(lockout_status.max_auth_fail(), 32);
+    assert_eq!(lockout_status.lockout_interval(), Duration::from_secs(1000));
+    assert_eq!(lockout_status.lockout_recovery(), Duration::from_secs(2000));
+    assert!(!lockout_status.is_locked_out());
+
+    // Using a key that is protected against dictionary attacks
+    // with the wrong auth value increments the lockout counter.
+    let key_handle = context
+        .create_primary(
+            Hierarchy::Owner,
+            signing_key_pub(),
+            Some(Auth::try_from(vec![1, 2, 3]).unwrap()),
+            None,
+            None,
+            None,
+        )
+        .expect("Call to create_primary failed")
+        .key_handle;
+    context
+        .tr_set_auth(key_handle.into(), Auth::try_from(vec![4, 5, 6]).unwrap())
+        .expect("Call to tr_set_auth failed");
+    let _ = context
+        .sign(
+            key_handle,
+            Digest::try_from(vec![0xaa; 32]).unwrap(),
+            SignatureScheme::Null,
+            TPMT_TK_HASHCHECK {
+                tag: TPM2_ST_HASHCHECK,
+                hierarchy: TPM2_RH_NULL,
+                digest: Default::default(),
+            }
+            .try_into()
+            .unwrap(),
+        )
+        .expect_err("Signing with the wrong auth value did not fail");
+    context
+        .flush_context(key_handle.into())
+        .expect("Call to flush_context failed");
+
+    let lockout_status =
+        LockoutStatus::read(&mut context).expect("Failed to read the lockout status");
+    assert_eq!(lockout_status.lockout_counter(), 1);
+    assert!(!lockout_status.is_locked_out());
+
+    // Restore the initial state
+    context
+        .dictionary_attack_lock_reset(Lockout::Lockout)
+        .expect("Failed to reset the dictionary attack lockout");
+    context
+        .dictionary_attack_parameters(
+            Lockout::Lockout,
+            initial_max_auth_fail,
+            initial_lockout_interval,
+            initial_lockout_recovery,
+        )
+        .expect("Failed to restore the dictionary attack parameters");
+}
@@ -0,0 +1,194 @@
+// Copyright 2023 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use sha2::{Digest as _, Sha256};
+use std::{convert::TryFrom, io::Write};
+use tss_esapi::{
+    abstraction::field_upgrade::{read_firmware, FieldUpgradeTpm, FirmwareUpload},
+    constants::tss::{TPM2_ALG_NULL, TPM2_ALG_SHA256},
+    interface_types::{algorithm::HashingAlgorithm, resource_handles::Platform},
+    structures::{Digest, HashAgile, MaxBuffer},
+    Error, Result, WrapperErrorKind,
+};
+
+use crate::common::{create_ctx_with_session, untrusted_manifest};
+
+const BLOCK_SIZE: usize = 64;
+
+fn sha256_hash_agile(data: &[u8]) -> HashAgile {
+    HashAgile::new(
+        HashingAlgorithm::Sha256,
+        Digest::try_from(Sha256::digest(data).to_vec()).unwrap(),
+    )
+}
+
+/// Creates the blocks of a firmware image.
+///
+/// Each block starts with the marshalled `TPMT_HA` of the next block,
+/// the last block starts with a `TPMT_HA` with a null algorithm.
+/// The remainder of a block is filled with `payload` bytes, so that
+/// all blocks but the last one are `BLOCK_SIZE` bytes long.
+fn firmware_image(block_count: usize, payload: u8) -> Vec<Vec<u8>> {
+    let mut blocks: Vec<Vec<u8>> = Vec::new();
+    for index in (0..block_count).rev() {
+        let mut block = match blocks.first() {
+            Some(next_block) => [
+                &TPM2_ALG_SHA256.to_be_bytes()[..],
+                Sha256::digest(next_block).as_slice(),
+            ]
+            .concat(),
+            None => TPM2_ALG_NULL.to_be_bytes().to_vec(),
+        };
+        let block_size = if blocks.is_empty() {
+            block.len() + 5
+        } else {
+            BLOCK_SIZE
+        };
+        block.resize(block_size, payload.wrapping_add(index as u8));
+        blocks.insert(0, block);
+    }
+    blocks
+}
+
+/// A TPM that verifies the digests of the blocks it receives
+/// the way the TPM does during a field upgrade.
+struct FakeTpm {
+    first_digest: HashAgile,
+    expected_digest: Option<HashAgile>,
+    received: Vec<Vec<u8>>,
+    firmware: Vec<Vec<u8>>,
+}
+
+impl FakeTpm {
+    fn new(fu_digest: HashAgile, firmware: Vec<Vec<u8>>) -> Self {
+        FakeTpm {
+            first_digest: fu_digest.clone(),
+            expected_digest: Some(fu_digest),
+            received: Vec::new(),
+            firmware,
+        }
+    }
+}
+
+impl FieldUpgradeTpm for FakeTpm {
+    fn max_block_size(&mut self) -> Result<usize> {
+        Ok(BLOCK_SIZE)
+    }
+
+    fn field_upgrade_data(&mut self, fu_data: MaxBuffer) -> Result<(Option<HashAgile>, HashAgile)> {
+        if self.expected_digest != Some(sha256_hash_agile(&fu_data)) {
+            return Err(Error::WrapperError(WrapperErrorKind::InconsistentParams));
+        }
+        let algorithm = u16::from_be_bytes([fu_data[0], fu_data[1]]);
+        self.expected_digest = if algorithm == TPM2_ALG_NULL {
+            None
+        } else {
+            Some(HashAgile::new(
+                HashingAlgorithm::Sha256,
+                Digest::from_bytes(&fu_data[2..34]).unwrap(),
+            ))
+        };
+        self.received.push(fu_data.to_vec());
+        Ok((self.expected_digest.clone(), self.first_digest.clone()))
+    }
+
+    fn firmware_read(&mut self, sequence_number: u32) -> Result<MaxBuffer> {
+        Ok(self
+            .firmware
+            .get(sequence_number as usize)
+            .map(|block| MaxBuffer::try_from(block.clone()).unwrap())
+            .unwrap_or_default())
+    }
+}
+
+#[test]
+fn test_start_with_untrusted_manifest() {
+    let mut context = create_ctx_with_session();
+    let (key_handle, fu_digest, manifest_signature) = untrusted_manifest(&mut context);
+
+    let _ = FirmwareUpload::start(
+        &mut context,
+        Platform::Platform,
+        key_handle,
+        fu_digest,
+        manifest_signature,
+    )
+    .expect_err("Starting a firmware upload with an untrusted manifest did not fail");
+}
+
+#[test]
+fn test_digest_chaining() {
+    let image = firmware_image(3, 0x10);
+    let first_digest = sha256_hash_agile(&image[0]);
+    let mut tpm = FakeTpm::new(first_digest.clone(), Vec::new());
+    let mut upload = FirmwareUpload::new(&mut tpm).expect("Failed to create upload");
+    assert_eq!(upload.block_size(), BLOCK_SIZE);
+    assert_eq!(upload.first_digest(), None);
+    assert_eq!(upload.next_digest(), None);
+    assert!(!upload.is_complete());
+
+    for (index, block) in image.iter().enumerate() {
+        upload.send_block(block).expect("Failed to send block");
+        assert_eq!(upload.first_digest(), Some(&first_digest));
+        let expected_next_digest = image.get(index + 1).map(|next| sha256_hash_agile(next));
+        assert_eq!(upload.next_digest(), expected_next_digest.as_ref());
+        assert_eq!(upload.is_complete(), expected_next_digest.is_none());
+    }
+
+    assert_eq!(
+        upload.send_block(&image[0]),
+        Err(Error::WrapperError(WrapperErrorKind::InvalidHandleState))
+    );
+}
+
+#[test]
+fn test_block_size() {
+    let image = firmware_image(2, 0x20);
+    let mut tpm = FakeTpm::new(sha256_hash_agile(&image[0]), Vec::new());
+    let mut upload = FirmwareUpload::new(&mut tpm).expect("Failed to create upload");
+
+    assert_eq!(
+        upload.send_block(&[0xff; BLOCK_SIZE + 1]),
+        Err(Error::WrapperError(WrapperErrorKind::WrongParamSize))
+    );
+    assert_eq!(upload.first_digest(), None);
+    assert!(!upload.is_complete());
+}
+
+#[test]
+fn test_write_image() {
+    let image = firmware_image(4, 0x30);
+    let mut tpm = FakeTpm::new(sha256_hash_agile(&image[0]), Vec::new());
+    let mut upload = FirmwareUpload::new(&mut tpm).expect("Failed to create upload");
+
+    // The image is split into blocks of the TPM input buffer size.
+    upload
+        .write_all(&image.concat())
+        .expect("Failed to write firmware image");
+    assert!(upload.is_complete());
+    let _ = upload
+        .write(&[0xff])
+        .expect_err("Writing to a completed upload did not fail");
+    assert_eq!(tpm.received, image);
+}
+
+#[test]
+fn test_read_firmware() {
+    let firmware = firmware_image(3, 0x40);
+    let mut tpm = FakeTpm::new(sha256_hash_agile(&firmware[0]), firmware.clone());
+
+    let blocks = read_firmware(&mut tpm).expect("Failed to read firmware");
+    assert_eq!(
+        blocks
+            .iter()
+            .map(|block| block.to_vec())
+            .collect::<Vec<_>>(),
+        firmware
+    );
+
+    // The firmware that has been read can be sent back to the TPM.
+    let mut upload = FirmwareUpload::new(&mut tpm).expect("Failed to create upload");
+    for block in &blocks {
+        upload.send_block(block).expect("Failed to send block");
+    }
+    assert!(upload.is_complete());
+}
@@ -1,9 +1,20 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
 mod ak_tests;
+mod audit_tests;
+mod cipher_tests;
+mod creation_tests;
+mod dictionary_attack_tests;
 mod ek_tests;
+mod field_upgrade_tests;
 mod nv_tests;
 mod pcr_data_tests;
 mod pcr_tests;
+mod physical_presence_tests;
+mod policy_tests;
 mod public_tests;
+mod reparent_tests;
+mod self_test_tests;
+mod sequence_tests;
 mod transient_key_context_tests;
+mod x509_tests;
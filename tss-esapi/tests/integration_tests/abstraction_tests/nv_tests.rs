@@ -11,13 +11,14 @@ use tss_esapi::{
     handles::{NvIndexHandle, NvIndexTpmHandle},
     interface_types::{
         algorithm::HashingAlgorithm,
-        resource_handles::{NvAuth, Provision},
+        resource_handles::{Hierarchy, NvAuth, Provision},
+        session_handles::AuthSession,
     },
-    structures::{MaxNvBuffer, NvPublicBuilder},
+    structures::{Data, MaxNvBuffer, NvPublicBuilder, SignatureScheme},
     Context,
 };
 
-use crate::common::create_ctx_with_session;
+use crate::common::{create_ctx_with_session, signing_key_pub};
 
 fn write_nv_index(context: &mut Context, nv_index: NvIndexTpmHandle) -> NvIndexHandle {
     // Create owner nv public.
@@ -181,3 +182,232 @@ fn write() {
         .nv_undefine_space(Provision::Owner, owner_nv_index_handle.into())
         .expect("Call to nv_undefine_space failed");
 }
+
+#[test]
+fn verify_nv_certify_info() {
+    let mut context = create_ctx_with_session();
+
+    let nv_index = NvIndexTpmHandle::new(0x01500015).unwrap();
+
+    let sign_key_handle = context
+        .create_primary(Hierarchy::Owner, signing_key_pub(), None, None, None, None)
+        .expect("Call to create_primary failed")
+        .key_handle;
+
+    let owner_nv_index_handle = write_nv_index(&mut context, nv_index);
+
+    let (nv_public, _) = context
+        .nv_read_public(owner_nv_index_handle)
+        .expect("Call to nv_read_public failed");
+    let qualifying_data = Data::try_from(vec![0xff; 16]).unwrap();
+
+    let nv_certify_result = context.execute_with_sessions(
+        (
+            Some(AuthSession::Password),
+            Some(AuthSession::Password),
+            None,
+        ),
+        |ctx| {
+            ctx.nv_certify(
+                sign_key_handle,
+                NvAuth::Owner,
+                owner_nv_index_handle,
+                qualifying_data.clone(),
+                SignatureScheme::Null,
+                7,
+                1024,
+            )
+        },
+    );
+
+    context
+        .nv_undefine_space(Provision::Owner, owner_nv_index_handle)
+        .expect("Call to nv_undefine_space failed");
+
+    let (attest, _) = nv_certify_result.expect("Call to nv_certify failed");
+
+    nv::verify_nv_certify_info(
+        &attest,
+        &qualifying_data,
+        &nv_public,
+        1024,
+        &[1, 2, 3, 4, 5, 6, 7],
+        HashingAlgorithm::Sha256,
+    )
+    .expect("Failed to verify the certified NV contents");
+
+    // Wrong offset
+    let _ = nv::verify_nv_certify_info(
+        &attest,
+        &qualifying_data,
+        &nv_public,
+        0,
+        &[1, 2, 3, 4, 5, 6, 7],
+        HashingAlgorithm::Sha256,
+    )
+    .unwrap_err();
+
+    // Wrong contents
+    let _ = nv::verify_nv_certify_info(
+        &attest,
+        &qualifying_data,
+        &nv_public,
+        1024,
+        &[7, 6, 5, 4, 3, 2, 1],
+        HashingAlgorithm::Sha256,
+    )
+    .unwrap_err();
+
+    // Wrong qualifying data
+    let _ = nv::verify_nv_certify_info(
+        &attest,
+        &Data::default(),
+        &nv_public,
+        1024,
+        &[1, 2, 3, 4, 5, 6, 7],
+        HashingAlgorithm::Sha256,
+    )
+    .unwrap_err();
+
+    // Wrong NV index, the public area as defined lacks the written attribute
+    let unwritten_nv_public = NvPublicBuilder::new()
+        .with_nv_index(nv_index)
+        .with_index_name_algorithm(HashingAlgorithm::Sha256)
+        .with_index_attributes(
+            NvIndexAttributesBuilder::new()
+                .with_owner_write(true)
+                .with_owner_read(true)
+                .with_pp_read(true)
+                .build()
+                .unwrap(),
+        )
+        .with_data_area_size(1540)
+        .build()
+        .unwrap();
+    let _ = nv::verify_nv_certify_info(
+        &attest,
+        &qualifying_data,
+        &unwritten_nv_public,
+        1024,
+        &[1, 2, 3, 4, 5, 6, 7],
+        HashingAlgorithm::Sha256,
+    )
+    .unwrap_err();
+}
+
+#[cfg(has_tpms_nv_digest_certify_info)]
+#[test]
+fn verify_nv_digest_certify_info() {
+    let mut context = create_ctx_with_session();
+
+    let nv_index = NvIndexTpmHandle::new(0x01500016).unwrap();
+    let nv_contents = [1, 2, 3, 4, 5, 6, 7];
+
+    let sign_key_handle = context
+        .create_primary(Hierarchy::Owner, signing_key_pub(), None, None, None, None)
+        .expect("Call to create_primary failed")
+        .key_handle;
+
+    let nv_index_handle = context
+        .nv_define_space(
+            Provision::Owner,
+            None,
+            NvPublicBuilder::new()
+                .with_nv_index(nv_index)
+                .with_index_name_algorithm(HashingAlgorithm::Sha256)
+                .with_index_attributes(
+                    NvIndexAttributesBuilder::new()
+                        .with_owner_write(true)
+                        .with_owner_read(true)
+                        .build()
+                        .unwrap(),
+                )
+                .with_data_area_size(nv_contents.len())
+                .build()
+                .unwrap(),
+        )
+        .expect("Call to nv_define_space failed");
+    context
+        .nv_write(
+            NvAuth::Owner,
+            nv_index_handle,
+            MaxNvBuffer::try_from(nv_contents.to_vec()).unwrap(),
+            0,
+        )
+        .expect("Call to nv_write failed");
+
+    let (nv_public, _) = context
+        .nv_read_public(nv_index_handle)
+        .expect("Call to nv_read_public failed");
+    let qualifying_data = Data::try_from(vec![0xee; 16]).unwrap();
+
+    // A size and offset of zero certifies the digest of the contents.
+    let nv_certify_result = context.execute_with_sessions(
+        (
+            Some(AuthSession::Password),
+            Some(AuthSession::Password),
+            None,
+        ),
+        |ctx| {
+            ctx.nv_certify(
+                sign_key_handle,
+                NvAuth::Owner,
+                nv_index_handle,
+                qualifying_data.clone(),
+                SignatureScheme::Null,
+                0,
+                0,
+            )
+        },
+    );
+
+    context
+        .nv_undefine_space(Provision::Owner, nv_index_handle)
+        .expect("Call to nv_undefine_space failed");
+
+    let (attest, _) = nv_certify_result.expect("Call to nv_certify failed");
+
+    // The signing key uses RSASSA with SHA256.
+    nv::verify_nv_certify_info(
+        &attest,
+        &qualifying_data,
+        &nv_public,
+        0,
+        &nv_contents,
+        HashingAlgorithm::Sha256,
+    )
+    .expect("Failed to verify the certified NV digest");
+
+    // Wrong contents
+    let _ = nv::verify_nv_certify_info(
+        &attest,
+        &qualifying_data,
+        &nv_public,
+        0,
+        &[7, 6, 5, 4, 3, 2, 1],
+        HashingAlgorithm::Sha256,
+    )
+    .unwrap_err();
+
+    // Partial contents
+    let _ = nv::verify_nv_certify_info(
+        &attest,
+        &qualifying_data,
+        &nv_public,
+        0,
+        &nv_contents[..4],
+        HashingAlgorithm::Sha256,
+    )
+    .unwrap_err();
+
+    // Wrong hashing algorithm
+    let _ = nv::verify_nv_certify_info(
+        &attest,
+        &qualifying_data,
+        &nv_public,
+        0,
+        &nv_contents,
+        HashingAlgorithm::Sha384,
+    )
+    .unwrap_err();
+}
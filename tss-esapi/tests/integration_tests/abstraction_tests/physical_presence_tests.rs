@@ -0,0 +1,56 @@
+// Copyright 2023 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use tss_esapi::{
+    abstraction::physical_presence::{read_pp_commands, PpCommandsDiff},
+    constants::CommandCode,
+};
+
+use crate::common::create_ctx_without_session;
+
+#[test]
+fn test_read_pp_commands() {
+    let mut context = create_ctx_without_session();
+    let pp_commands = read_pp_commands(&mut context).expect("Failed to read the PP commands");
+
+    // PP_Commands always requires physical presence.
+    assert!(pp_commands.contains(&CommandCode::PpCommands));
+
+    let diff = PpCommandsDiff::read(&mut context, &pp_commands)
+        .expect("Failed to compute the PP commands diff");
+    assert!(diff.is_empty());
+}
+
+#[test]
+fn test_diff() {
+    let current = [CommandCode::PpCommands, CommandCode::Clear];
+    let desired = [CommandCode::PpCommands, CommandCode::ClearControl];
+
+    let diff = PpCommandsDiff::new(&current, &desired).expect("Failed to compute diff");
+    assert!(!diff.is_empty());
+    assert_eq!(diff.set_list().as_ref(), &[CommandCode::ClearControl]);
+    assert_eq!(diff.clear_list().as_ref(), &[CommandCode::Clear]);
+
+    let diff = PpCommandsDiff::new(&current, &current).expect("Failed to compute diff");
+    assert!(diff.is_empty());
+    assert!(diff.set_list().is_empty());
+    assert!(diff.clear_list().is_empty());
+}
+
+#[test]
+fn test_diff_with_duplicates() {
+    let current = [
+        CommandCode::PpCommands,
+        CommandCode::Clear,
+        CommandCode::Clear,
+    ];
+    let desired = [
+        CommandCode::PpCommands,
+        CommandCode::ClearControl,
+        CommandCode::ClearControl,
+        CommandCode::PpCommands,
+    ];
+
+    let diff = PpCommandsDiff::new(&current, &desired).expect("Failed to compute diff");
+    assert_eq!(diff.set_list().as_ref(), &[CommandCode::ClearControl]);
+    assert_eq!(diff.clear_list().as_ref(), &[CommandCode::Clear]);
+}
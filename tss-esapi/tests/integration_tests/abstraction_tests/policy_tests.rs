@@ -0,0 +1,412 @@
+// Copyright 2023 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use sha2::{Digest as _, Sha256};
+use std::convert::TryFrom;
+use tss_esapi::{
+    abstraction::policy::PolicyDigestCalculator,
+    attributes::LocalityAttributesBuilder,
+    constants::{
+        tss::{TPM2_RH_NULL, TPM2_ST_VERIFIED},
+        AlgorithmIdentifier, ArithmeticOperation, CapabilityType, CommandCode, SessionType,
+    },
+    handles::{AuthHandle, ObjectHandle, SessionHandle},
+    interface_types::{
+        algorithm::HashingAlgorithm, resource_handles::Hierarchy, session_handles::PolicySession,
+    },
+    structures::{
+        CapabilityData, Digest, DigestList, Name, Nonce, Operand, PcrSelectionListBuilder, PcrSlot,
+        PublicKeyRsa, RsaSignature, Signature, SymmetricDefinition, VerifiedTicket,
+    },
+    tss2_esys::TPMT_TK_VERIFIED,
+    Context, Error, WrapperErrorKind,
+};
+
+use crate::common::{create_ctx_with_session, signing_key_pub};
+
+const HASHING_ALGORITHMS: [HashingAlgorithm; 8] = [
+    HashingAlgorithm::Sha1,
+    HashingAlgorithm::Sha256,
+    HashingAlgorithm::Sha384,
+    HashingAlgorithm::Sha512,
+    HashingAlgorithm::Sm3_256,
+    HashingAlgorithm::Sha3_256,
+    HashingAlgorithm::Sha3_384,
+    HashingAlgorithm::Sha3_512,
+];
+
+/// Returns the hashing algorithms of [HASHING_ALGORITHMS]
+/// that are implemented by the TPM.
+fn supported_hashing_algorithms(context: &mut Context) -> Vec<HashingAlgorithm> {
+    HASHING_ALGORITHMS
+        .iter()
+        .copied()
+        .filter(|hashing_algorithm| {
+            let algorithm_identifier = AlgorithmIdentifier::from(*hashing_algorithm);
+            let (capability_data, _) = context
+                .get_capability(
+                    CapabilityType::Algorithms,
+                    u16::from(algorithm_identifier).into(),
+                    1,
+                )
+                .expect("Failed to get the algorithms capability");
+            match capability_data {
+                CapabilityData::Algorithms(algorithms) => {
+                    algorithms.find(algorithm_identifier).is_some()
+                }
+                _ => panic!("Got wrong type of capability data"),
+            }
+        })
+        .collect()
+}
+
+/// Runs the same policy commands on a trial session and on a calculator
+/// and checks that the resulting policy digests are equal.
+fn assert_trial_digest_matches<F>(context: &mut Context, hashing_algorithm: HashingAlgorithm, f: F)
+where
+    F: FnOnce(&mut Context, PolicySession, &mut PolicyDigestCalculator),
+{
+    let trial_session = context
+        .execute_without_session(|ctx| {
+            ctx.start_auth_session(
+                None,
+                None,
+                None,
+                SessionType::Trial,
+                SymmetricDefinition::AES_128_CFB,
+                hashing_algorithm,
+            )
+        })
+        .expect("Failed to start trial session")
+        .expect("Received invalid handle");
+    let trial_session = PolicySession::try_from(trial_session).unwrap();
+    let mut calculator =
+        PolicyDigestCalculator::new(hashing_algorithm).expect("Failed to create calculator");
+
+    f(context, trial_session, &mut calculator);
+
+    let expected = context
+        .execute_without_session(|ctx| ctx.policy_get_digest(trial_session))
+        .expect("Failed to get policy digest");
+    context
+        .flush_context(ObjectHandle::from(SessionHandle::from(trial_session)))
+        .unwrap();
+    assert_eq!(
+        expected,
+        calculator.policy_digest(),
+        "The computed policy digest does not match the one of the TPM for {:?}",
+        hashing_algorithm
+    );
+}
+
+#[test]
+fn test_policy_assertions() {
+    let mut context = create_ctx_with_session();
+    let owner_name = context.tr_get_name(ObjectHandle::Owner).unwrap();
+    for hashing_algorithm in supported_hashing_algorithms(&mut context) {
+        assert_trial_digest_matches(
+            &mut context,
+            hashing_algorithm,
+            |context, trial_session, calculator| {
+                let locality = LocalityAttributesBuilder::new()
+                    .with_localities(&[0, 3])
+                    .build()
+                    .unwrap();
+                context.policy_locality(trial_session, locality).unwrap();
+                calculator.policy_locality(locality).unwrap();
+
+                context
+                    .policy_command_code(trial_session, CommandCode::Unseal)
+                    .unwrap();
+                calculator.policy_command_code(CommandCode::Unseal).unwrap();
+
+                context.policy_physical_presence(trial_session).unwrap();
+                calculator.policy_physical_presence().unwrap();
+
+                context.policy_auth_value(trial_session).unwrap();
+                calculator.policy_auth_value().unwrap();
+
+                context.policy_password(trial_session).unwrap();
+                calculator.policy_password().unwrap();
+
+                context.policy_nv_written(trial_session, true).unwrap();
+                calculator.policy_nv_written(true).unwrap();
+
+                let operand_b = Operand::try_from(vec![0, 0, 0, 0, 0, 0, 0x10, 0]).unwrap();
+                context
+                    .policy_counter_timer(
+                        trial_session,
+                        operand_b.clone(),
+                        8,
+                        ArithmeticOperation::UnsignedLt,
+                    )
+                    .unwrap();
+                calculator
+                    .policy_counter_timer(operand_b, 8, ArithmeticOperation::UnsignedLt)
+                    .unwrap();
+
+                let pcr_selection_list = PcrSelectionListBuilder::new()
+                    .with_selection(HashingAlgorithm::Sha256, &[PcrSlot::Slot0, PcrSlot::Slot7])
+                    .build()
+                    .unwrap();
+                let pcr_policy_digest = calculator
+                    .pcr_values_digest(&[
+                        Digest::try_from(vec![0x01; 32]).unwrap(),
+                        Digest::try_from(vec![0x07; 32]).unwrap(),
+                    ])
+                    .unwrap();
+                context
+                    .policy_pcr(
+                        trial_session,
+                        pcr_policy_digest.clone(),
+                        pcr_selection_list.clone(),
+                    )
+                    .unwrap();
+                calculator
+                    .policy_pcr(pcr_policy_digest, pcr_selection_list)
+                    .unwrap();
+
+                let policy_ref = Nonce::try_from(vec![1, 2, 3]).unwrap();
+                let _ = context
+                    .policy_secret(
+                        trial_session,
+                        AuthHandle::Owner,
+                        Nonce::default(),
+                        Digest::default(),
+                        policy_ref.clone(),
+                        None,
+                    )
+                    .unwrap();
+                calculator
+                    .policy_secret(owner_name.clone(), policy_ref)
+                    .unwrap();
+
+                let template_hash =
+                    Digest::try_from(vec![0xab; calculator.policy_digest().len()]).unwrap();
+                context
+                    .policy_template(trial_session, template_hash.clone())
+                    .unwrap();
+                calculator.policy_template(template_hash).unwrap();
+            },
+        );
+    }
+}
+
+#[test]
+fn test_policy_signed_and_or() {
+    let mut context = create_ctx_with_session();
+    let key_handle = context
+        .create_primary(Hierarchy::Owner, signing_key_pub(), None, None, None, None)
+        .unwrap()
+        .key_handle;
+    let key_name = context.tr_get_name(key_handle.into()).unwrap();
+    for hashing_algorithm in supported_hashing_algorithms(&mut context) {
+        assert_trial_digest_matches(
+            &mut context,
+            hashing_algorithm,
+            |context, trial_session, calculator| {
+                // The signature is not checked for trial sessions.
+                let signature = Signature::RsaSsa(
+                    RsaSignature::create(
+                        HashingAlgorithm::Sha256,
+                        PublicKeyRsa::try_from(vec![0xab; 256]).unwrap(),
+                    )
+                    .unwrap(),
+                );
+                let _ = context
+                    .policy_signed(
+                        trial_session,
+                        key_handle.into(),
+                        Nonce::default(),
+                        Digest::default(),
+                        Nonce::default(),
+                        None,
+                        signature,
+                    )
+                    .unwrap();
+                calculator
+                    .policy_signed(key_name.clone(), Nonce::default())
+                    .unwrap();
+
+                let digest_size = calculator.policy_digest().len();
+                let name_hash = Digest::try_from(vec![0x55; digest_size]).unwrap();
+                context
+                    .policy_name_hash(trial_session, name_hash.clone())
+                    .unwrap();
+                calculator.policy_name_hash(name_hash).unwrap();
+
+                let mut digest_list = DigestList::new();
+                digest_list.add(calculator.policy_digest()).unwrap();
+                digest_list
+                    .add(Digest::try_from(vec![0x11; digest_size]).unwrap())
+                    .unwrap();
+                context
+                    .policy_or(trial_session, digest_list.clone())
+                    .unwrap();
+                calculator.policy_or(digest_list).unwrap();
+            },
+        );
+    }
+    context.flush_context(key_handle.into()).unwrap();
+}
+
+#[test]
+fn test_policy_authorize_and_cp_hash() {
+    let mut context = create_ctx_with_session();
+    let key_sign = Name::try_from([&[0x00, 0x0b][..], &[0x42; 32][..]].concat()).unwrap();
+    for hashing_algorithm in supported_hashing_algorithms(&mut context) {
+        assert_trial_digest_matches(
+            &mut context,
+            hashing_algorithm,
+            |context, trial_session, calculator| {
+                let digest_size = calculator.policy_digest().len();
+                let cp_hash_a = Digest::try_from(vec![0x33; digest_size]).unwrap();
+                context
+                    .policy_cp_hash(trial_session, cp_hash_a.clone())
+                    .unwrap();
+                calculator.policy_cp_hash(cp_hash_a).unwrap();
+
+                // The ticket is not checked for trial sessions.
+                let check_ticket = VerifiedTicket::try_from(TPMT_TK_VERIFIED {
+                    tag: TPM2_ST_VERIFIED,
+                    hierarchy: TPM2_RH_NULL,
+                    digest: Default::default(),
+                })
+                .unwrap();
+                let policy_ref = Nonce::try_from(vec![4, 5, 6]).unwrap();
+                context
+                    .policy_authorize(
+                        trial_session,
+                        calculator.policy_digest(),
+                        policy_ref.clone(),
+                        &key_sign,
+                        check_ticket,
+                    )
+                    .unwrap();
+                calculator.policy_authorize(policy_ref, &key_sign).unwrap();
+            },
+        );
+    }
+}
+
+#[test]
+fn test_policy_duplication_select() {
+    let mut context = create_ctx_with_session();
+    let object_name = Name::try_from([&[0x00, 0x0b][..], &[0x01; 32][..]].concat()).unwrap();
+    let new_parent_name = Name::try_from([&[0x00, 0x0b][..], &[0x02; 32][..]].concat()).unwrap();
+    for hashing_algorithm in supported_hashing_algorithms(&mut context) {
+        for include_object in [false, true] {
+            assert_trial_digest_matches(
+                &mut context,
+                hashing_algorithm,
+                |context, trial_session, calculator| {
+                    context
+                        .policy_duplication_select(
+                            trial_session,
+                            object_name.clone(),
+                            new_parent_name.clone(),
+                            include_object,
+                        )
+                        .unwrap();
+                    calculator
+                        .policy_duplication_select(
+                            object_name.clone(),
+                            new_parent_name.clone(),
+                            include_object,
+                        )
+                        .unwrap();
+                },
+            );
+        }
+    }
+}
+
+#[cfg(has_esys_ac)]
+#[test]
+fn test_policy_ac_send_select() {
+    let mut context = create_ctx_with_session();
+    let object_name = Name::try_from([&[0x00, 0x0b][..], &[0x04; 32][..]].concat()).unwrap();
+    let auth_handle_name = Name::try_from([&[0x00, 0x0b][..], &[0x05; 32][..]].concat()).unwrap();
+    let ac_name = Name::try_from([&[0x00, 0x0b][..], &[0x06; 32][..]].concat()).unwrap();
+    for hashing_algorithm in supported_hashing_algorithms(&mut context) {
+        for include_object in [false, true] {
+            assert_trial_digest_matches(
+                &mut context,
+                hashing_algorithm,
+                |context, trial_session, calculator| {
+                    context
+                        .policy_ac_send_select(
+                            trial_session,
+                            object_name.clone(),
+                            auth_handle_name.clone(),
+                            ac_name.clone(),
+                            include_object,
+                        )
+                        .unwrap();
+                    calculator
+                        .policy_ac_send_select(
+                            object_name.clone(),
+                            auth_handle_name.clone(),
+                            ac_name.clone(),
+                            include_object,
+                        )
+                        .unwrap();
+                },
+            );
+        }
+    }
+}
+
+#[test]
+fn test_policy_nv() {
+    let nv_index_name = Name::try_from([&[0x00, 0x0b][..], &[0x03; 32][..]].concat()).unwrap();
+    let operand_b = Operand::try_from(vec![1, 2, 3, 4]).unwrap();
+    let mut calculator = PolicyDigestCalculator::new(HashingAlgorithm::Sha256).unwrap();
+    calculator
+        .policy_nv(nv_index_name.clone(), operand_b, 2, ArithmeticOperation::Eq)
+        .unwrap();
+
+    // args := H(operandB || offset || operation)
+    let args = Sha256::new()
+        .chain_update([1, 2, 3, 4])
+        .chain_update(2u16.to_be_bytes())
+        .chain_update(0u16.to_be_bytes())
+        .finalize();
+    // policyDigest := H(0...0 || TPM_CC_PolicyNV || args || nvIndexName)
+    let expected = Sha256::new()
+        .chain_update([0u8; 32])
+        .chain_update(0x0000_0149u32.to_be_bytes())
+        .chain_update(args)
+        .chain_update(nv_index_name.value())
+        .finalize();
+    assert_eq!(expected.as_slice(), calculator.policy_digest().as_bytes());
+
+    calculator
+        .policy_authorize_nv(nv_index_name.clone())
+        .unwrap();
+    // policyDigest := H(0...0 || TPM_CC_PolicyAuthorizeNV || nvIndexName)
+    let expected = Sha256::new()
+        .chain_update([0u8; 32])
+        .chain_update(0x0000_0192u32.to_be_bytes())
+        .chain_update(nv_index_name.value())
+        .finalize();
+    assert_eq!(expected.as_slice(), calculator.policy_digest().as_bytes());
+
+    calculator.policy_restart();
+    assert_eq!(&[0u8; 32], calculator.policy_digest().as_bytes());
+}
+
+#[test]
+fn test_invalid_parameters() {
+    let mut calculator = PolicyDigestCalculator::new(HashingAlgorithm::Sha256).unwrap();
+    assert_eq!(
+        Err(Error::WrapperError(WrapperErrorKind::InconsistentParams)),
+        calculator.policy_cp_hash(Digest::try_from(vec![0x33; 20]).unwrap())
+    );
+
+    let mut digest_list = DigestList::new();
+    digest_list.add(calculator.policy_digest()).unwrap();
+    assert_eq!(
+        Err(Error::WrapperError(WrapperErrorKind::WrongParamSize)),
+        calculator.policy_or(digest_list)
+    );
+}
@@ -0,0 +1,159 @@
+// Copyright 2023 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use sha2::{Digest as _, Sha256};
+use tss_esapi::{
+    abstraction::reparent::{duplication_select_policy, reparent, DuplicatedObject},
+    attributes::ObjectAttributesBuilder,
+    handles::KeyHandle,
+    interface_types::{
+        algorithm::{HashingAlgorithm, PublicAlgorithm},
+        resource_handles::Hierarchy,
+    },
+    structures::{
+        Digest, KeyedHashScheme, Public, PublicBuilder, PublicKeyedHashParameters,
+        SymmetricDefinitionObject,
+    },
+    Context,
+};
+
+use crate::common::{create_ctx_with_session, decryption_key_pub};
+
+fn duplicable_key_pub(auth_policy: Digest) -> Public {
+    let object_attributes = ObjectAttributesBuilder::new()
+        .with_fixed_tpm(false)
+        .with_fixed_parent(false)
+        .with_sensitive_data_origin(true)
+        .with_user_with_auth(true)
+        .with_sign_encrypt(true)
+        .build()
+        .expect("Attributes to be valid");
+
+    PublicBuilder::new()
+        .with_public_algorithm(PublicAlgorithm::KeyedHash)
+        .with_name_hashing_algorithm(HashingAlgorithm::Sha256)
+        .with_object_attributes(object_attributes)
+        .with_auth_policy(auth_policy)
+        .with_keyed_hash_parameters(PublicKeyedHashParameters::new(
+            KeyedHashScheme::HMAC_SHA_256,
+        ))
+        .with_keyed_hash_unique_identifier(Digest::default())
+        .build()
+        .expect("Public to be valid")
+}
+
+fn create_parent(context: &mut Context, hierarchy: Hierarchy) -> KeyHandle {
+    context
+        .create_primary(hierarchy, decryption_key_pub(), None, None, None, None)
+        .expect("Failed to create parent")
+        .key_handle
+}
+
+#[test]
+fn test_duplication_select_policy() {
+    let mut context = create_ctx_with_session();
+    let new_parent_handle = create_parent(&mut context, Hierarchy::Owner);
+    let new_parent_name = context.tr_get_name(new_parent_handle.into()).unwrap();
+
+    let policy = duplication_select_policy(
+        &mut context,
+        new_parent_name.clone(),
+        HashingAlgorithm::Sha256,
+    )
+    .expect("Failed to compute policy");
+
+    // policyDigest := H(0...0 || TPM_CC_PolicyDuplicationSelect || newParentName || includeObject)
+    let mut hasher = Sha256::new();
+    hasher.update([0u8; 32]);
+    hasher.update(0x0000_0188u32.to_be_bytes());
+    hasher.update(new_parent_name.value());
+    hasher.update([0u8]);
+    assert_eq!(policy.as_bytes(), hasher.finalize().as_slice());
+}
+
+#[test]
+fn test_reparent() {
+    let mut context = create_ctx_with_session();
+    let old_parent_handle = create_parent(&mut context, Hierarchy::Owner);
+    let new_parent_handle = create_parent(&mut context, Hierarchy::Endorsement);
+    let new_parent_name = context.tr_get_name(new_parent_handle.into()).unwrap();
+
+    let policy = duplication_select_policy(&mut context, new_parent_name, HashingAlgorithm::Sha256)
+        .expect("Failed to compute policy");
+    let key = context
+        .create(
+            old_parent_handle,
+            duplicable_key_pub(policy),
+            None,
+            None,
+            None,
+            None,
+        )
+        .expect("Failed to create key");
+    let key_handle = context
+        .load(old_parent_handle, key.out_private, key.out_public)
+        .expect("Failed to load key");
+
+    let (private, public) = reparent(&mut context, key_handle.into(), new_parent_handle)
+        .expect("Failed to reparent key");
+    context.flush_context(key_handle.into()).unwrap();
+    let _ = context
+        .load(new_parent_handle, private, public)
+        .expect("Failed to load key under the new parent");
+}
+
+#[test]
+fn test_duplicate_rewrap_and_import() {
+    let mut context = create_ctx_with_session();
+    let old_parent_handle = create_parent(&mut context, Hierarchy::Owner);
+    let intermediate_parent_handle = create_parent(&mut context, Hierarchy::Endorsement);
+    let new_parent_handle = create_parent(&mut context, Hierarchy::Null);
+    let intermediate_parent_name = context
+        .tr_get_name(intermediate_parent_handle.into())
+        .unwrap();
+
+    let policy = duplication_select_policy(
+        &mut context,
+        intermediate_parent_name,
+        HashingAlgorithm::Sha256,
+    )
+    .expect("Failed to compute policy");
+    let key = context
+        .create(
+            old_parent_handle,
+            duplicable_key_pub(policy),
+            None,
+            None,
+            None,
+            None,
+        )
+        .expect("Failed to create key");
+    let key_handle = context
+        .load(old_parent_handle, key.out_private, key.out_public)
+        .expect("Failed to load key");
+
+    let duplicated_object = DuplicatedObject::duplicate(
+        &mut context,
+        key_handle.into(),
+        intermediate_parent_handle.into(),
+        SymmetricDefinitionObject::AES_128_CFB,
+    )
+    .expect("Failed to duplicate key");
+    assert!(!duplicated_object.encryption_key().is_empty());
+    context.flush_context(key_handle.into()).unwrap();
+    context.flush_context(old_parent_handle.into()).unwrap();
+
+    let duplicated_object = duplicated_object
+        .rewrap(
+            &mut context,
+            intermediate_parent_handle.into(),
+            new_parent_handle.into(),
+        )
+        .expect("Failed to rewrap key");
+    let public = duplicated_object.public().clone();
+    let private = duplicated_object
+        .import(&mut context, new_parent_handle.into())
+        .expect("Failed to import key");
+    let _ = context
+        .load(new_parent_handle, private, public)
+        .expect("Failed to load key under the new parent");
+}
@@ -0,0 +1,24 @@
+// Copyright 2023 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use std::time::Duration;
+use tss_esapi::{abstraction::self_test::ensure_algorithms_tested, constants::AlgorithmIdentifier};
+
+use crate::common::create_ctx_without_session;
+
+#[test]
+fn test_ensure_algorithms_tested() {
+    let mut context = create_ctx_without_session();
+    ensure_algorithms_tested(
+        &mut context,
+        &[
+            AlgorithmIdentifier::Sha256,
+            AlgorithmIdentifier::Rsa,
+            AlgorithmIdentifier::Aes,
+        ],
+        Duration::from_millis(10),
+        100,
+    )
+    .expect("Failed to test algorithms");
+    ensure_algorithms_tested(&mut context, &[], Duration::from_millis(10), 1)
+        .expect("Failed to test no algorithms");
+}
@@ -0,0 +1,89 @@
+// Copyright 2023 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use std::io::Write;
+
+use sha2::{Digest as Sha2Digest, Sha256};
+use tss_esapi::{
+    abstraction::sequence::{EventSequence, HashSequence},
+    interface_types::{
+        algorithm::HashingAlgorithm, resource_handles::Hierarchy, session_handles::AuthSession,
+    },
+    structures::{MaxBuffer, Ticket},
+};
+
+use crate::common::create_ctx_without_session;
+
+#[test]
+fn test_hash_sequence_larger_than_max_buffer() {
+    let mut context = create_ctx_without_session();
+    let data = (0..(4 * MaxBuffer::MAX_SIZE + 17))
+        .map(|i| (i % 251) as u8)
+        .collect::<Vec<u8>>();
+
+    let (digest, ticket) = context.execute_with_session(Some(AuthSession::Password), |ctx| {
+        let mut hash_sequence = HashSequence::start(ctx, HashingAlgorithm::Sha256, None)
+            .expect("Failed to start hash sequence");
+        hash_sequence
+            .write_all(&data)
+            .expect("Failed to write data to the hash sequence");
+        hash_sequence
+            .finish(Hierarchy::Owner)
+            .expect("Failed to finish hash sequence")
+    });
+
+    assert_eq!(Sha256::digest(&data).as_slice(), digest.as_bytes());
+    assert_eq!(Hierarchy::Owner, ticket.hierarchy());
+}
+
+#[test]
+fn test_hash_sequence_with_null_algorithm() {
+    let mut context = create_ctx_without_session();
+    let _ = HashSequence::start(&mut context, HashingAlgorithm::Null, None).unwrap_err();
+}
+
+#[test]
+fn test_dropped_hash_sequence_is_flushed() {
+    let mut context = create_ctx_without_session();
+    context.execute_with_session(Some(AuthSession::Password), |ctx| {
+        let mut hash_sequence = HashSequence::start(ctx, HashingAlgorithm::Sha256, None)
+            .expect("Failed to start hash sequence");
+        hash_sequence
+            .write_all(&[0xa5; 64])
+            .expect("Failed to write data to the hash sequence");
+    });
+    // Dropping the context would fail to flush the sequence object if it had not
+    // already been flushed when the hash sequence was dropped.
+    context
+        .hash_sequence_start(HashingAlgorithm::Sha256, None)
+        .and_then(|sequence_handle| context.flush_context(sequence_handle))
+        .expect("Failed to start and flush a new hash sequence");
+}
+
+#[test]
+fn test_event_sequence_larger_than_max_buffer() {
+    let mut context = create_ctx_without_session();
+    let data = vec![0x42; 2 * MaxBuffer::MAX_SIZE + 1];
+
+    let digest_values = context.execute_with_sessions(
+        (
+            Some(AuthSession::Password),
+            Some(AuthSession::Password),
+            None,
+        ),
+        |ctx| {
+            let mut event_sequence =
+                EventSequence::start(ctx, None).expect("Failed to start event sequence");
+            event_sequence
+                .write_all(&data)
+                .expect("Failed to write data to the event sequence");
+            event_sequence
+                .finish(None)
+                .expect("Failed to finish event sequence")
+        },
+    );
+
+    let sha256_digest = digest_values
+        .get(HashingAlgorithm::Sha256)
+        .expect("No SHA256 digest was returned");
+    assert_eq!(Sha256::digest(&data).as_slice(), sha256_digest.as_bytes());
+}
@@ -0,0 +1,144 @@
+// Copyright 2023 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use picky_asn1_x509::Certificate;
+use std::convert::TryFrom;
+use tss_esapi::{
+    abstraction::x509::{assemble_certificate, PartialCertificate},
+    interface_types::algorithm::HashingAlgorithm,
+    structures::{MaxBuffer, PublicKeyRsa, RsaSignature, Signature},
+    Error, WrapperErrorKind,
+};
+
+use crate::common::{der, partial_certificate_der};
+
+const OID_RSA_ENCRYPTION: &[u8] = &[0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x01];
+const OID_SHA256_WITH_RSA_ENCRYPTION: &[u8] =
+    &[0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x0b];
+
+fn added_to_certificate_der() -> Vec<u8> {
+    let mut modulus = vec![0x00, 0xc1];
+    modulus.extend_from_slice(&[0x5a; 255]);
+    let rsa_public_key = der(
+        0x30,
+        &[&der(0x02, &[&modulus]), &der(0x02, &[&[0x01, 0x00, 0x01]])],
+    );
+    let subject_public_key_info = der(
+        0x30,
+        &[
+            &der(0x30, &[&der(0x06, &[OID_RSA_ENCRYPTION]), &der(0x05, &[])]),
+            &der(0x03, &[&[0x00], &rsa_public_key]),
+        ],
+    );
+    der(
+        0x30,
+        &[
+            &der(0xa0, &[&der(0x02, &[&[0x02]])]),
+            &der(0x02, &[&[0x01, 0x23, 0x45, 0x67]]),
+            &der(
+                0x30,
+                &[
+                    &der(0x06, &[OID_SHA256_WITH_RSA_ENCRYPTION]),
+                    &der(0x05, &[]),
+                ],
+            ),
+            &subject_public_key_info,
+        ],
+    )
+}
+
+fn rsa_signature() -> Signature {
+    Signature::RsaSsa(
+        RsaSignature::create(
+            HashingAlgorithm::Sha256,
+            PublicKeyRsa::try_from(vec![0xab; 256]).unwrap(),
+        )
+        .unwrap(),
+    )
+}
+
+#[test]
+fn test_partial_certificate_encoding() {
+    let partial_certificate: PartialCertificate =
+        picky_asn1_der::from_bytes(&partial_certificate_der())
+            .expect("Failed to decode partial certificate");
+
+    let encoded =
+        MaxBuffer::try_from(&partial_certificate).expect("Failed to encode partial certificate");
+    assert_eq!(encoded.as_bytes(), partial_certificate_der().as_slice());
+
+    let rebuilt = PartialCertificate::new(
+        partial_certificate.issuer().clone(),
+        partial_certificate.validity().clone(),
+        partial_certificate.subject().clone(),
+        partial_certificate.extensions().clone(),
+    );
+    assert_eq!(rebuilt, partial_certificate);
+}
+
+#[test]
+fn test_assemble_certificate() {
+    let partial_certificate: PartialCertificate =
+        picky_asn1_der::from_bytes(&partial_certificate_der())
+            .expect("Failed to decode partial certificate");
+    let added_to_certificate = MaxBuffer::try_from(added_to_certificate_der()).unwrap();
+
+    let certificate = assemble_certificate(
+        &partial_certificate,
+        &added_to_certificate,
+        &rsa_signature(),
+    )
+    .expect("Failed to assemble certificate");
+
+    assert_eq!(
+        &certificate.tbs_certificate.issuer,
+        partial_certificate.issuer()
+    );
+    assert_eq!(
+        &certificate.tbs_certificate.subject,
+        partial_certificate.subject()
+    );
+    assert_eq!(
+        certificate.tbs_certificate.signature,
+        certificate.signature_algorithm
+    );
+    assert_eq!(
+        certificate
+            .tbs_certificate
+            .serial_number
+            .as_unsigned_bytes_be(),
+        &[0x01, 0x23, 0x45, 0x67]
+    );
+
+    // The assembled certificate is a valid DER encoded certificate
+    let encoded = picky_asn1_der::to_vec(&certificate).expect("Failed to encode certificate");
+    let decoded: Certificate =
+        picky_asn1_der::from_bytes(&encoded).expect("Failed to decode certificate");
+    assert_eq!(decoded, certificate);
+}
+
+#[test]
+fn test_assemble_certificate_errors() {
+    let partial_certificate: PartialCertificate =
+        picky_asn1_der::from_bytes(&partial_certificate_der())
+            .expect("Failed to decode partial certificate");
+    let added_to_certificate = MaxBuffer::try_from(added_to_certificate_der()).unwrap();
+
+    assert_eq!(
+        assemble_certificate(
+            &partial_certificate,
+            &added_to_certificate,
+            &Signature::Null
+        )
+        .unwrap_err(),
+        Error::WrapperError(WrapperErrorKind::UnsupportedParam)
+    );
+    assert_eq!(
+        assemble_certificate(
+            &partial_certificate,
+            &MaxBuffer::try_from(vec![0x30, 0x00]).unwrap(),
+            &rsa_signature()
+        )
+        .unwrap_err(),
+        Error::WrapperError(WrapperErrorKind::InvalidParam)
+    );
+}
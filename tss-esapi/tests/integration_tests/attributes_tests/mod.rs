@@ -1,5 +1,6 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
+mod act_attributes_tests;
 mod algorithm_attributes_tests;
 mod command_code_attributes_tests;
 mod locality_attributes_tests;
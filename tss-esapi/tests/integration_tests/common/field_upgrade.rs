@@ -0,0 +1,41 @@
+// Copyright 2023 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use std::convert::{TryFrom, TryInto};
+use tss_esapi::{
+    constants::tss::{TPM2_RH_NULL, TPM2_ST_HASHCHECK},
+    handles::KeyHandle,
+    interface_types::resource_handles::Hierarchy,
+    structures::{Digest, Signature, SignatureScheme},
+    tss2_esys::TPMT_TK_HASHCHECK,
+    Context,
+};
+
+use super::signing_key_pub;
+
+/// Creates a field upgrade manifest signed by a key that
+/// the TPM does not trust as a firmware signing key.
+///
+/// # Returns
+/// The handle of the signing key, the digest of the first
+/// block of the firmware and the manifest signature.
+pub fn untrusted_manifest(context: &mut Context) -> (KeyHandle, Digest, Signature) {
+    let key_handle = context
+        .create_primary(Hierarchy::Owner, signing_key_pub(), None, None, None, None)
+        .expect("Failed to create the signing key")
+        .key_handle;
+    let fu_digest = Digest::try_from(vec![0xaa; 32]).unwrap();
+    let validation = TPMT_TK_HASHCHECK {
+        tag: TPM2_ST_HASHCHECK,
+        hierarchy: TPM2_RH_NULL,
+        digest: Default::default(),
+    };
+    let manifest_signature = context
+        .sign(
+            key_handle,
+            fu_digest.clone(),
+            SignatureScheme::Null,
+            validation.try_into().unwrap(),
+        )
+        .expect("Failed to sign the digest");
+    (key_handle, fu_digest, manifest_signature)
+}
@@ -31,18 +31,22 @@ use tss_esapi::{
     utils, Context,
 };
 
+mod field_upgrade;
 mod marshall;
 mod tpm2b_types_equality_checks;
 mod tpma_types_equality_checks;
 mod tpml_types_equality_checks;
 mod tpms_types_equality_checks;
 mod tpmt_types_equality_checks;
+mod x509;
+pub use field_upgrade::*;
 pub use marshall::*;
 pub use tpm2b_types_equality_checks::*;
 pub use tpma_types_equality_checks::*;
 pub use tpml_types_equality_checks::*;
 pub use tpms_types_equality_checks::*;
 pub use tpmt_types_equality_checks::*;
+pub use x509::*;
 
 #[allow(dead_code)]
 pub const HASH: [u8; 64] = [
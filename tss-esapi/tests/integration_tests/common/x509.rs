@@ -0,0 +1,63 @@
+// Copyright 2023 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+
+const OID_COMMON_NAME: &[u8] = &[0x55, 0x04, 0x03];
+const OID_KEY_USAGE: &[u8] = &[0x55, 0x1d, 0x0f];
+
+pub fn der(tag: u8, contents: &[&[u8]]) -> Vec<u8> {
+    let contents = contents.concat();
+    let mut encoded = vec![tag];
+    if contents.len() < 0x80 {
+        encoded.push(contents.len() as u8);
+    } else if contents.len() <= 0xff {
+        encoded.extend_from_slice(&[0x81, contents.len() as u8]);
+    } else {
+        encoded.extend_from_slice(&[0x82, (contents.len() >> 8) as u8, contents.len() as u8]);
+    }
+    encoded.extend_from_slice(&contents);
+    encoded
+}
+
+fn common_name(name: &str) -> Vec<u8> {
+    der(
+        0x30,
+        &[&der(
+            0x31,
+            &[&der(
+                0x30,
+                &[
+                    &der(0x06, &[OID_COMMON_NAME]),
+                    &der(0x0c, &[name.as_bytes()]),
+                ],
+            )],
+        )],
+    )
+}
+
+pub fn partial_certificate_der() -> Vec<u8> {
+    let validity = der(
+        0x30,
+        &[
+            &der(0x17, &[b"230101000000Z"]),
+            &der(0x17, &[b"330101000000Z"]),
+        ],
+    );
+    let key_usage = der(
+        0x30,
+        &[
+            &der(0x06, &[OID_KEY_USAGE]),
+            &der(0x01, &[&[0xff]]),
+            &der(0x04, &[&der(0x03, &[&[0x07, 0x80]])]),
+        ],
+    );
+    let extensions = der(0xa3, &[&der(0x30, &[&key_usage])]);
+    der(
+        0x30,
+        &[
+            &common_name("TPM test CA"),
+            &validity,
+            &common_name("LDevID"),
+            &extensions,
+        ],
+    )
+}
@@ -0,0 +1,120 @@
+// Copyright 2023 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use tss_esapi::{
+    constants::{
+        tss::{
+            TPM2_EO_BITCLEAR, TPM2_EO_BITSET, TPM2_EO_EQ, TPM2_EO_NEQ, TPM2_EO_SIGNED_GE,
+            TPM2_EO_SIGNED_GT, TPM2_EO_SIGNED_LE, TPM2_EO_SIGNED_LT, TPM2_EO_UNSIGNED_GE,
+            TPM2_EO_UNSIGNED_GT, TPM2_EO_UNSIGNED_LE, TPM2_EO_UNSIGNED_LT,
+        },
+        ArithmeticOperation,
+    },
+    tss2_esys::TPM2_EO,
+    Error, WrapperErrorKind,
+};
+
+use std::convert::{From, TryFrom};
+
+#[test]
+fn test_conversion_to_tss_type() {
+    assert_eq!(TPM2_EO_EQ, TPM2_EO::from(ArithmeticOperation::Eq));
+    assert_eq!(TPM2_EO_NEQ, TPM2_EO::from(ArithmeticOperation::Neq));
+    assert_eq!(
+        TPM2_EO_SIGNED_GT,
+        TPM2_EO::from(ArithmeticOperation::SignedGt)
+    );
+    assert_eq!(
+        TPM2_EO_UNSIGNED_GT,
+        TPM2_EO::from(ArithmeticOperation::UnsignedGt)
+    );
+    assert_eq!(
+        TPM2_EO_SIGNED_LT,
+        TPM2_EO::from(ArithmeticOperation::SignedLt)
+    );
+    assert_eq!(
+        TPM2_EO_UNSIGNED_LT,
+        TPM2_EO::from(ArithmeticOperation::UnsignedLt)
+    );
+    assert_eq!(
+        TPM2_EO_SIGNED_GE,
+        TPM2_EO::from(ArithmeticOperation::SignedGe)
+    );
+    assert_eq!(
+        TPM2_EO_UNSIGNED_GE,
+        TPM2_EO::from(ArithmeticOperation::UnsignedGe)
+    );
+    assert_eq!(
+        TPM2_EO_SIGNED_LE,
+        TPM2_EO::from(ArithmeticOperation::SignedLe)
+    );
+    assert_eq!(
+        TPM2_EO_UNSIGNED_LE,
+        TPM2_EO::from(ArithmeticOperation::UnsignedLe)
+    );
+    assert_eq!(TPM2_EO_BITSET, TPM2_EO::from(ArithmeticOperation::BitSet));
+    assert_eq!(
+        TPM2_EO_BITCLEAR,
+        TPM2_EO::from(ArithmeticOperation::BitClear)
+    );
+}
+
+#[test]
+fn test_conversion_from_tss_type() {
+    assert_eq!(
+        ArithmeticOperation::Eq,
+        ArithmeticOperation::try_from(TPM2_EO_EQ).unwrap()
+    );
+    assert_eq!(
+        ArithmeticOperation::Neq,
+        ArithmeticOperation::try_from(TPM2_EO_NEQ).unwrap()
+    );
+    assert_eq!(
+        ArithmeticOperation::SignedGt,
+        ArithmeticOperation::try_from(TPM2_EO_SIGNED_GT).unwrap()
+    );
+    assert_eq!(
+        ArithmeticOperation::UnsignedGt,
+        ArithmeticOperation::try_from(TPM2_EO_UNSIGNED_GT).unwrap()
+    );
+    assert_eq!(
+        ArithmeticOperation::SignedLt,
+        ArithmeticOperation::try_from(TPM2_EO_SIGNED_LT).unwrap()
+    );
+    assert_eq!(
+        ArithmeticOperation::UnsignedLt,
+        ArithmeticOperation::try_from(TPM2_EO_UNSIGNED_LT).unwrap()
+    );
+    assert_eq!(
+        ArithmeticOperation::SignedGe,
+        ArithmeticOperation::try_from(TPM2_EO_SIGNED_GE).unwrap()
+    );
+    assert_eq!(
+        ArithmeticOperation::UnsignedGe,
+        ArithmeticOperation::try_from(TPM2_EO_UNSIGNED_GE).unwrap()
+    );
+    assert_eq!(
+        ArithmeticOperation::SignedLe,
+        ArithmeticOperation::try_from(TPM2_EO_SIGNED_LE).unwrap()
+    );
+    assert_eq!(
+        ArithmeticOperation::UnsignedLe,
+        ArithmeticOperation::try_from(TPM2_EO_UNSIGNED_LE).unwrap()
+    );
+    assert_eq!(
+        ArithmeticOperation::BitSet,
+        ArithmeticOperation::try_from(TPM2_EO_BITSET).unwrap()
+    );
+    assert_eq!(
+        ArithmeticOperation::BitClear,
+        ArithmeticOperation::try_from(TPM2_EO_BITCLEAR).unwrap()
+    );
+}
+
+#[test]
+fn test_invalid_conversions() {
+    assert_eq!(
+        Err(Error::WrapperError(WrapperErrorKind::InvalidParam)),
+        ArithmeticOperation::try_from(0x000C),
+        "Expected an error when converting an invalid value into an ArithmeticOperation"
+    );
+}
@@ -0,0 +1,47 @@
+// Copyright 2023 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use tss_esapi::{
+    constants::{
+        tss::{TPM2_AT_ANY, TPM2_AT_ERROR, TPM2_AT_PV1, TPM2_AT_VEND},
+        AttachedComponentTag,
+    },
+    tss2_esys::TPM_AT,
+    Error, WrapperErrorKind,
+};
+
+use std::convert::{From, TryFrom};
+
+#[test]
+fn test_conversion_to_tss_type() {
+    assert_eq!(TPM2_AT_ANY, TPM_AT::from(AttachedComponentTag::Any));
+    assert_eq!(TPM2_AT_ERROR, TPM_AT::from(AttachedComponentTag::Error));
+    assert_eq!(TPM2_AT_PV1, TPM_AT::from(AttachedComponentTag::Pv1));
+    assert_eq!(
+        TPM2_AT_VEND + 1,
+        TPM_AT::from(AttachedComponentTag::Vendor(TPM2_AT_VEND + 1))
+    );
+}
+
+#[test]
+fn test_conversion_from_tss_type() {
+    assert_eq!(
+        AttachedComponentTag::Any,
+        AttachedComponentTag::try_from(TPM2_AT_ANY).unwrap()
+    );
+    assert_eq!(
+        AttachedComponentTag::Error,
+        AttachedComponentTag::try_from(TPM2_AT_ERROR).unwrap()
+    );
+    assert_eq!(
+        AttachedComponentTag::Pv1,
+        AttachedComponentTag::try_from(TPM2_AT_PV1).unwrap()
+    );
+    assert_eq!(
+        AttachedComponentTag::Vendor(TPM2_AT_VEND + 1),
+        AttachedComponentTag::try_from(TPM2_AT_VEND + 1).unwrap()
+    );
+    assert_eq!(
+        Error::WrapperError(WrapperErrorKind::InvalidParam),
+        AttachedComponentTag::try_from(3).unwrap_err()
+    );
+}
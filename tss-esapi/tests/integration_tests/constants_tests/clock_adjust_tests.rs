@@ -0,0 +1,89 @@
+// Copyright 2023 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use tss_esapi::{
+    constants::{
+        tss::{
+            TPM2_CLOCK_COARSE_FASTER, TPM2_CLOCK_COARSE_SLOWER, TPM2_CLOCK_FINE_FASTER,
+            TPM2_CLOCK_FINE_SLOWER, TPM2_CLOCK_MEDIUM_FASTER, TPM2_CLOCK_MEDIUM_SLOWER,
+            TPM2_CLOCK_NO_CHANGE,
+        },
+        ClockAdjust,
+    },
+    tss2_esys::TPM2_CLOCK_ADJUST,
+    Error, WrapperErrorKind,
+};
+
+use std::convert::{From, TryFrom};
+
+#[test]
+fn test_conversion_to_tss_type() {
+    assert_eq!(
+        TPM2_CLOCK_COARSE_SLOWER,
+        TPM2_CLOCK_ADJUST::from(ClockAdjust::CoarseSlower)
+    );
+    assert_eq!(
+        TPM2_CLOCK_MEDIUM_SLOWER,
+        TPM2_CLOCK_ADJUST::from(ClockAdjust::MediumSlower)
+    );
+    assert_eq!(
+        TPM2_CLOCK_FINE_SLOWER,
+        TPM2_CLOCK_ADJUST::from(ClockAdjust::FineSlower)
+    );
+    assert_eq!(
+        TPM2_CLOCK_NO_CHANGE,
+        TPM2_CLOCK_ADJUST::from(ClockAdjust::NoChange)
+    );
+    assert_eq!(
+        TPM2_CLOCK_FINE_FASTER,
+        TPM2_CLOCK_ADJUST::from(ClockAdjust::FineFaster)
+    );
+    assert_eq!(
+        TPM2_CLOCK_MEDIUM_FASTER,
+        TPM2_CLOCK_ADJUST::from(ClockAdjust::MediumFaster)
+    );
+    assert_eq!(
+        TPM2_CLOCK_COARSE_FASTER,
+        TPM2_CLOCK_ADJUST::from(ClockAdjust::CoarseFaster)
+    );
+}
+
+#[test]
+fn test_conversion_from_tss_type() {
+    assert_eq!(
+        ClockAdjust::CoarseSlower,
+        ClockAdjust::try_from(TPM2_CLOCK_COARSE_SLOWER).unwrap()
+    );
+    assert_eq!(
+        ClockAdjust::MediumSlower,
+        ClockAdjust::try_from(TPM2_CLOCK_MEDIUM_SLOWER).unwrap()
+    );
+    assert_eq!(
+        ClockAdjust::FineSlower,
+        ClockAdjust::try_from(TPM2_CLOCK_FINE_SLOWER).unwrap()
+    );
+    assert_eq!(
+        ClockAdjust::NoChange,
+        ClockAdjust::try_from(TPM2_CLOCK_NO_CHANGE).unwrap()
+    );
+    assert_eq!(
+        ClockAdjust::FineFaster,
+        ClockAdjust::try_from(TPM2_CLOCK_FINE_FASTER).unwrap()
+    );
+    assert_eq!(
+        ClockAdjust::MediumFaster,
+        ClockAdjust::try_from(TPM2_CLOCK_MEDIUM_FASTER).unwrap()
+    );
+    assert_eq!(
+        ClockAdjust::CoarseFaster,
+        ClockAdjust::try_from(TPM2_CLOCK_COARSE_FASTER).unwrap()
+    );
+}
+
+#[test]
+fn test_invalid_conversions() {
+    assert_eq!(
+        Err(Error::WrapperError(WrapperErrorKind::InvalidParam)),
+        ClockAdjust::try_from(4),
+        "Expected an error when converting an invalid value into a ClockAdjust"
+    );
+}
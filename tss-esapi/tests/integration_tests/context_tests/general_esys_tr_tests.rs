@@ -445,3 +445,64 @@ mod test_tr_from_tpm_public {
         assert_eq!(expected, actual);
     }
 }
+
+mod test_tr_serialize {
+    use crate::common::create_ctx_without_session;
+    use tss_esapi::{
+        attributes::NvIndexAttributesBuilder,
+        handles::{NvIndexHandle, NvIndexTpmHandle},
+        interface_types::{
+            algorithm::HashingAlgorithm, resource_handles::Provision, session_handles::AuthSession,
+        },
+        structures::NvPublicBuilder,
+        utils::SerializedEsysTr,
+    };
+
+    #[test]
+    fn test_tr_serialize_and_deserialize() {
+        let nv_index_tpm_handle = NvIndexTpmHandle::new(0x01500305).unwrap();
+
+        let nv_index_attributes = NvIndexAttributesBuilder::new()
+            .with_owner_write(true)
+            .with_owner_read(true)
+            .build()
+            .expect("Failed to create owner nv index attributes");
+        let nv_public = NvPublicBuilder::new()
+            .with_nv_index(nv_index_tpm_handle)
+            .with_index_name_algorithm(HashingAlgorithm::Sha256)
+            .with_index_attributes(nv_index_attributes)
+            .with_data_area_size(32)
+            .build()
+            .unwrap();
+
+        let mut context = create_ctx_without_session();
+        context.set_sessions((Some(AuthSession::Password), None, None));
+        let nv_index_handle = context
+            .nv_define_space(Provision::Owner, None, nv_public)
+            .expect("Failed to call nv_define_space");
+        let expected_name = context.tr_get_name(nv_index_handle.into()).unwrap();
+        let serialized = context.tr_serialize(nv_index_handle.into());
+        context
+            .tr_close(&mut nv_index_handle.into())
+            .expect("Failed to call tr_close");
+        let serialized = SerializedEsysTr::from(Vec::<u8>::from(
+            serialized.expect("Failed to call tr_serialize"),
+        ));
+
+        // Restore the handle in a new context.
+        let mut context = create_ctx_without_session();
+        let object_handle = context
+            .tr_deserialize(&serialized)
+            .expect("Failed to call tr_deserialize");
+        let actual_name = context.tr_get_name(object_handle);
+
+        context.set_sessions((Some(AuthSession::Password), None, None));
+        context
+            .nv_undefine_space(Provision::Owner, NvIndexHandle::from(object_handle))
+            .expect("Failed to call nv_undefine_space");
+        assert_eq!(
+            expected_name,
+            actual_name.expect("Failed to call tr_get_name")
+        );
+    }
+}
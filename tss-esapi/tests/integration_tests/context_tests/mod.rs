@@ -1,2 +1,3 @@
 mod general_esys_tr_tests;
+mod session_administration_tests;
 mod tpm_commands;
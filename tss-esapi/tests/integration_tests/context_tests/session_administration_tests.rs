@@ -0,0 +1,85 @@
+// Copyright 2023 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+mod test_tr_sess_get_nonce_tpm {
+    use crate::common::create_ctx_without_session;
+    use tss_esapi::{
+        constants::SessionType,
+        interface_types::{algorithm::HashingAlgorithm, session_handles::AuthSession},
+        structures::SymmetricDefinition,
+    };
+
+    #[test]
+    fn test_tr_sess_get_nonce_tpm() {
+        let mut context = create_ctx_without_session();
+        let session = context
+            .start_auth_session(
+                None,
+                None,
+                None,
+                SessionType::Hmac,
+                SymmetricDefinition::AES_256_CFB,
+                HashingAlgorithm::Sha256,
+            )
+            .expect("Failed to create session")
+            .expect("Received invalid handle");
+        let nonce_tpm = context
+            .tr_sess_get_nonce_tpm(session)
+            .expect("Failed to call tr_sess_get_nonce_tpm");
+        // The TPM uses nonces with the size of the session digest.
+        assert_eq!(32, nonce_tpm.len());
+
+        let _ = context
+            .tr_sess_get_nonce_tpm(AuthSession::Password)
+            .expect_err("Getting the nonce of a password session did not fail");
+    }
+}
+
+mod test_session_info {
+    use crate::common::create_ctx_without_session;
+    use tss_esapi::{
+        attributes::SessionAttributesBuilder,
+        constants::SessionType,
+        interface_types::{algorithm::HashingAlgorithm, session_handles::AuthSession},
+        structures::SymmetricDefinition,
+        Error, WrapperErrorKind,
+    };
+
+    #[test]
+    fn test_session_info() {
+        let mut context = create_ctx_without_session();
+        let session = context
+            .start_auth_session(
+                None,
+                None,
+                None,
+                SessionType::Policy,
+                SymmetricDefinition::AES_128_CFB,
+                HashingAlgorithm::Sha1,
+            )
+            .expect("Failed to create session")
+            .expect("Received invalid handle");
+        let (session_attributes, session_attributes_mask) = SessionAttributesBuilder::new()
+            .with_decrypt(true)
+            .with_encrypt(true)
+            .build();
+        context
+            .tr_sess_set_attributes(session, session_attributes, session_attributes_mask)
+            .expect("Failed to set attributes on session");
+
+        let session_info = context
+            .session_info(session)
+            .expect("Failed to call session_info");
+        assert_eq!(HashingAlgorithm::Sha1, session_info.hashing_algorithm);
+        assert!(session_info.attributes.decrypt());
+        assert!(session_info.attributes.encrypt());
+        assert_eq!(
+            context.tr_sess_get_nonce_tpm(session).unwrap(),
+            session_info.nonce_tpm
+        );
+
+        assert_eq!(
+            Err(Error::WrapperError(WrapperErrorKind::InvalidParam)),
+            context.session_info(AuthSession::Password)
+        );
+    }
+}
@@ -104,3 +104,102 @@ mod test_rsa_encrypt_decrypt {
         assert_eq!(z_point.x().as_bytes(), param.x().as_bytes());
     }
 }
+
+mod test_ecc_parameters {
+    use crate::common::create_ctx_without_session;
+    use tss_esapi::interface_types::ecc::EccCurve;
+
+    #[test]
+    fn test_ecc_parameters() {
+        let mut context = create_ctx_without_session();
+
+        let parameters = context
+            .ecc_parameters(EccCurve::NistP256)
+            .expect("Call to ecc_parameters failed");
+
+        assert_eq!(parameters.curve_id(), EccCurve::NistP256);
+        assert_eq!(parameters.key_size(), 256);
+        assert_eq!(parameters.p().len(), 32);
+        assert_eq!(parameters.n().len(), 32);
+        assert_eq!(parameters.g().x(), parameters.g_x());
+        assert_eq!(parameters.h().as_bytes(), [0x01]);
+    }
+}
+
+mod test_zgen_2phase {
+    use crate::common::create_ctx_with_session;
+    use tss_esapi::{
+        attributes::ObjectAttributesBuilder,
+        interface_types::{
+            algorithm::{EccKeyExchangeAlgorithm, HashingAlgorithm, PublicAlgorithm},
+            ecc::EccCurve,
+            resource_handles::Hierarchy,
+        },
+        structures::{
+            EccPoint, EccScheme, HashScheme, KeyDerivationFunctionScheme, PublicBuilder,
+            PublicEccParametersBuilder,
+        },
+    };
+
+    #[test]
+    fn test_zgen_2phase() {
+        let mut context = create_ctx_with_session();
+
+        let ecc_parms = PublicEccParametersBuilder::new()
+            .with_ecc_scheme(EccScheme::EcDh(HashScheme::new(HashingAlgorithm::Sha256)))
+            .with_curve(EccCurve::NistP256)
+            .with_is_signing_key(false)
+            .with_is_decryption_key(true)
+            .with_restricted(false)
+            .with_key_derivation_function_scheme(KeyDerivationFunctionScheme::Null)
+            .build()
+            .unwrap();
+
+        let object_attributes = ObjectAttributesBuilder::new()
+            .with_fixed_tpm(true)
+            .with_fixed_parent(true)
+            .with_sensitive_data_origin(true)
+            .with_user_with_auth(true)
+            .with_decrypt(true)
+            .with_sign_encrypt(false)
+            .with_restricted(false)
+            .build()
+            .unwrap();
+
+        let public = PublicBuilder::new()
+            .with_public_algorithm(PublicAlgorithm::Ecc)
+            .with_name_hashing_algorithm(HashingAlgorithm::Sha256)
+            .with_object_attributes(object_attributes)
+            .with_ecc_parameters(ecc_parms)
+            .with_ecc_unique_identifier(EccPoint::default())
+            .build()
+            .unwrap();
+
+        let key_handle = context
+            .create_primary(Hierarchy::Owner, public, None, None, None, None)
+            .unwrap()
+            .key_handle;
+
+        // Party B is simulated with points generated by the TPM.
+        let (_, static_b) = context.ecdh_key_gen(key_handle).unwrap();
+        let (_, ephemeral_b) = context.ecdh_key_gen(key_handle).unwrap();
+        let (_, counter) = context
+            .execute_without_session(|ctx| ctx.ec_ephemeral(EccCurve::NistP256))
+            .expect("Call to ec_ephemeral failed");
+
+        let (z1, z2) = context
+            .zgen_2phase(
+                key_handle,
+                static_b.clone(),
+                ephemeral_b,
+                EccKeyExchangeAlgorithm::EcDh,
+                counter,
+            )
+            .expect("Call to zgen_2phase failed");
+
+        // For ECDH, Z1 is the product of the static key of A and the static key of B.
+        let expected_z1 = context.ecdh_z_gen(key_handle, static_b).unwrap();
+        assert_eq!(z1.x(), expected_z1.x());
+        assert_eq!(z2.x().len(), 32);
+    }
+}
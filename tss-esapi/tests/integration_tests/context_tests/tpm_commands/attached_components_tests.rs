@@ -1,2 +1,235 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
+#[cfg(has_esys_ac)]
+mod test_ac_get_capability {
+    use crate::common::create_ctx_without_session;
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        constants::{tss::TPM2_AC_FIRST, AttachedComponentTag},
+        handles::{AttachedComponentTpmHandle, TpmHandle},
+    };
+
+    #[test]
+    fn test_ac_get_capability() {
+        let mut context = create_ctx_without_session();
+        // The reference implementation only provides access to the first attached component.
+        let ac_handle = context
+            .tr_from_tpm_public(TpmHandle::from(
+                AttachedComponentTpmHandle::try_from(TPM2_AC_FIRST + 1)
+                    .expect("Failed to create attached component handle"),
+            ))
+            .expect("Failed to get the ESYS handle of the attached component");
+
+        let (ac_capabilities, _more_data) = context
+            .ac_get_capability(ac_handle, AttachedComponentTag::Any, 10)
+            .expect("Call to ac_get_capability failed");
+        assert!(ac_capabilities.len() <= 10);
+    }
+}
+
+#[cfg(has_esys_ac)]
+mod test_ac_send {
+    use crate::common::{create_ctx_with_session, decryption_key_pub};
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        attributes::ObjectAttributesBuilder,
+        constants::{tss::TPM2_AC_FIRST, CommandCode, SessionType},
+        handles::{AttachedComponentTpmHandle, ObjectHandle, SessionHandle, TpmHandle},
+        interface_types::{
+            algorithm::{HashingAlgorithm, PublicAlgorithm},
+            ecc::EccCurve,
+            resource_handles::{Hierarchy, NvAuth},
+            session_handles::{AuthSession, PolicySession},
+        },
+        structures::{
+            Digest, EccPoint, EccScheme, KeyDerivationFunctionScheme, MaxBuffer, Public,
+            PublicBuilder, PublicEccParametersBuilder, SymmetricDefinition,
+        },
+        Context,
+    };
+
+    /// Starts a policy session of type `session_type` that
+    /// satisfies PolicyCommandCode(TPM2_CC_AC_Send).
+    fn ac_send_policy_session(context: &mut Context, session_type: SessionType) -> PolicySession {
+        let session = context
+            .execute_without_session(|ctx| {
+                ctx.start_auth_session(
+                    None,
+                    None,
+                    None,
+                    session_type,
+                    SymmetricDefinition::AES_256_CFB,
+                    HashingAlgorithm::Sha256,
+                )
+            })
+            .expect("Start auth session failed")
+            .expect("Start auth session returned a NONE handle");
+        let policy_session =
+            PolicySession::try_from(session).expect("Failed to convert into PolicySession");
+        context
+            .execute_without_session(|ctx| {
+                ctx.policy_command_code(policy_session, CommandCode::AcSend)
+            })
+            .expect("Call to policy_command_code failed");
+        policy_session
+    }
+
+    /// Creates the public area of a key that can be duplicated,
+    /// and therefore sent, when `auth_policy` is satisfied.
+    fn duplicable_key_pub(auth_policy: Digest) -> Public {
+        // Fixed TPM and Fixed Parent should be "false" for an object
+        // to be eligible for duplication
+        let object_attributes = ObjectAttributesBuilder::new()
+            .with_fixed_tpm(false)
+            .with_fixed_parent(false)
+            .with_sensitive_data_origin(true)
+            .with_user_with_auth(true)
+            .with_decrypt(true)
+            .build()
+            .expect("Attributes to be valid");
+        PublicBuilder::new()
+            .with_public_algorithm(PublicAlgorithm::Ecc)
+            .with_name_hashing_algorithm(HashingAlgorithm::Sha256)
+            .with_object_attributes(object_attributes)
+            .with_auth_policy(auth_policy)
+            .with_ecc_parameters(
+                PublicEccParametersBuilder::new()
+                    .with_ecc_scheme(EccScheme::Null)
+                    .with_curve(EccCurve::NistP256)
+                    .with_is_signing_key(false)
+                    .with_is_decryption_key(true)
+                    .with_restricted(false)
+                    .with_key_derivation_function_scheme(KeyDerivationFunctionScheme::Null)
+                    .build()
+                    .expect("Params to be valid"),
+            )
+            .with_ecc_unique_identifier(EccPoint::default())
+            .build()
+            .expect("Public to be valid")
+    }
+
+    #[test]
+    fn test_ac_send() {
+        let mut context = create_ctx_with_session();
+
+        // The object can only be sent in the DUP role, which requires
+        // a policy session that satisfies the policy of the object.
+        let trial_session = ac_send_policy_session(&mut context, SessionType::Trial);
+        let auth_policy = context
+            .execute_without_session(|ctx| ctx.policy_get_digest(trial_session))
+            .expect("Failed to get policy digest");
+        context
+            .flush_context(ObjectHandle::from(SessionHandle::from(trial_session)))
+            .expect("Failed to flush trial session");
+
+        let parent_handle = context
+            .create_primary(
+                Hierarchy::Owner,
+                decryption_key_pub(),
+                None,
+                None,
+                None,
+                None,
+            )
+            .expect("Failed to create primary key")
+            .key_handle;
+        let result = context
+            .create(
+                parent_handle,
+                duplicable_key_pub(auth_policy),
+                None,
+                None,
+                None,
+                None,
+            )
+            .expect("Failed to create duplicable key");
+        let key_handle = context
+            .load(parent_handle, result.out_private, result.out_public)
+            .expect("Failed to load duplicable key");
+        let ac_handle = context
+            .tr_from_tpm_public(TpmHandle::from(
+                AttachedComponentTpmHandle::try_from(TPM2_AC_FIRST + 1)
+                    .expect("Failed to create attached component handle"),
+            ))
+            .expect("Failed to get the ESYS handle of the attached component");
+
+        let policy_session = ac_send_policy_session(&mut context, SessionType::Policy);
+        let _ = context
+            .execute_with_sessions(
+                (
+                    Some(AuthSession::PolicySession(policy_session)),
+                    Some(AuthSession::Password),
+                    None,
+                ),
+                |ctx| {
+                    ctx.ac_send(
+                        key_handle.into(),
+                        NvAuth::Owner,
+                        ac_handle,
+                        MaxBuffer::try_from(vec![1, 2, 3]).expect("Failed to create MaxBuffer"),
+                    )
+                },
+            )
+            .expect("Call to ac_send failed");
+    }
+}
+
+#[cfg(has_esys_ac)]
+mod test_policy_ac_send_select {
+    use crate::common::create_ctx_without_session;
+    use sha2::{Digest as _, Sha256};
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        constants::SessionType,
+        interface_types::{algorithm::HashingAlgorithm, session_handles::PolicySession},
+        structures::{Name, SymmetricDefinition},
+    };
+
+    #[test]
+    fn test_policy_ac_send_select() {
+        let mut context = create_ctx_without_session();
+        let trial_session = context
+            .start_auth_session(
+                None,
+                None,
+                None,
+                SessionType::Trial,
+                SymmetricDefinition::AES_256_CFB,
+                HashingAlgorithm::Sha256,
+            )
+            .expect("Start auth session failed")
+            .expect("Start auth session returned a NONE handle");
+        let policy_session =
+            PolicySession::try_from(trial_session).expect("Failed to convert into PolicySession");
+
+        let object_name =
+            Name::try_from(vec![0x00, 0x0b, 0x11, 0x22]).expect("Failed to create object name");
+        let auth_handle_name = Name::try_from(0x4000_0001u32.to_be_bytes().to_vec())
+            .expect("Failed to create auth handle name");
+        let ac_name = Name::try_from(0x9000_0001u32.to_be_bytes().to_vec())
+            .expect("Failed to create attached component name");
+
+        context
+            .policy_ac_send_select(
+                policy_session,
+                object_name.clone(),
+                auth_handle_name.clone(),
+                ac_name.clone(),
+                true,
+            )
+            .expect("Call to policy_ac_send_select failed");
+        let policy_digest = context
+            .policy_get_digest(policy_session)
+            .expect("Failed to get policy digest");
+
+        // policyDigest := H(0...0 || TPM_CC_Policy_AC_SendSelect || objectName || authHandleName || acName || includeObject)
+        let mut hasher = Sha256::new();
+        hasher.update([0u8; 32]);
+        hasher.update(0x0000_0196u32.to_be_bytes());
+        hasher.update(object_name.value());
+        hasher.update(auth_handle_name.value());
+        hasher.update(ac_name.value());
+        hasher.update([1u8]);
+        assert_eq!(policy_digest.as_bytes(), hasher.finalize().as_slice());
+    }
+}
@@ -167,3 +167,409 @@ mod test_quote {
         assert_eq!(signature.algorithm(), SignatureSchemeAlgorithm::Null);
     }
 }
+
+mod test_certify_creation {
+    use crate::common::{create_ctx_with_session, decryption_key_pub, signing_key_pub};
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        interface_types::{resource_handles::Hierarchy, structure_tags::AttestationType},
+        structures::{AttestInfo, Data, Digest, SignatureScheme},
+    };
+
+    #[test]
+    fn certify_creation() {
+        let mut context = create_ctx_with_session();
+        let qualifying_data = vec![0xff; 16];
+
+        let sign_key_handle = context
+            .create_primary(Hierarchy::Owner, signing_key_pub(), None, None, None, None)
+            .unwrap()
+            .key_handle;
+        let created_primary = context
+            .create_primary(
+                Hierarchy::Owner,
+                decryption_key_pub(),
+                None,
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+        let object_name = context
+            .tr_get_name(created_primary.key_handle.into())
+            .expect("Failed to get the name of the created key");
+
+        let (attest, _signature) = context
+            .certify_creation(
+                sign_key_handle,
+                created_primary.key_handle.into(),
+                Data::try_from(qualifying_data.clone()).unwrap(),
+                created_primary.creation_hash.clone(),
+                SignatureScheme::Null,
+                created_primary.creation_ticket,
+            )
+            .expect("Failed to certify creation");
+
+        assert_eq!(attest.attestation_type(), AttestationType::Creation);
+        assert_eq!(attest.extra_data().as_bytes(), qualifying_data);
+        match attest.attested() {
+            AttestInfo::Creation { info } => {
+                assert_eq!(info.object_name(), &object_name);
+                assert_eq!(info.creation_hash(), &created_primary.creation_hash);
+            }
+            _ => {
+                panic!("Attested did not contain the expected variant.")
+            }
+        }
+    }
+
+    #[test]
+    fn certify_creation_with_wrong_hash() {
+        let mut context = create_ctx_with_session();
+
+        let sign_key_handle = context
+            .create_primary(Hierarchy::Owner, signing_key_pub(), None, None, None, None)
+            .unwrap()
+            .key_handle;
+        let created_primary = context
+            .create_primary(
+                Hierarchy::Owner,
+                decryption_key_pub(),
+                None,
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+
+        // The ticket does not match a different creation hash
+        let _ = context
+            .certify_creation(
+                sign_key_handle,
+                created_primary.key_handle.into(),
+                Data::default(),
+                Digest::try_from(vec![0xab; 32]).unwrap(),
+                SignatureScheme::Null,
+                created_primary.creation_ticket,
+            )
+            .unwrap_err();
+    }
+}
+
+mod test_get_time {
+    use crate::common::{create_ctx_with_session, signing_key_pub};
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        constants::StructureTag,
+        interface_types::{
+            algorithm::HashingAlgorithm, resource_handles::Hierarchy, session_handles::AuthSession,
+            structure_tags::AttestationType,
+        },
+        structures::{AttestInfo, Data, MaxBuffer, SignatureScheme, Ticket},
+        traits::Marshall,
+    };
+
+    #[test]
+    fn get_time() {
+        let mut context = create_ctx_with_session();
+        let qualifying_data = vec![0xff; 16];
+
+        let sign_key_handle = context
+            .create_primary(Hierarchy::Owner, signing_key_pub(), None, None, None, None)
+            .unwrap()
+            .key_handle;
+
+        let (attest, signature) = context
+            .execute_with_sessions(
+                (
+                    Some(AuthSession::Password),
+                    Some(AuthSession::Password),
+                    None,
+                ),
+                |ctx| {
+                    ctx.get_time(
+                        sign_key_handle,
+                        Data::try_from(qualifying_data.clone()).unwrap(),
+                        SignatureScheme::Null,
+                    )
+                },
+            )
+            .expect("Failed to get time");
+
+        // Verify the signature is valid for the attestation data
+
+        let data = MaxBuffer::try_from(attest.marshall().unwrap())
+            .expect("Failed to get data buffer from attestation data");
+        let (digest, _) = context
+            .hash(data, HashingAlgorithm::Sha256, Hierarchy::Null)
+            .expect("Failed to hash data");
+
+        let ticket = context
+            .execute_with_nullauth_session(|ctx| {
+                ctx.verify_signature(sign_key_handle, digest, signature)
+            })
+            .expect("Failed to verify signature");
+        assert_eq!(ticket.tag(), StructureTag::Verified);
+
+        // Verify the attestation data is as expected
+
+        assert_eq!(attest.attestation_type(), AttestationType::Time);
+        assert_eq!(attest.extra_data().as_bytes(), qualifying_data);
+        match attest.attested() {
+            AttestInfo::Time { info } => {
+                assert_eq!(
+                    attest.clock_info(),
+                    info.time_info().clock_info(),
+                    "TimeAttestInfo clock info did not match the clock info of the attestation"
+                );
+            }
+            _ => {
+                panic!("Attested did not contain the expected variant.")
+            }
+        }
+    }
+}
+
+#[cfg(has_esys_certify_x509)]
+mod test_certify_x509 {
+    use crate::common::{create_ctx_with_session, partial_certificate_der, signing_key_pub};
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        constants::StructureTag,
+        interface_types::{
+            algorithm::HashingAlgorithm, resource_handles::Hierarchy, session_handles::AuthSession,
+        },
+        structures::{MaxBuffer, Signature, SignatureScheme, Ticket},
+    };
+
+    #[test]
+    fn certify_x509() {
+        let mut context = create_ctx_with_session();
+
+        let sign_key_handle = context
+            .create_primary(Hierarchy::Owner, signing_key_pub(), None, None, None, None)
+            .unwrap()
+            .key_handle;
+
+        // The key usage extension of the partial certificate only allows
+        // digital signatures, so the certified object needs to be a signing key.
+        let (added_to_certificate, tbs_digest, signature) = context
+            .execute_with_sessions(
+                (
+                    Some(AuthSession::Password),
+                    Some(AuthSession::Password),
+                    None,
+                ),
+                |ctx| {
+                    ctx.certify_x509(
+                        sign_key_handle.into(),
+                        sign_key_handle,
+                        SignatureScheme::Null,
+                        MaxBuffer::try_from(partial_certificate_der()).unwrap(),
+                    )
+                },
+            )
+            .expect("Call to certify_x509 failed");
+
+        // The fields added by the TPM are a DER encoded SEQUENCE.
+        assert_eq!(added_to_certificate.as_bytes().first(), Some(&0x30));
+
+        // The TBS digest is computed with the hashing algorithm of the signing scheme.
+        assert_eq!(tbs_digest.len(), 32);
+        match &signature {
+            Signature::RsaSsa(rsa_signature) => {
+                assert_eq!(rsa_signature.hashing_algorithm(), HashingAlgorithm::Sha256)
+            }
+            _ => panic!("Signature did not contain the expected variant."),
+        }
+
+        let ticket = context
+            .execute_with_nullauth_session(|ctx| {
+                ctx.verify_signature(sign_key_handle, tbs_digest, signature)
+            })
+            .expect("Failed to verify signature");
+        assert_eq!(ticket.tag(), StructureTag::Verified);
+    }
+}
+
+mod test_audit_digest {
+    use crate::common::{create_ctx_without_session, signing_key_pub};
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        abstraction::audit::{compute_audit_digest, AuditEntry},
+        attributes::SessionAttributesBuilder,
+        constants::{CommandCode, SessionType},
+        handles::KeyHandle,
+        interface_types::{
+            algorithm::HashingAlgorithm,
+            resource_handles::{Hierarchy, Provision},
+            session_handles::AuthSession,
+            structure_tags::AttestationType,
+        },
+        structures::{AttestInfo, CommandCodeList, Data, SignatureScheme, SymmetricDefinition},
+        Context,
+    };
+
+    fn create_signing_key(context: &mut Context) -> KeyHandle {
+        context
+            .execute_with_session(Some(AuthSession::Password), |ctx| {
+                ctx.create_primary(Hierarchy::Owner, signing_key_pub(), None, None, None, None)
+            })
+            .expect("Call to create_primary failed")
+            .key_handle
+    }
+
+    // GetRandom parameters: bytesRequested
+    fn get_random_audit_entry(hashing_algorithm: HashingAlgorithm, bytes: &[u8]) -> AuditEntry {
+        let command_parameters = u16::try_from(bytes.len()).unwrap().to_be_bytes();
+        let response_parameters = [&command_parameters[..], bytes].concat();
+        AuditEntry::compute(
+            hashing_algorithm,
+            CommandCode::GetRandom,
+            &[],
+            &command_parameters,
+            &response_parameters,
+        )
+        .expect("Failed to compute audit entry")
+    }
+
+    #[test]
+    fn get_command_audit_digest() {
+        let mut context = create_ctx_without_session();
+        let sign_key_handle = create_signing_key(&mut context);
+        let qualifying_data = Data::try_from(vec![0xff; 16]).unwrap();
+        let audited_commands = CommandCodeList::try_from(vec![CommandCode::GetRandom]).unwrap();
+
+        context
+            .execute_with_session(Some(AuthSession::Password), |ctx| {
+                ctx.set_command_code_audit_status(
+                    Provision::Owner,
+                    HashingAlgorithm::Null,
+                    audited_commands.clone(),
+                    CommandCodeList::new(),
+                )
+            })
+            .expect("Call to set_command_code_audit_status failed");
+
+        let result = context.execute_with_sessions(
+            (
+                Some(AuthSession::Password),
+                Some(AuthSession::Password),
+                None,
+            ),
+            |ctx| {
+                // Clear the command audit digest
+                let _ = ctx.get_command_audit_digest(
+                    sign_key_handle,
+                    qualifying_data.clone(),
+                    SignatureScheme::Null,
+                )?;
+                let random_bytes = ctx.execute_without_session(|ctx| ctx.get_random(8))?;
+                ctx.get_command_audit_digest(
+                    sign_key_handle,
+                    qualifying_data.clone(),
+                    SignatureScheme::Null,
+                )
+                .map(|(attest, _)| (random_bytes, attest))
+            },
+        );
+
+        context
+            .execute_with_session(Some(AuthSession::Password), |ctx| {
+                ctx.set_command_code_audit_status(
+                    Provision::Owner,
+                    HashingAlgorithm::Null,
+                    CommandCodeList::new(),
+                    audited_commands,
+                )
+            })
+            .expect("Call to set_command_code_audit_status failed");
+
+        let (random_bytes, attest) = result.expect("Call to get_command_audit_digest failed");
+        assert_eq!(attest.attestation_type(), AttestationType::CommandAudit);
+        assert_eq!(attest.extra_data(), &qualifying_data);
+        match attest.attested() {
+            AttestInfo::CommandAudit { info } => {
+                let expected_digest = compute_audit_digest(
+                    info.hashing_algorithm(),
+                    &[get_random_audit_entry(
+                        info.hashing_algorithm(),
+                        random_bytes.as_bytes(),
+                    )],
+                )
+                .expect("Failed to compute audit digest");
+                assert_eq!(&expected_digest, info.audit_digest());
+            }
+            _ => {
+                panic!("Attested did not contain the expected variant.")
+            }
+        }
+    }
+
+    #[test]
+    fn get_session_audit_digest() {
+        let mut context = create_ctx_without_session();
+        let sign_key_handle = create_signing_key(&mut context);
+        let qualifying_data = Data::try_from(vec![0xff; 16]).unwrap();
+
+        let audit_session = context
+            .start_auth_session(
+                None,
+                None,
+                None,
+                SessionType::Hmac,
+                SymmetricDefinition::AES_256_CFB,
+                HashingAlgorithm::Sha256,
+            )
+            .expect("Failed to start audit session")
+            .expect("Received invalid handle");
+        let (session_attributes, session_attributes_mask) = SessionAttributesBuilder::new()
+            .with_continue_session(true)
+            .with_audit(true)
+            .build();
+        context
+            .tr_sess_set_attributes(audit_session, session_attributes, session_attributes_mask)
+            .expect("Failed to set attributes on session");
+
+        let random_bytes = context
+            .execute_with_session(Some(audit_session), |ctx| ctx.get_random(8))
+            .expect("Call to get_random failed");
+
+        let (attest, _) = context
+            .execute_with_sessions(
+                (
+                    Some(AuthSession::Password),
+                    Some(AuthSession::Password),
+                    None,
+                ),
+                |ctx| {
+                    ctx.get_session_audit_digest(
+                        sign_key_handle,
+                        audit_session,
+                        qualifying_data.clone(),
+                        SignatureScheme::Null,
+                    )
+                },
+            )
+            .expect("Call to get_session_audit_digest failed");
+
+        assert_eq!(attest.attestation_type(), AttestationType::SessionAudit);
+        assert_eq!(attest.extra_data(), &qualifying_data);
+        match attest.attested() {
+            AttestInfo::SessionAudit { info } => {
+                let expected_digest = compute_audit_digest(
+                    HashingAlgorithm::Sha256,
+                    &[get_random_audit_entry(
+                        HashingAlgorithm::Sha256,
+                        random_bytes.as_bytes(),
+                    )],
+                )
+                .expect("Failed to compute audit digest");
+                assert_eq!(&expected_digest, info.session_digest());
+            }
+            _ => {
+                panic!("Attested did not contain the expected variant.")
+            }
+        }
+    }
+}
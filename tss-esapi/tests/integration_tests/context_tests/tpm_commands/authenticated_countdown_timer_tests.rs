@@ -1,2 +1,43 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
+#[cfg(has_esys_act)]
+mod test_act_set_timeout {
+    use crate::common::create_ctx_without_session;
+    use tss_esapi::{
+        constants::CapabilityType,
+        handles::TpmHandle,
+        interface_types::{resource_handles::Act, session_handles::AuthSession},
+        structures::CapabilityData,
+    };
+
+    #[test]
+    fn test_act_set_timeout() {
+        let mut context = create_ctx_without_session();
+        let start_timeout = 60;
+        context
+            .execute_with_session(Some(AuthSession::Password), |ctx| {
+                ctx.act_set_timeout(Act::Act0, start_timeout)
+            })
+            .expect("Call to act_set_timeout failed");
+
+        let (capability_data, _) = context
+            .get_capability(CapabilityType::Act, TpmHandle::from(Act::Act0).into(), 1)
+            .expect("Failed to get the ACT capability");
+        match capability_data {
+            CapabilityData::ActData(act_data_list) => {
+                let act_data = act_data_list
+                    .find(Act::Act0)
+                    .expect("The TPM did not report the state of ACT 0");
+                assert!(act_data.timeout() <= start_timeout);
+            }
+            _ => panic!("Got wrong type of capability data: {:?}", capability_data),
+        }
+
+        // Stop the timer again.
+        context
+            .execute_with_session(Some(AuthSession::Password), |ctx| {
+                ctx.act_set_timeout(Act::Act0, 0)
+            })
+            .expect("Call to act_set_timeout failed");
+    }
+}
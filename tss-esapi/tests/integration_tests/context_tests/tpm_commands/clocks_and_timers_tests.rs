@@ -1,2 +1,79 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
+mod test_read_clock {
+    use crate::common::create_ctx_without_session;
+
+    #[test]
+    fn test_read_clock() {
+        let mut context = create_ctx_without_session();
+        let first_time_info = context.read_clock().expect("Call to read_clock failed");
+        let second_time_info = context.read_clock().expect("Call to read_clock failed");
+
+        assert!(first_time_info.time() <= second_time_info.time());
+        assert!(first_time_info.clock_info().clock() <= second_time_info.clock_info().clock());
+        assert_eq!(
+            first_time_info.clock_info().reset_count(),
+            second_time_info.clock_info().reset_count()
+        );
+    }
+}
+
+mod test_clock_set {
+    use crate::common::create_ctx_without_session;
+    use tss_esapi::interface_types::{resource_handles::Provision, session_handles::AuthSession};
+
+    #[test]
+    fn test_clock_set() {
+        let mut context = create_ctx_without_session();
+        let current_clock = context
+            .read_clock()
+            .expect("Call to read_clock failed")
+            .clock_info()
+            .clock();
+        let new_clock = current_clock + 10_000;
+
+        context
+            .execute_with_session(Some(AuthSession::Password), |ctx| {
+                ctx.clock_set(Provision::Owner, new_clock)
+            })
+            .expect("Call to clock_set failed");
+
+        let time_info = context.read_clock().expect("Call to read_clock failed");
+        assert!(time_info.clock_info().clock() >= new_clock);
+    }
+
+    #[test]
+    fn test_clock_set_backwards_fails() {
+        let mut context = create_ctx_without_session();
+        let current_clock = context
+            .read_clock()
+            .expect("Call to read_clock failed")
+            .clock_info()
+            .clock();
+
+        let _ = context
+            .execute_with_session(Some(AuthSession::Password), |ctx| {
+                ctx.clock_set(Provision::Owner, current_clock / 2)
+            })
+            .unwrap_err();
+    }
+}
+
+mod test_clock_rate_adjust {
+    use crate::common::create_ctx_without_session;
+    use tss_esapi::{
+        constants::ClockAdjust,
+        interface_types::{resource_handles::Provision, session_handles::AuthSession},
+    };
+
+    #[test]
+    fn test_clock_rate_adjust() {
+        let mut context = create_ctx_without_session();
+        context
+            .execute_with_session(Some(AuthSession::Password), |ctx| {
+                ctx.clock_rate_adjust(Provision::Owner, ClockAdjust::FineFaster)?;
+                ctx.clock_rate_adjust(Provision::Owner, ClockAdjust::FineSlower)
+            })
+            .expect("Call to clock_rate_adjust failed");
+    }
+}
@@ -1,2 +1,74 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
+mod test_dictionary_attack_lock_reset {
+    use crate::common::create_ctx_with_session;
+    use tss_esapi::{
+        abstraction::dictionary_attack::LockoutStatus, interface_types::resource_handles::Lockout,
+    };
+
+    #[test]
+    fn test_lock_reset() {
+        let mut context = create_ctx_with_session();
+        context
+            .dictionary_attack_lock_reset(Lockout::Lockout)
+            .expect("Failed to reset the dictionary attack lockout");
+
+        let lockout_status =
+            LockoutStatus::read(&mut context).expect("Failed to read the lockout status");
+        assert_eq!(lockout_status.lockout_counter(), 0);
+        assert!(!lockout_status.is_locked_out());
+    }
+}
+
+mod test_dictionary_attack_parameters {
+    use crate::common::create_ctx_with_session;
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        abstraction::dictionary_attack::LockoutStatus, interface_types::resource_handles::Lockout,
+    };
+
+    #[test]
+    fn test_set_parameters() {
+        let mut context = create_ctx_with_session();
+        let initial_status =
+            LockoutStatus::read(&mut context).expect("Failed to read the lockout status");
+        let initial_recovery_time =
+            u32::try_from(initial_status.lockout_interval().as_secs()).unwrap();
+        let initial_lockout_recovery =
+            u32::try_from(initial_status.lockout_recovery().as_secs()).unwrap();
+
+        context
+            .dictionary_attack_parameters(
+                Lockout::Lockout,
+                initial_status.max_auth_fail() + 1,
+                initial_recovery_time + 1,
+                initial_lockout_recovery + 1,
+            )
+            .expect("Failed to set the dictionary attack parameters");
+
+        let updated_status =
+            LockoutStatus::read(&mut context).expect("Failed to read the lockout status");
+        assert_eq!(
+            updated_status.max_auth_fail(),
+            initial_status.max_auth_fail() + 1
+        );
+        assert_eq!(
+            updated_status.lockout_interval().as_secs(),
+            initial_status.lockout_interval().as_secs() + 1
+        );
+        assert_eq!(
+            updated_status.lockout_recovery().as_secs(),
+            initial_status.lockout_recovery().as_secs() + 1
+        );
+
+        // Restore the initial parameters
+        context
+            .dictionary_attack_parameters(
+                Lockout::Lockout,
+                initial_status.max_auth_fail(),
+                initial_recovery_time,
+                initial_lockout_recovery,
+            )
+            .expect("Failed to restore the dictionary attack parameters");
+    }
+}
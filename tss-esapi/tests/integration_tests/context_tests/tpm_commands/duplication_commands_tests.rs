@@ -303,3 +303,112 @@ mod test_duplicate {
         eprintln!("P: {:?}", private);
     }
 }
+
+mod test_rewrap {
+    use crate::common::{create_ctx_with_session, decryption_key_pub};
+    use tss_esapi::{
+        abstraction::reparent::{duplication_select_policy, DuplicatedObject},
+        attributes::ObjectAttributesBuilder,
+        interface_types::{
+            algorithm::{HashingAlgorithm, PublicAlgorithm},
+            resource_handles::Hierarchy,
+        },
+        structures::{
+            Digest, KeyedHashScheme, PublicBuilder, PublicKeyedHashParameters,
+            SymmetricDefinitionObject,
+        },
+    };
+
+    #[test]
+    fn test_rewrap() {
+        let mut context = create_ctx_with_session();
+        let old_parent_handle = context
+            .create_primary(
+                Hierarchy::Owner,
+                decryption_key_pub(),
+                None,
+                None,
+                None,
+                None,
+            )
+            .unwrap()
+            .key_handle;
+        let new_parent_handle = context
+            .create_primary(
+                Hierarchy::Endorsement,
+                decryption_key_pub(),
+                None,
+                None,
+                None,
+                None,
+            )
+            .unwrap()
+            .key_handle;
+        let old_parent_name = context.tr_get_name(old_parent_handle.into()).unwrap();
+
+        let policy =
+            duplication_select_policy(&mut context, old_parent_name, HashingAlgorithm::Sha256)
+                .unwrap();
+        let object_attributes = ObjectAttributesBuilder::new()
+            .with_sensitive_data_origin(true)
+            .with_user_with_auth(true)
+            .with_sign_encrypt(true)
+            .build()
+            .expect("Attributes to be valid");
+        let public = PublicBuilder::new()
+            .with_public_algorithm(PublicAlgorithm::KeyedHash)
+            .with_name_hashing_algorithm(HashingAlgorithm::Sha256)
+            .with_object_attributes(object_attributes)
+            .with_auth_policy(policy)
+            .with_keyed_hash_parameters(PublicKeyedHashParameters::new(
+                KeyedHashScheme::HMAC_SHA_256,
+            ))
+            .with_keyed_hash_unique_identifier(Digest::default())
+            .build()
+            .expect("Public to be valid");
+        let result = context
+            .create(old_parent_handle, public, None, None, None, None)
+            .unwrap();
+        let key_handle = context
+            .load(old_parent_handle, result.out_private, result.out_public)
+            .unwrap();
+
+        // Duplicate the key to the old parent, then move the outer wrapper
+        // to the new parent.
+        let duplicated_object = DuplicatedObject::duplicate(
+            &mut context,
+            key_handle.into(),
+            old_parent_handle.into(),
+            SymmetricDefinitionObject::Null,
+        )
+        .unwrap();
+        context.flush_context(key_handle.into()).unwrap();
+        let (duplicate, sym_seed) = context
+            .rewrap(
+                old_parent_handle.into(),
+                new_parent_handle.into(),
+                duplicated_object.duplicate_private().clone(),
+                duplicated_object.name().clone(),
+                duplicated_object.sym_seed().clone(),
+            )
+            .unwrap();
+
+        let private = context
+            .import(
+                new_parent_handle.into(),
+                None,
+                duplicated_object.public().clone(),
+                duplicate,
+                sym_seed,
+                SymmetricDefinitionObject::Null,
+            )
+            .unwrap();
+        let _ = context
+            .load(
+                new_parent_handle,
+                private,
+                duplicated_object.public().clone(),
+            )
+            .unwrap();
+    }
+}
@@ -141,6 +141,57 @@ mod test_policy_secret {
     }
 }
 
+mod test_policy_ticket {
+    use crate::common::create_ctx_with_session;
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        constants::{tss::TPM2_RH_OWNER, SessionType, StructureTag},
+        interface_types::{algorithm::HashingAlgorithm, session_handles::PolicySession},
+        structures::{AuthTicket, Digest, Name, Nonce, SymmetricDefinition, Timeout},
+        tss2_esys::TPMT_TK_AUTH,
+    };
+    #[test]
+    fn test_policy_ticket_invalid_ticket() {
+        let mut context = create_ctx_with_session();
+        let policy_auth_session = context
+            .execute_without_session(|ctx| {
+                ctx.start_auth_session(
+                    None,
+                    None,
+                    None,
+                    SessionType::Policy,
+                    SymmetricDefinition::AES_256_CFB,
+                    HashingAlgorithm::Sha256,
+                )
+            })
+            .expect("Start auth session failed")
+            .expect("Start auth session returned a NONE handle");
+        let policy_session = PolicySession::try_from(policy_auth_session)
+            .expect("Failed to convert auth session into policy session");
+
+        let ticket = AuthTicket::try_from(TPMT_TK_AUTH {
+            tag: StructureTag::AuthSecret.into(),
+            hierarchy: TPM2_RH_OWNER,
+            digest: Digest::try_from(vec![0xab; 32]).unwrap().into(),
+        })
+        .expect("Failed to create AuthTicket");
+
+        // The ticket has not been produced by the TPM so it cannot be verified.
+        let _ = context
+            .execute_without_session(|ctx| {
+                ctx.policy_ticket(
+                    policy_session,
+                    Timeout::try_from(vec![0; 8]).unwrap(),
+                    Digest::default(),
+                    Nonce::default(),
+                    Name::try_from(vec![0x40, 0x00, 0x00, 0x01]).unwrap(),
+                    ticket,
+                )
+            })
+            .unwrap_err();
+    }
+}
+
 mod test_policy_or {
     use crate::common::{create_ctx_without_session, get_pcr_policy_digest};
     use std::convert::TryFrom;
@@ -340,6 +391,147 @@ mod test_policy_locality {
     }
 }
 
+mod test_policy_nv {
+    use crate::common::create_ctx_with_session;
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        attributes::NvIndexAttributesBuilder,
+        constants::{ArithmeticOperation, SessionType},
+        handles::NvIndexTpmHandle,
+        interface_types::{
+            algorithm::HashingAlgorithm,
+            resource_handles::{NvAuth, Provision},
+            session_handles::PolicySession,
+        },
+        structures::{MaxNvBuffer, NvPublicBuilder, Operand, SymmetricDefinition},
+    };
+    #[test]
+    fn test_policy_nv() {
+        let mut context = create_ctx_with_session();
+
+        let nv_index = NvIndexTpmHandle::new(0x01500501).unwrap();
+        let owner_nv_index_attributes = NvIndexAttributesBuilder::new()
+            .with_owner_write(true)
+            .with_owner_read(true)
+            .build()
+            .expect("Failed to create owner nv index attributes");
+        let owner_nv_public = NvPublicBuilder::new()
+            .with_nv_index(nv_index)
+            .with_index_name_algorithm(HashingAlgorithm::Sha256)
+            .with_index_attributes(owner_nv_index_attributes)
+            .with_data_area_size(8)
+            .build()
+            .expect("Failed to build NvPublic for owner");
+        let nv_index_handle = context
+            .nv_define_space(Provision::Owner, None, owner_nv_public)
+            .expect("Call to nv_define_space failed");
+
+        let policy_nv_results = context
+            .nv_write(
+                NvAuth::Owner,
+                nv_index_handle,
+                MaxNvBuffer::try_from(vec![0, 0, 0, 0, 0, 0, 0, 5]).unwrap(),
+                0,
+            )
+            .and_then(|_| {
+                let policy_auth_session = context
+                    .execute_without_session(|ctx| {
+                        ctx.start_auth_session(
+                            None,
+                            None,
+                            None,
+                            SessionType::Policy,
+                            SymmetricDefinition::AES_256_CFB,
+                            HashingAlgorithm::Sha256,
+                        )
+                    })?
+                    .expect("Start auth session returned a NONE handle");
+                let policy_session = PolicySession::try_from(policy_auth_session)?;
+
+                // The value in the index (5) is less than 10
+                let less_than = context.policy_nv(
+                    NvAuth::Owner,
+                    nv_index_handle,
+                    policy_session,
+                    Operand::try_from(vec![0, 0, 0, 0, 0, 0, 0, 10]).unwrap(),
+                    0,
+                    ArithmeticOperation::UnsignedLt,
+                );
+                // ... but it is not greater than 10
+                let greater_than = context.policy_nv(
+                    NvAuth::Owner,
+                    nv_index_handle,
+                    policy_session,
+                    Operand::try_from(vec![0, 0, 0, 0, 0, 0, 0, 10]).unwrap(),
+                    0,
+                    ArithmeticOperation::UnsignedGt,
+                );
+                Ok((less_than, greater_than))
+            });
+
+        context
+            .nv_undefine_space(Provision::Owner, nv_index_handle)
+            .expect("Call to nv_undefine_space failed");
+
+        let (less_than, greater_than) = policy_nv_results.expect("Failed to set up policy session");
+        less_than.expect("Call to policy_nv failed");
+        let _ = greater_than.unwrap_err();
+    }
+}
+
+mod test_policy_counter_timer {
+    use crate::common::create_ctx_without_session;
+    use sha2::{Digest as _, Sha256};
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        constants::{ArithmeticOperation, SessionType},
+        interface_types::{algorithm::HashingAlgorithm, session_handles::PolicySession},
+        structures::{Operand, SymmetricDefinition},
+    };
+    #[test]
+    fn test_policy_counter_timer() {
+        let mut context = create_ctx_without_session();
+        let trial_policy_auth_session = context
+            .start_auth_session(
+                None,
+                None,
+                None,
+                SessionType::Trial,
+                SymmetricDefinition::AES_256_CFB,
+                HashingAlgorithm::Sha256,
+            )
+            .expect("Start auth session failed")
+            .expect("Start auth session returned a NONE handle");
+        let trial_policy_session = PolicySession::try_from(trial_policy_auth_session)
+            .expect("Failed to convert auth session into policy session");
+
+        // The clock is at offset 8 of the TPMS_TIME_INFO structure.
+        let operand_b = vec![0, 0, 0, 0, 0, 0, 0x27, 0x10];
+        context
+            .policy_counter_timer(
+                trial_policy_session,
+                Operand::try_from(operand_b.clone()).unwrap(),
+                8,
+                ArithmeticOperation::UnsignedGe,
+            )
+            .expect("Call to policy_counter_timer failed");
+
+        let policy_digest = context
+            .policy_get_digest(trial_policy_session)
+            .expect("Failed to get policy digest for trial session");
+
+        let mut args = Sha256::new();
+        args.update(&operand_b);
+        args.update(8u16.to_be_bytes());
+        args.update(0x0007u16.to_be_bytes());
+        let mut expected = Sha256::new();
+        expected.update([0; 32]);
+        expected.update(0x0000016Du32.to_be_bytes());
+        expected.update(args.finalize());
+        assert_eq!(policy_digest.as_bytes(), expected.finalize().as_slice());
+    }
+}
+
 mod test_policy_command_code {
     use crate::common::create_ctx_without_session;
     use std::convert::TryFrom;
@@ -897,3 +1089,117 @@ mod test_policy_template {
         assert_eq!(expected_policy_template, policy_digest);
     }
 }
+
+mod test_policy_authorize_nv {
+    use crate::common::create_ctx_with_session;
+    use sha2::{Digest as _, Sha256};
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        attributes::NvIndexAttributesBuilder,
+        constants::{CommandCode, SessionType},
+        handles::NvIndexTpmHandle,
+        interface_types::{
+            algorithm::HashingAlgorithm,
+            resource_handles::{NvAuth, Provision},
+            session_handles::PolicySession,
+        },
+        structures::{MaxNvBuffer, NvPublicBuilder, SymmetricDefinition},
+        Context,
+    };
+    #[test]
+    fn test_policy_authorize_nv() {
+        let mut context = create_ctx_with_session();
+
+        // The policy stored in the index: PolicyCommandCode(TPM2_CC_Unseal)
+        let mut approved_policy = Sha256::new();
+        approved_policy.update([0; 32]);
+        approved_policy.update(0x0000015Eu32.to_be_bytes());
+        let approved_policy = approved_policy.finalize();
+
+        let nv_index = NvIndexTpmHandle::new(0x01500502).unwrap();
+        let owner_nv_index_attributes = NvIndexAttributesBuilder::new()
+            .with_owner_write(true)
+            .with_owner_read(true)
+            .build()
+            .expect("Failed to create owner nv index attributes");
+        let owner_nv_public = NvPublicBuilder::new()
+            .with_nv_index(nv_index)
+            .with_index_name_algorithm(HashingAlgorithm::Sha256)
+            .with_index_attributes(owner_nv_index_attributes)
+            .with_data_area_size(34)
+            .build()
+            .expect("Failed to build NvPublic for owner");
+        let nv_index_handle = context
+            .nv_define_space(Provision::Owner, None, owner_nv_public)
+            .expect("Call to nv_define_space failed");
+
+        // The index contains a marshalled TPMT_HA.
+        let nv_contents = [
+            &u16::from(HashingAlgorithm::Sha256).to_be_bytes()[..],
+            approved_policy.as_slice(),
+        ]
+        .concat();
+        let policy_authorize_nv_results = context
+            .nv_write(
+                NvAuth::Owner,
+                nv_index_handle,
+                MaxNvBuffer::try_from(nv_contents).unwrap(),
+                0,
+            )
+            .and_then(|_| {
+                let start_policy_session = |context: &mut Context| {
+                    context
+                        .execute_without_session(|ctx| {
+                            ctx.start_auth_session(
+                                None,
+                                None,
+                                None,
+                                SessionType::Policy,
+                                SymmetricDefinition::AES_256_CFB,
+                                HashingAlgorithm::Sha256,
+                            )
+                        })
+                        .map(|session| {
+                            PolicySession::try_from(
+                                session.expect("Start auth session returned a NONE handle"),
+                            )
+                            .expect("Failed to convert auth session into policy session")
+                        })
+                };
+
+                // The policy digest of the session matches the one in the index ...
+                let policy_session = start_policy_session(&mut context)?;
+                context.policy_command_code(policy_session, CommandCode::Unseal)?;
+                let authorized = context
+                    .policy_authorize_nv(NvAuth::Owner, nv_index_handle, policy_session)
+                    .and_then(|_| {
+                        context.execute_without_session(|ctx| ctx.policy_get_digest(policy_session))
+                    });
+
+                // ... but the digest of an empty policy does not.
+                let policy_session = start_policy_session(&mut context)?;
+                let not_authorized =
+                    context.policy_authorize_nv(NvAuth::Owner, nv_index_handle, policy_session);
+
+                // The name of the index changes when it is written.
+                let nv_name = context.tr_get_name(nv_index_handle.into())?;
+                Ok((authorized, not_authorized, nv_name))
+            });
+
+        context
+            .nv_undefine_space(Provision::Owner, nv_index_handle)
+            .expect("Call to nv_undefine_space failed");
+
+        let (authorized, not_authorized, nv_name) =
+            policy_authorize_nv_results.expect("Failed to set up policy sessions");
+        let policy_digest = authorized.expect("Call to policy_authorize_nv failed");
+        let _ = not_authorized.unwrap_err();
+
+        // policyDigest := H(0...0 || TPM_CC_PolicyAuthorizeNV || nvIndexName)
+        let mut expected = Sha256::new();
+        expected.update([0; 32]);
+        expected.update(0x00000192u32.to_be_bytes());
+        expected.update(nv_name.value());
+        assert_eq!(policy_digest.as_bytes(), expected.finalize().as_slice());
+    }
+}
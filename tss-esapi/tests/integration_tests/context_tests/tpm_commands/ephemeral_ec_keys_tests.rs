@@ -1,2 +1,103 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
+mod test_commit {
+    use crate::common::create_ctx_with_session;
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        attributes::ObjectAttributesBuilder,
+        interface_types::{
+            algorithm::{HashingAlgorithm, PublicAlgorithm},
+            ecc::EccCurve,
+            resource_handles::Hierarchy,
+        },
+        structures::{
+            EcDaaScheme, EccPoint, EccScheme, KeyDerivationFunctionScheme, PublicBuilder,
+            PublicEccParametersBuilder, SensitiveData,
+        },
+    };
+
+    #[test]
+    fn test_commit() {
+        let mut context = create_ctx_with_session();
+
+        let ecc_parms = PublicEccParametersBuilder::new()
+            .with_ecc_scheme(EccScheme::EcDaa(EcDaaScheme::new(
+                HashingAlgorithm::Sha256,
+                0,
+            )))
+            .with_curve(EccCurve::BnP256)
+            .with_is_signing_key(true)
+            .with_is_decryption_key(false)
+            .with_restricted(false)
+            .with_key_derivation_function_scheme(KeyDerivationFunctionScheme::Null)
+            .build()
+            .unwrap();
+
+        let object_attributes = ObjectAttributesBuilder::new()
+            .with_fixed_tpm(true)
+            .with_fixed_parent(true)
+            .with_sensitive_data_origin(true)
+            .with_user_with_auth(true)
+            .with_sign_encrypt(true)
+            .build()
+            .unwrap();
+
+        let public = PublicBuilder::new()
+            .with_public_algorithm(PublicAlgorithm::Ecc)
+            .with_name_hashing_algorithm(HashingAlgorithm::Sha256)
+            .with_object_attributes(object_attributes)
+            .with_ecc_parameters(ecc_parms)
+            .with_ecc_unique_identifier(EccPoint::default())
+            .build()
+            .unwrap();
+
+        let key_handle = context
+            .create_primary(Hierarchy::Owner, public, None, None, None, None)
+            .unwrap()
+            .key_handle;
+
+        // K and L are only computed if s2 is provided
+        let (k, l, _, first_counter) = context
+            .commit(key_handle, None, None, None)
+            .expect("Call to commit failed");
+        assert!(k.x().is_empty());
+        assert!(l.x().is_empty());
+
+        let (_, _, _, second_counter) = context
+            .commit(key_handle, None, None, None)
+            .expect("Call to commit failed");
+        assert_ne!(first_counter, second_counter);
+
+        // Providing s2 without y2 is not allowed
+        let _ = context
+            .commit(
+                key_handle,
+                None,
+                Some(SensitiveData::try_from(vec![0x01; 32]).unwrap()),
+                None,
+            )
+            .unwrap_err();
+    }
+}
+
+mod test_ec_ephemeral {
+    use crate::common::create_ctx_without_session;
+    use tss_esapi::interface_types::ecc::EccCurve;
+
+    #[test]
+    fn test_ec_ephemeral() {
+        let mut context = create_ctx_without_session();
+
+        let (first_q, first_counter) = context
+            .ec_ephemeral(EccCurve::NistP256)
+            .expect("Call to ec_ephemeral failed");
+        let (second_q, second_counter) = context
+            .ec_ephemeral(EccCurve::NistP256)
+            .expect("Call to ec_ephemeral failed");
+
+        assert_eq!(first_q.x().len(), 32);
+        assert_eq!(first_q.y().len(), 32);
+        assert_ne!(first_q, second_q);
+        assert_ne!(first_counter, second_counter);
+    }
+}
@@ -1,2 +1,36 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
+mod test_field_upgrade_data {
+    use crate::common::create_ctx_without_session;
+    use std::convert::TryFrom;
+    use tss_esapi::structures::MaxBuffer;
+
+    #[test]
+    fn test_field_upgrade_data_without_start() {
+        let mut context = create_ctx_without_session();
+        let _ = context
+            .field_upgrade_data(MaxBuffer::try_from(vec![0xff; 32]).unwrap())
+            .expect_err("Sending field upgrade data without starting a field upgrade did not fail");
+    }
+}
+
+mod test_field_upgrade_start {
+    use crate::common::{create_ctx_with_session, untrusted_manifest};
+    use tss_esapi::interface_types::resource_handles::Platform;
+
+    #[test]
+    fn test_field_upgrade_start_with_invalid_manifest() {
+        let mut context = create_ctx_with_session();
+        let (key_handle, fu_digest, manifest_signature) = untrusted_manifest(&mut context);
+
+        // The key is not trusted by the TPM as a firmware signing key.
+        let _ = context
+            .field_upgrade_start(
+                Platform::Platform,
+                key_handle,
+                fu_digest,
+                manifest_signature,
+            )
+            .expect_err("Starting a field upgrade with an untrusted manifest did not fail");
+    }
+}
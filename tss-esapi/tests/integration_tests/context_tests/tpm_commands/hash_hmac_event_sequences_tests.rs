@@ -1,2 +1,210 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
+mod test_hash_sequence {
+    use crate::common::create_ctx_without_session;
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        interface_types::{
+            algorithm::HashingAlgorithm, resource_handles::Hierarchy, session_handles::AuthSession,
+        },
+        structures::{MaxBuffer, Ticket},
+    };
+
+    #[test]
+    fn test_hash_sequence_matches_hash() {
+        let mut context = create_ctx_without_session();
+        let first_part = MaxBuffer::try_from(vec![0xa5; 512]).unwrap();
+        let second_part = MaxBuffer::try_from(vec![0x5a; 512]).unwrap();
+        let all_data =
+            MaxBuffer::try_from([first_part.as_bytes(), second_part.as_bytes()].concat()).unwrap();
+
+        let (expected_digest, _) = context
+            .hash(all_data, HashingAlgorithm::Sha256, Hierarchy::Owner)
+            .expect("Call to hash failed");
+
+        let sequence_handle = context
+            .hash_sequence_start(HashingAlgorithm::Sha256, None)
+            .expect("Call to hash_sequence_start failed");
+        let (actual_digest, ticket) =
+            context.execute_with_session(Some(AuthSession::Password), |ctx| {
+                ctx.sequence_update(sequence_handle, first_part)
+                    .expect("Call to sequence_update failed");
+                ctx.sequence_complete(sequence_handle, second_part, Hierarchy::Owner)
+                    .expect("Call to sequence_complete failed")
+            });
+
+        assert_eq!(expected_digest, actual_digest);
+        assert_eq!(Hierarchy::Owner, ticket.hierarchy());
+    }
+
+    #[test]
+    fn test_sequence_update_without_session() {
+        let mut context = create_ctx_without_session();
+        let sequence_handle = context
+            .hash_sequence_start(HashingAlgorithm::Sha256, None)
+            .expect("Call to hash_sequence_start failed");
+        let _ = context
+            .sequence_update(sequence_handle, MaxBuffer::default())
+            .unwrap_err();
+        context
+            .flush_context(sequence_handle)
+            .expect("Call to flush_context failed");
+    }
+}
+
+mod test_hmac_sequence {
+    use crate::common::create_ctx_with_session;
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        attributes::ObjectAttributesBuilder,
+        interface_types::{
+            algorithm::{HashingAlgorithm, PublicAlgorithm},
+            resource_handles::Hierarchy,
+        },
+        structures::{
+            Digest, KeyedHashScheme, MaxBuffer, PublicBuilder, PublicKeyedHashParameters, Ticket,
+        },
+    };
+
+    #[test]
+    fn test_hmac_sequence_matches_hmac() {
+        let mut context = create_ctx_with_session();
+        let object_attributes = ObjectAttributesBuilder::new()
+            .with_sign_encrypt(true)
+            .with_sensitive_data_origin(true)
+            .with_user_with_auth(true)
+            .build()
+            .expect("Failed to build object attributes");
+        let key_pub = PublicBuilder::new()
+            .with_public_algorithm(PublicAlgorithm::KeyedHash)
+            .with_name_hashing_algorithm(HashingAlgorithm::Sha256)
+            .with_object_attributes(object_attributes)
+            .with_keyed_hash_parameters(PublicKeyedHashParameters::new(
+                KeyedHashScheme::HMAC_SHA_256,
+            ))
+            .with_keyed_hash_unique_identifier(Digest::default())
+            .build()
+            .expect("Failed to build public for HMAC key");
+        let key_handle = context
+            .create_primary(Hierarchy::Owner, key_pub, None, None, None, None)
+            .expect("Call to create_primary failed")
+            .key_handle;
+
+        let data = MaxBuffer::try_from("There is no spoon".as_bytes().to_vec()).unwrap();
+        let expected_hmac = context
+            .hmac(key_handle.into(), data.clone(), HashingAlgorithm::Sha256)
+            .expect("Call to hmac failed");
+
+        let sequence_handle = context
+            .hmac_start(key_handle.into(), None, HashingAlgorithm::Sha256)
+            .expect("Call to hmac_start failed");
+        context
+            .sequence_update(sequence_handle, data)
+            .expect("Call to sequence_update failed");
+        let (actual_hmac, ticket) = context
+            .sequence_complete(sequence_handle, MaxBuffer::default(), Hierarchy::Null)
+            .expect("Call to sequence_complete failed");
+
+        assert_eq!(expected_hmac, actual_hmac);
+        assert_eq!(Hierarchy::Null, ticket.hierarchy());
+        assert!(ticket.digest().is_empty());
+    }
+}
+
+#[cfg(has_esys_mac)]
+mod test_mac_sequence {
+    use crate::common::create_ctx_with_session;
+    use std::convert::{TryFrom, TryInto};
+    use tss_esapi::{
+        abstraction::cipher::Cipher,
+        attributes::ObjectAttributesBuilder,
+        interface_types::{
+            algorithm::{HashingAlgorithm, MacSchemeAlgorithm, PublicAlgorithm},
+            resource_handles::Hierarchy,
+        },
+        structures::{MaxBuffer, PublicBuilder, SymmetricCipherParameters},
+    };
+
+    #[test]
+    fn test_cmac_sequence() {
+        let mut context = create_ctx_with_session();
+        let object_attributes = ObjectAttributesBuilder::new()
+            .with_sign_encrypt(true)
+            .with_sensitive_data_origin(true)
+            .with_user_with_auth(true)
+            .build()
+            .expect("Failed to build object attributes");
+        let key_pub = PublicBuilder::new()
+            .with_public_algorithm(PublicAlgorithm::SymCipher)
+            .with_name_hashing_algorithm(HashingAlgorithm::Sha256)
+            .with_object_attributes(object_attributes)
+            .with_symmetric_cipher_parameters(SymmetricCipherParameters::new(
+                Cipher::aes_128_cfb().try_into().unwrap(),
+            ))
+            .with_symmetric_cipher_unique_identifier(Default::default())
+            .build()
+            .expect("Failed to build public for CMAC key");
+        let key_handle = context
+            .create_primary(Hierarchy::Owner, key_pub, None, None, None, None)
+            .expect("Call to create_primary failed")
+            .key_handle;
+
+        let data = MaxBuffer::try_from("There is no spoon".as_bytes().to_vec()).unwrap();
+        let expected_cmac = context
+            .mac(key_handle.into(), data.clone(), MacSchemeAlgorithm::Cmac)
+            .expect("Call to mac failed");
+
+        let sequence_handle = context
+            .mac_start(key_handle.into(), None, MacSchemeAlgorithm::Cmac)
+            .expect("Call to mac_start failed");
+        context
+            .sequence_update(sequence_handle, data)
+            .expect("Call to sequence_update failed");
+        let (actual_cmac, _) = context
+            .sequence_complete(sequence_handle, MaxBuffer::default(), Hierarchy::Null)
+            .expect("Call to sequence_complete failed");
+
+        assert_eq!(expected_cmac, actual_cmac);
+    }
+}
+
+mod test_event_sequence_complete {
+    use crate::common::create_ctx_without_session;
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        handles::PcrHandle,
+        interface_types::{algorithm::HashingAlgorithm, session_handles::AuthSession},
+        structures::MaxBuffer,
+    };
+
+    #[test]
+    fn test_event_sequence_complete() {
+        let mut context = create_ctx_without_session();
+        let data = MaxBuffer::try_from("There is no spoon".as_bytes().to_vec()).unwrap();
+        let expected_sha256_digest: [u8; 32] = [
+            0x6b, 0x38, 0x4d, 0x2b, 0xfb, 0x0e, 0x0d, 0xfb, 0x64, 0x89, 0xdb, 0xf4, 0xf8, 0xe9,
+            0xe5, 0x2f, 0x71, 0xee, 0xb1, 0x0d, 0x06, 0x4c, 0x56, 0x59, 0x70, 0xcd, 0xd9, 0x44,
+            0x43, 0x18, 0x5d, 0xc1,
+        ];
+
+        let sequence_handle = context
+            .hash_sequence_start(HashingAlgorithm::Null, None)
+            .expect("Call to hash_sequence_start failed");
+        let digest_values = context.execute_with_sessions(
+            (
+                Some(AuthSession::Password),
+                Some(AuthSession::Password),
+                None,
+            ),
+            |ctx| {
+                ctx.event_sequence_complete(Some(PcrHandle::Pcr16), sequence_handle, data)
+                    .expect("Call to event_sequence_complete failed")
+            },
+        );
+
+        let actual_sha256_digest = digest_values
+            .get(HashingAlgorithm::Sha256)
+            .expect("No SHA256 digest was returned");
+        assert_eq!(&expected_sha256_digest[..], actual_sha256_digest.as_bytes());
+    }
+}
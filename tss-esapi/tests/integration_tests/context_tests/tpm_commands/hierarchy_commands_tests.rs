@@ -123,3 +123,130 @@ mod test_change_auth {
             .unwrap();
     }
 }
+
+mod test_hierarchy_control {
+    use crate::common::create_ctx_with_session;
+    use tss_esapi::interface_types::resource_handles::{Enables, Hierarchy};
+
+    #[test]
+    fn test_hierarchy_control() {
+        let mut context = create_ctx_with_session();
+
+        // NOTE: The platform NV storage is used in this test because it can be
+        //  enabled again with the platform authorization, disabling the platform
+        //  hierarchy itself would require a TPM restart.
+        context
+            .hierarchy_control(Hierarchy::Platform, Enables::PlatformNv, false)
+            .unwrap();
+        context
+            .hierarchy_control(Hierarchy::Platform, Enables::PlatformNv, true)
+            .unwrap();
+    }
+
+    #[test]
+    fn test_hierarchy_control_enable_without_platform_auth() {
+        let mut context = create_ctx_with_session();
+
+        // Only the platform authorization can be used to enable a hierarchy.
+        let _ = context
+            .hierarchy_control(Hierarchy::Owner, Enables::Owner, true)
+            .unwrap_err();
+    }
+}
+
+mod test_set_primary_policy {
+    use crate::common::create_ctx_with_session;
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        interface_types::{algorithm::HashingAlgorithm, resource_handles::HierarchyAuth},
+        structures::Digest,
+    };
+
+    #[test]
+    fn test_set_primary_policy() {
+        let mut context = create_ctx_with_session();
+
+        context
+            .set_primary_policy(
+                HierarchyAuth::Platform,
+                Digest::try_from(vec![0xab; 32]).unwrap(),
+                HashingAlgorithm::Sha256,
+            )
+            .unwrap();
+        context
+            .set_primary_policy(
+                HierarchyAuth::Platform,
+                Digest::default(),
+                HashingAlgorithm::Null,
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn test_set_primary_policy_wrong_digest_size() {
+        let mut context = create_ctx_with_session();
+
+        // The size of the digest has to match the hashing algorithm.
+        let _ = context
+            .set_primary_policy(
+                HierarchyAuth::Platform,
+                Digest::try_from(vec![0xab; 20]).unwrap(),
+                HashingAlgorithm::Sha256,
+            )
+            .unwrap_err();
+    }
+}
+
+mod test_change_seeds {
+    //! Changing a primary seed cannot be undone: it invalidates all the
+    //! objects of the hierarchy, evicts its persistent objects and, for the
+    //! endorsement hierarchy, resets its authorization values.
+    //!
+    //! To keep the side effects isolated, each test uses its own context,
+    //! only relies on the primary objects it creates itself and flushes
+    //! them before returning. No other test depends on the value of the
+    //! platform or endorsement primary seeds.
+    use crate::common::{create_ctx_with_session, decryption_key_pub};
+    use tss_esapi::{
+        interface_types::resource_handles::{Hierarchy, Platform},
+        structures::Public,
+        Context,
+    };
+
+    /// Creates a primary key in `hierarchy`, flushes it and returns its public area.
+    fn primary_public(context: &mut Context, hierarchy: Hierarchy) -> Public {
+        let primary = context
+            .create_primary(hierarchy, decryption_key_pub(), None, None, None, None)
+            .expect("Call to create_primary failed");
+        context
+            .flush_context(primary.key_handle.into())
+            .expect("Call to flush_context failed");
+        primary.out_public
+    }
+
+    #[test]
+    fn test_change_pps() {
+        let mut context = create_ctx_with_session();
+
+        let public_before = primary_public(&mut context, Hierarchy::Platform);
+        context
+            .change_pps(Platform::Platform)
+            .expect("Call to change_pps failed");
+        let public_after = primary_public(&mut context, Hierarchy::Platform);
+
+        assert_ne!(public_before, public_after);
+    }
+
+    #[test]
+    fn test_change_eps() {
+        let mut context = create_ctx_with_session();
+
+        let public_before = primary_public(&mut context, Hierarchy::Endorsement);
+        context
+            .change_eps(Platform::Platform)
+            .expect("Call to change_eps failed");
+        let public_after = primary_public(&mut context, Hierarchy::Endorsement);
+
+        assert_ne!(public_before, public_after);
+    }
+}
@@ -146,6 +146,145 @@ mod test_pcr_extend_reset {
     }
 }
 
+mod test_pcr_event {
+    use crate::common::create_ctx_with_session;
+    use sha2::{Digest as _, Sha256};
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        handles::PcrHandle,
+        interface_types::algorithm::HashingAlgorithm,
+        structures::{Event, PcrSelectionListBuilder, PcrSlot},
+    };
+
+    #[test]
+    fn test_pcr_event() {
+        let mut context = create_ctx_with_session();
+        let event_data = b"measured event".to_vec();
+
+        context.pcr_reset(PcrHandle::Pcr16).unwrap();
+        let digests = context
+            .pcr_event(
+                PcrHandle::Pcr16,
+                Event::try_from(event_data.clone()).unwrap(),
+            )
+            .expect("Failed to call pcr_event");
+
+        let event_digest = Sha256::digest(&event_data);
+        assert_eq!(
+            digests
+                .get(HashingAlgorithm::Sha256)
+                .expect("No SHA-256 digest returned")
+                .as_bytes(),
+            event_digest.as_slice()
+        );
+
+        let pcr_selection_list = PcrSelectionListBuilder::new()
+            .with_selection(HashingAlgorithm::Sha256, &[PcrSlot::Slot16])
+            .build()
+            .expect("Failed to create PcrSelectionList");
+        let (_, _, read_pcr_digests) = context
+            .execute_without_session(|ctx| ctx.pcr_read(pcr_selection_list))
+            .expect("Failed to call pcr_read");
+
+        let mut hasher = Sha256::new();
+        hasher.update([0; 32]);
+        hasher.update(event_digest);
+        assert_eq!(
+            read_pcr_digests.value()[0].as_bytes(),
+            hasher.finalize().as_slice()
+        );
+    }
+}
+
+mod test_pcr_allocate {
+    use crate::common::create_ctx_with_session;
+    use tss_esapi::{
+        constants::CapabilityType, interface_types::resource_handles::Platform,
+        structures::CapabilityData,
+    };
+
+    #[test]
+    fn test_pcr_allocate_current_allocation() {
+        let mut context = create_ctx_with_session();
+        let (capability_data, _) = context
+            .get_capability(CapabilityType::AssignedPcr, 0, 1)
+            .expect("Failed to get the PCR allocation");
+        let current_allocation = match capability_data {
+            CapabilityData::AssignedPcr(pcr_selection_list) => pcr_selection_list,
+            _ => panic!("Unexpected capability data"),
+        };
+
+        let allocation_result = context
+            .pcr_allocate(Platform::Platform, current_allocation)
+            .expect("Failed to call pcr_allocate");
+        assert!(allocation_result.allocation_success);
+        assert!(allocation_result.max_pcr > 0);
+        assert!(allocation_result.size_needed <= allocation_result.size_available);
+    }
+}
+
+mod test_pcr_set_auth {
+    use crate::common::create_ctx_with_session;
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        handles::PcrHandle,
+        interface_types::{algorithm::HashingAlgorithm, resource_handles::Platform},
+        structures::{Auth, Digest},
+    };
+
+    #[test]
+    fn test_pcr_set_auth_value() {
+        // PCR 20 to 22 are in the authorization value group of the reference implementation.
+        let mut context = create_ctx_with_session();
+        let auth = Auth::try_from(vec![0x01, 0x02, 0x03, 0x04]).unwrap();
+
+        context
+            .pcr_set_auth_value(PcrHandle::Pcr20, auth.clone())
+            .expect("Failed to set PCR auth value");
+        context
+            .tr_set_auth(PcrHandle::Pcr20.into(), auth)
+            .expect("Failed to set auth on the PCR handle");
+        context
+            .pcr_set_auth_value(PcrHandle::Pcr20, Auth::default())
+            .expect("Failed to clear PCR auth value");
+        context
+            .tr_set_auth(PcrHandle::Pcr20.into(), Auth::default())
+            .expect("Failed to clear auth on the PCR handle");
+    }
+
+    #[test]
+    fn test_pcr_set_auth_policy() {
+        let mut context = create_ctx_with_session();
+
+        context
+            .pcr_set_auth_policy(
+                Platform::Platform,
+                Digest::try_from(vec![0xaa; 32]).unwrap(),
+                HashingAlgorithm::Sha256,
+                PcrHandle::Pcr20,
+            )
+            .expect("Failed to set PCR auth policy");
+        context
+            .pcr_set_auth_policy(
+                Platform::Platform,
+                Digest::default(),
+                HashingAlgorithm::Null,
+                PcrHandle::Pcr20,
+            )
+            .expect("Failed to clear PCR auth policy");
+
+        // PCR 16 is not in a policy group.
+        let _ = context
+            .pcr_set_auth_policy(
+                Platform::Platform,
+                Digest::try_from(vec![0xaa; 32]).unwrap(),
+                HashingAlgorithm::Sha256,
+                PcrHandle::Pcr16,
+            )
+            .unwrap_err();
+    }
+}
+
 mod test_pcr_read {
     use crate::common::create_ctx_without_session;
     use tss_esapi::{
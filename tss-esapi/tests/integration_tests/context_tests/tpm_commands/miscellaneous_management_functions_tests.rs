@@ -1,2 +1,107 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
+mod test_pp_commands {
+    use crate::common::create_ctx_without_session;
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        constants::{return_code::TpmFormatOneError, CapabilityType, CommandCode},
+        error::TpmResponseCode,
+        interface_types::{resource_handles::Platform, session_handles::AuthSession},
+        structures::{CapabilityData, CommandCodeList},
+        tss2_esys::TPM2_CC,
+        Context, Error, ReturnCode,
+    };
+
+    fn requires_physical_presence(context: &mut Context, command_code: CommandCode) -> bool {
+        let (capability_data, _) = context
+            .get_capability(CapabilityType::PpCommands, TPM2_CC::from(command_code), 1)
+            .expect("Failed to get the PP commands capability");
+        match capability_data {
+            CapabilityData::PpCommands(command_code_list) => {
+                command_code_list.contains(&command_code)
+            }
+            _ => panic!("Got wrong type of capability data: {:?}", capability_data),
+        }
+    }
+
+    #[test]
+    fn test_pp_commands() {
+        let mut context = create_ctx_without_session();
+        let initially_required = requires_physical_presence(&mut context, CommandCode::Clear);
+        let toggle_list = CommandCodeList::try_from(vec![CommandCode::Clear]).unwrap();
+        let (set_list, clear_list) = if initially_required {
+            (CommandCodeList::new(), toggle_list)
+        } else {
+            (toggle_list, CommandCodeList::new())
+        };
+
+        match context.execute_with_session(Some(AuthSession::Password), |ctx| {
+            ctx.pp_commands(Platform::Platform, set_list.clone(), clear_list.clone())
+        }) {
+            Ok(()) => {
+                assert_ne!(
+                    initially_required,
+                    requires_physical_presence(&mut context, CommandCode::Clear)
+                );
+                // Restore the initial configuration
+                context
+                    .execute_with_session(Some(AuthSession::Password), |ctx| {
+                        ctx.pp_commands(Platform::Platform, clear_list, set_list)
+                    })
+                    .expect("Failed to restore the PP commands");
+                assert_eq!(
+                    initially_required,
+                    requires_physical_presence(&mut context, CommandCode::Clear)
+                );
+            }
+            // The command itself requires physical presence, which
+            // is not necessarily asserted on the simulator.
+            Err(Error::TssError(ReturnCode::Tpm(TpmResponseCode::FormatOne(error)))) => {
+                assert_eq!(error.error_number(), TpmFormatOneError::Pp)
+            }
+            Err(e) => panic!("Error was unexpected: {:?}", e),
+        }
+    }
+}
+
+mod test_set_algorithm_set {
+    use crate::common::create_ctx_without_session;
+    use tss_esapi::{
+        constants::{
+            return_code::TpmFormatZeroError, tss::TPM2_CC_SetAlgorithmSet, CapabilityType,
+        },
+        error::{TpmFormatZeroResponseCode, TpmResponseCode},
+        interface_types::{resource_handles::Platform, session_handles::AuthSession},
+        structures::CapabilityData,
+        Error, ReturnCode,
+    };
+
+    #[test]
+    fn test_set_algorithm_set() {
+        let mut context = create_ctx_without_session();
+        let (capability_data, _) = context
+            .get_capability(CapabilityType::Command, TPM2_CC_SetAlgorithmSet, 1)
+            .expect("Failed to get the command capability");
+        let is_supported = match capability_data {
+            CapabilityData::Commands(command_code_attributes_list) => command_code_attributes_list
+                .find(TPM2_CC_SetAlgorithmSet as u16)
+                .is_some(),
+            _ => panic!("Got wrong type of capability data: {:?}", capability_data),
+        };
+
+        // The reference implementation uses 0 as the default algorithm set.
+        let result = context.execute_with_session(Some(AuthSession::Password), |ctx| {
+            ctx.set_algorithm_set(Platform::Platform, 0)
+        });
+        if is_supported {
+            result.expect("Call to set_algorithm_set failed");
+        } else {
+            match result {
+                Err(Error::TssError(ReturnCode::Tpm(TpmResponseCode::FormatZero(
+                    TpmFormatZeroResponseCode::Error(error),
+                )))) => assert_eq!(error.error_number(), TpmFormatZeroError::CommandCode),
+                _ => panic!("Unexpected result for an unsupported command: {:?}", result),
+            }
+        }
+    }
+}
@@ -421,3 +421,622 @@ mod test_nv_increment {
         assert_eq!(first_value + 1, second_value);
     }
 }
+
+mod test_nv_extend {
+    use crate::common::create_ctx_with_session;
+    use sha2::{Digest, Sha256};
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        attributes::NvIndexAttributesBuilder,
+        constants::nv_index_type::NvIndexType,
+        handles::NvIndexTpmHandle,
+        interface_types::{
+            algorithm::HashingAlgorithm,
+            resource_handles::{NvAuth, Provision},
+        },
+        structures::{MaxNvBuffer, NvPublicBuilder},
+    };
+
+    #[test]
+    fn test_nv_extend() {
+        let mut context = create_ctx_with_session();
+        let nv_index = NvIndexTpmHandle::new(0x01500032).unwrap();
+
+        // Create owner nv public.
+        let owner_nv_index_attributes = NvIndexAttributesBuilder::new()
+            .with_owner_write(true)
+            .with_owner_read(true)
+            .with_nv_index_type(NvIndexType::Extend)
+            .build()
+            .expect("Failed to create owner nv index attributes");
+
+        let owner_nv_public = NvPublicBuilder::new()
+            .with_nv_index(nv_index)
+            .with_index_name_algorithm(HashingAlgorithm::Sha256)
+            .with_index_attributes(owner_nv_index_attributes)
+            .with_data_area_size(32)
+            .build()
+            .expect("Failed to build NvPublic for owner");
+
+        let data = MaxNvBuffer::try_from(vec![1, 2, 3, 4, 5, 6, 7])
+            .expect("Failed to create MaxNvBuffer from vec");
+
+        let owner_nv_index_handle = context
+            .nv_define_space(Provision::Owner, None, owner_nv_public)
+            .expect("Call to nv_define_space failed");
+
+        // Extend the data using owner authorization
+        let nv_extend_result =
+            context.nv_extend(NvAuth::Owner, owner_nv_index_handle, data.clone());
+
+        // Read the extended value using owner authorization
+        let nv_read_result = context.nv_read(NvAuth::Owner, owner_nv_index_handle, 32, 0);
+
+        context
+            .nv_undefine_space(Provision::Owner, owner_nv_index_handle)
+            .expect("Call to nv_undefine_space failed");
+
+        // Process results
+        nv_extend_result.expect("Call to nv_extend failed");
+        let actual_value = nv_read_result.expect("Call to nv_read failed");
+
+        // The initial value of an extend index is all zeros.
+        let mut hasher = Sha256::new();
+        hasher.update([0u8; 32]);
+        hasher.update(data.as_slice());
+        let expected_value = hasher.finalize();
+
+        // Check result.
+        assert_eq!(expected_value.as_slice(), actual_value.as_slice());
+    }
+}
+
+mod test_nv_set_bits {
+    use crate::common::create_ctx_with_session;
+    use std::convert::TryInto;
+    use tss_esapi::{
+        attributes::NvIndexAttributesBuilder,
+        constants::nv_index_type::NvIndexType,
+        handles::NvIndexTpmHandle,
+        interface_types::{
+            algorithm::HashingAlgorithm,
+            resource_handles::{NvAuth, Provision},
+        },
+        structures::NvPublicBuilder,
+    };
+
+    #[test]
+    fn test_nv_set_bits() {
+        let mut context = create_ctx_with_session();
+        let nv_index = NvIndexTpmHandle::new(0x01500033).unwrap();
+
+        // Create owner nv public.
+        let owner_nv_index_attributes = NvIndexAttributesBuilder::new()
+            .with_owner_write(true)
+            .with_owner_read(true)
+            .with_nv_index_type(NvIndexType::Bits)
+            .build()
+            .expect("Failed to create owner nv index attributes");
+
+        let owner_nv_public = NvPublicBuilder::new()
+            .with_nv_index(nv_index)
+            .with_index_name_algorithm(HashingAlgorithm::Sha256)
+            .with_index_attributes(owner_nv_index_attributes)
+            .with_data_area_size(8)
+            .build()
+            .expect("Failed to build NvPublic for owner");
+
+        let owner_nv_index_handle = context
+            .nv_define_space(Provision::Owner, None, owner_nv_public)
+            .expect("Call to nv_define_space failed");
+
+        // Set bits using owner authorization
+        let first_nv_set_bits_result =
+            context.nv_set_bits(NvAuth::Owner, owner_nv_index_handle, 0b0011);
+        let second_nv_set_bits_result =
+            context.nv_set_bits(NvAuth::Owner, owner_nv_index_handle, 0b1000);
+
+        // Read the bit field using owner authorization
+        let nv_read_result = context.nv_read(NvAuth::Owner, owner_nv_index_handle, 8, 0);
+
+        context
+            .nv_undefine_space(Provision::Owner, owner_nv_index_handle)
+            .expect("Call to nv_undefine_space failed");
+
+        // Process results
+        first_nv_set_bits_result.expect("First call to nv_set_bits failed");
+        second_nv_set_bits_result.expect("Second call to nv_set_bits failed");
+        let nv_read_value = nv_read_result.expect("Call to nv_read failed");
+
+        // The bit field is an 8 byte unsigned big-endian value.
+        let actual_value = u64::from_be_bytes(
+            nv_read_value
+                .to_vec()
+                .try_into()
+                .expect("Failed to convert nv_read_value as a vector into an 8 byte array"),
+        );
+
+        // Check result.
+        assert_eq!(0b1011, actual_value);
+    }
+}
+
+mod test_nv_write_lock {
+    use crate::common::create_ctx_with_session;
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        attributes::NvIndexAttributesBuilder,
+        handles::NvIndexTpmHandle,
+        interface_types::{
+            algorithm::HashingAlgorithm,
+            resource_handles::{NvAuth, Provision},
+        },
+        structures::{MaxNvBuffer, NvPublicBuilder},
+    };
+
+    #[test]
+    fn test_nv_write_lock() {
+        let mut context = create_ctx_with_session();
+        let nv_index = NvIndexTpmHandle::new(0x01500034).unwrap();
+
+        // Create owner nv public.
+        let owner_nv_index_attributes = NvIndexAttributesBuilder::new()
+            .with_owner_write(true)
+            .with_owner_read(true)
+            .with_write_stclear(true)
+            .build()
+            .expect("Failed to create owner nv index attributes");
+
+        let owner_nv_public = NvPublicBuilder::new()
+            .with_nv_index(nv_index)
+            .with_index_name_algorithm(HashingAlgorithm::Sha256)
+            .with_index_attributes(owner_nv_index_attributes)
+            .with_data_area_size(32)
+            .build()
+            .expect("Failed to build NvPublic for owner");
+
+        let data = MaxNvBuffer::try_from(vec![1, 2, 3, 4, 5, 6, 7])
+            .expect("Failed to create MaxNvBuffer from vec");
+
+        let owner_nv_index_handle = context
+            .nv_define_space(Provision::Owner, None, owner_nv_public)
+            .expect("Call to nv_define_space failed");
+
+        // Write the data before and after locking the index.
+        let first_nv_write_result =
+            context.nv_write(NvAuth::Owner, owner_nv_index_handle, data.clone(), 0);
+        let nv_write_lock_result = context.nv_write_lock(NvAuth::Owner, owner_nv_index_handle);
+        let second_nv_write_result =
+            context.nv_write(NvAuth::Owner, owner_nv_index_handle, data, 0);
+
+        context
+            .nv_undefine_space(Provision::Owner, owner_nv_index_handle)
+            .expect("Call to nv_undefine_space failed");
+
+        // Process results
+        first_nv_write_result.expect("First call to nv_write failed");
+        nv_write_lock_result.expect("Call to nv_write_lock failed");
+        let _ = second_nv_write_result.expect_err("Writing to a write locked index succeeded");
+    }
+}
+
+mod test_nv_global_write_lock {
+    use crate::common::create_ctx_with_session;
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        attributes::NvIndexAttributesBuilder,
+        handles::NvIndexTpmHandle,
+        interface_types::{
+            algorithm::HashingAlgorithm,
+            resource_handles::{NvAuth, Provision},
+        },
+        structures::{MaxNvBuffer, NvPublicBuilder},
+    };
+
+    #[test]
+    fn test_nv_global_write_lock() {
+        let mut context = create_ctx_with_session();
+        let nv_index = NvIndexTpmHandle::new(0x01500035).unwrap();
+
+        // Create owner nv public.
+        let owner_nv_index_attributes = NvIndexAttributesBuilder::new()
+            .with_owner_write(true)
+            .with_owner_read(true)
+            .with_global_lock(true)
+            .build()
+            .expect("Failed to create owner nv index attributes");
+
+        let owner_nv_public = NvPublicBuilder::new()
+            .with_nv_index(nv_index)
+            .with_index_name_algorithm(HashingAlgorithm::Sha256)
+            .with_index_attributes(owner_nv_index_attributes)
+            .with_data_area_size(32)
+            .build()
+            .expect("Failed to build NvPublic for owner");
+
+        let data = MaxNvBuffer::try_from(vec![1, 2, 3, 4, 5, 6, 7])
+            .expect("Failed to create MaxNvBuffer from vec");
+
+        let owner_nv_index_handle = context
+            .nv_define_space(Provision::Owner, None, owner_nv_public)
+            .expect("Call to nv_define_space failed");
+
+        // Lock all indices with the global lock attribute set.
+        let nv_global_write_lock_result = context.nv_global_write_lock(Provision::Owner);
+        let nv_write_result = context.nv_write(NvAuth::Owner, owner_nv_index_handle, data, 0);
+
+        context
+            .nv_undefine_space(Provision::Owner, owner_nv_index_handle)
+            .expect("Call to nv_undefine_space failed");
+
+        // Process results
+        nv_global_write_lock_result.expect("Call to nv_global_write_lock failed");
+        let _ = nv_write_result.expect_err("Writing to a globally write locked index succeeded");
+    }
+}
+
+mod test_nv_read_lock {
+    use crate::common::create_ctx_with_session;
+    use tss_esapi::{
+        attributes::NvIndexAttributesBuilder,
+        handles::NvIndexTpmHandle,
+        interface_types::{
+            algorithm::HashingAlgorithm,
+            resource_handles::{NvAuth, Provision},
+        },
+        structures::{MaxNvBuffer, NvPublicBuilder},
+    };
+
+    #[test]
+    fn test_nv_read_lock() {
+        let mut context = create_ctx_with_session();
+        let nv_index = NvIndexTpmHandle::new(0x01500036).unwrap();
+
+        // Create owner nv public.
+        let owner_nv_index_attributes = NvIndexAttributesBuilder::new()
+            .with_owner_write(true)
+            .with_owner_read(true)
+            .with_read_stclear(true)
+            .build()
+            .expect("Failed to create owner nv index attributes");
+
+        let owner_nv_public = NvPublicBuilder::new()
+            .with_nv_index(nv_index)
+            .with_index_name_algorithm(HashingAlgorithm::Sha256)
+            .with_index_attributes(owner_nv_index_attributes)
+            .with_data_area_size(32)
+            .build()
+            .expect("Failed to build NvPublic for owner");
+
+        let owner_nv_index_handle = context
+            .nv_define_space(Provision::Owner, None, owner_nv_public)
+            .expect("Call to nv_define_space failed");
+
+        // Read the data before and after locking the index.
+        let nv_write_result = context.nv_write(
+            NvAuth::Owner,
+            owner_nv_index_handle,
+            MaxNvBuffer::default(),
+            0,
+        );
+        let first_nv_read_result = context.nv_read(NvAuth::Owner, owner_nv_index_handle, 0, 0);
+        let nv_read_lock_result = context.nv_read_lock(NvAuth::Owner, owner_nv_index_handle);
+        let second_nv_read_result = context.nv_read(NvAuth::Owner, owner_nv_index_handle, 0, 0);
+
+        context
+            .nv_undefine_space(Provision::Owner, owner_nv_index_handle)
+            .expect("Call to nv_undefine_space failed");
+
+        // Process results
+        nv_write_result.expect("Call to nv_write failed");
+        let _ = first_nv_read_result.expect("First call to nv_read failed");
+        nv_read_lock_result.expect("Call to nv_read_lock failed");
+        let _ = second_nv_read_result.expect_err("Reading a read locked index succeeded");
+    }
+}
+
+mod test_nv_change_auth {
+    use crate::common::{create_ctx_with_session, create_ctx_without_session};
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        attributes::{NvIndexAttributesBuilder, SessionAttributesBuilder},
+        constants::{CommandCode, SessionType},
+        handles::NvIndexTpmHandle,
+        interface_types::{
+            algorithm::HashingAlgorithm,
+            resource_handles::{NvAuth, Provision},
+            session_handles::{AuthSession, PolicySession},
+        },
+        structures::{Auth, Digest, MaxNvBuffer, NvPublicBuilder, SymmetricDefinition},
+        Context,
+    };
+
+    fn start_policy_session(context: &mut Context, session_type: SessionType) -> PolicySession {
+        let policy_auth_session = context
+            .start_auth_session(
+                None,
+                None,
+                None,
+                session_type,
+                SymmetricDefinition::AES_256_CFB,
+                HashingAlgorithm::Sha256,
+            )
+            .expect("Start auth session failed")
+            .expect("Start auth session returned a NONE handle");
+        let (policy_auth_session_attributes, policy_auth_session_attributes_mask) =
+            SessionAttributesBuilder::new()
+                .with_decrypt(true)
+                .with_encrypt(true)
+                .build();
+        context
+            .tr_sess_set_attributes(
+                policy_auth_session,
+                policy_auth_session_attributes,
+                policy_auth_session_attributes_mask,
+            )
+            .expect("tr_sess_set_attributes call failed");
+        let policy_session = PolicySession::try_from(policy_auth_session)
+            .expect("Failed to convert auth session into policy session");
+        context
+            .policy_command_code(policy_session, CommandCode::NvChangeAuth)
+            .expect("Call to policy_command_code failed");
+        policy_session
+    }
+
+    fn change_auth_policy_digest() -> Digest {
+        let mut context = create_ctx_without_session();
+        let trial_policy_session = start_policy_session(&mut context, SessionType::Trial);
+        context
+            .policy_get_digest(trial_policy_session)
+            .expect("Call to policy_get_digest failed")
+    }
+
+    #[test]
+    fn test_nv_change_auth() {
+        let mut context = create_ctx_with_session();
+        let nv_index = NvIndexTpmHandle::new(0x01500037).unwrap();
+
+        // Create nv public that is authorized using the auth value.
+        let nv_index_attributes = NvIndexAttributesBuilder::new()
+            .with_auth_write(true)
+            .with_auth_read(true)
+            .build()
+            .expect("Failed to create nv index attributes");
+
+        let nv_public = NvPublicBuilder::new()
+            .with_nv_index(nv_index)
+            .with_index_name_algorithm(HashingAlgorithm::Sha256)
+            .with_index_attributes(nv_index_attributes)
+            .with_index_auth_policy(change_auth_policy_digest())
+            .with_data_area_size(32)
+            .build()
+            .expect("Failed to build NvPublic");
+
+        let old_auth = Auth::try_from(vec![1, 2, 3, 4]).expect("Failed to create old auth");
+        let new_auth = Auth::try_from(vec![5, 6, 7, 8]).expect("Failed to create new auth");
+        let data = MaxNvBuffer::try_from(vec![1, 2, 3, 4, 5, 6, 7])
+            .expect("Failed to create MaxNvBuffer from vec");
+
+        let nv_index_handle = context
+            .nv_define_space(Provision::Owner, Some(old_auth), nv_public)
+            .expect("Call to nv_define_space failed");
+
+        // Change the auth value using a policy session.
+        let policy_session = start_policy_session(&mut context, SessionType::Policy);
+        let nv_change_auth_result = context
+            .execute_with_session(Some(AuthSession::PolicySession(policy_session)), |ctx| {
+                ctx.nv_change_auth(nv_index_handle, new_auth)
+            });
+
+        // Write using the new auth value.
+        let nv_write_result =
+            context.nv_write(NvAuth::NvIndex(nv_index_handle), nv_index_handle, data, 0);
+
+        context
+            .nv_undefine_space(Provision::Owner, nv_index_handle)
+            .expect("Call to nv_undefine_space failed");
+
+        // Process results
+        nv_change_auth_result.expect("Call to nv_change_auth failed");
+        nv_write_result.expect("Call to nv_write with the new auth value failed");
+    }
+}
+
+mod test_nv_undefine_space_special {
+    use crate::common::create_ctx_without_session;
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        attributes::{NvIndexAttributesBuilder, SessionAttributesBuilder},
+        constants::{CommandCode, SessionType},
+        handles::NvIndexTpmHandle,
+        interface_types::{
+            algorithm::HashingAlgorithm,
+            resource_handles::{Platform, Provision},
+            session_handles::{AuthSession, PolicySession},
+        },
+        structures::{NvPublicBuilder, SymmetricDefinition},
+        Context,
+    };
+
+    fn start_policy_session(context: &mut Context, session_type: SessionType) -> PolicySession {
+        let policy_auth_session = context
+            .start_auth_session(
+                None,
+                None,
+                None,
+                session_type,
+                SymmetricDefinition::AES_256_CFB,
+                HashingAlgorithm::Sha256,
+            )
+            .expect("Start auth session failed")
+            .expect("Start auth session returned a NONE handle");
+        let (policy_auth_session_attributes, policy_auth_session_attributes_mask) =
+            SessionAttributesBuilder::new()
+                .with_decrypt(true)
+                .with_encrypt(true)
+                .build();
+        context
+            .tr_sess_set_attributes(
+                policy_auth_session,
+                policy_auth_session_attributes,
+                policy_auth_session_attributes_mask,
+            )
+            .expect("tr_sess_set_attributes call failed");
+        let policy_session = PolicySession::try_from(policy_auth_session)
+            .expect("Failed to convert auth session into policy session");
+        context
+            .policy_command_code(policy_session, CommandCode::NvUndefineSpaceSpecial)
+            .expect("Call to policy_command_code failed");
+        policy_session
+    }
+
+    #[test]
+    fn test_nv_undefine_space_special() {
+        let mut context = create_ctx_without_session();
+        let nv_index = NvIndexTpmHandle::new(0x01500038).unwrap();
+
+        let trial_policy_session = start_policy_session(&mut context, SessionType::Trial);
+        let policy_digest = context
+            .policy_get_digest(trial_policy_session)
+            .expect("Call to policy_get_digest failed");
+
+        // Create platform nv public that can only be deleted using the policy.
+        let platform_nv_index_attributes = NvIndexAttributesBuilder::new()
+            .with_pp_write(true)
+            .with_pp_read(true)
+            .with_platform_create(true)
+            .with_policy_delete(true)
+            .build()
+            .expect("Failed to create platform nv index attributes");
+
+        let platform_nv_public = NvPublicBuilder::new()
+            .with_nv_index(nv_index)
+            .with_index_name_algorithm(HashingAlgorithm::Sha256)
+            .with_index_attributes(platform_nv_index_attributes)
+            .with_index_auth_policy(policy_digest)
+            .with_data_area_size(32)
+            .build()
+            .expect("Failed to build NvPublic for platform");
+
+        let platform_nv_index_handle = context
+            .execute_with_session(Some(AuthSession::Password), |ctx| {
+                ctx.nv_define_space(Provision::Platform, None, platform_nv_public)
+            })
+            .expect("Call to nv_define_space failed");
+
+        // Indices with the policy delete attribute cannot be undefined normally.
+        let _ = context
+            .execute_with_session(Some(AuthSession::Password), |ctx| {
+                ctx.nv_undefine_space(Provision::Platform, platform_nv_index_handle)
+            })
+            .expect_err("Call to nv_undefine_space succeeded for a policy delete index");
+
+        let policy_session = start_policy_session(&mut context, SessionType::Policy);
+        context
+            .execute_with_sessions(
+                (
+                    Some(AuthSession::PolicySession(policy_session)),
+                    Some(AuthSession::Password),
+                    None,
+                ),
+                |ctx| ctx.nv_undefine_space_special(platform_nv_index_handle, Platform::Platform),
+            )
+            .expect("Call to nv_undefine_space_special failed");
+    }
+}
+
+mod test_nv_certify {
+    use crate::common::{create_ctx_with_session, signing_key_pub};
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        attributes::NvIndexAttributesBuilder,
+        handles::NvIndexTpmHandle,
+        interface_types::{
+            algorithm::HashingAlgorithm,
+            resource_handles::{Hierarchy, NvAuth, Provision},
+            session_handles::AuthSession,
+            structure_tags::AttestationType,
+        },
+        structures::{AttestInfo, Data, MaxNvBuffer, NvPublicBuilder, SignatureScheme},
+    };
+
+    #[test]
+    fn test_nv_certify() {
+        let mut context = create_ctx_with_session();
+        let nv_index = NvIndexTpmHandle::new(0x01500040).unwrap();
+
+        let sign_key_handle = context
+            .create_primary(Hierarchy::Owner, signing_key_pub(), None, None, None, None)
+            .expect("Call to create_primary failed")
+            .key_handle;
+
+        // Create owner nv public.
+        let owner_nv_index_attributes = NvIndexAttributesBuilder::new()
+            .with_owner_write(true)
+            .with_owner_read(true)
+            .build()
+            .expect("Failed to create owner nv index attributes");
+
+        let owner_nv_public = NvPublicBuilder::new()
+            .with_nv_index(nv_index)
+            .with_index_name_algorithm(HashingAlgorithm::Sha256)
+            .with_index_attributes(owner_nv_index_attributes)
+            .with_data_area_size(32)
+            .build()
+            .expect("Failed to build NvPublic for owner");
+
+        let data = MaxNvBuffer::try_from(vec![1, 2, 3, 4, 5, 6, 7])
+            .expect("Failed to create MaxNvBuffer from vec");
+        let qualifying_data = Data::try_from(vec![0xff; 16]).unwrap();
+
+        let owner_nv_index_handle = context
+            .nv_define_space(Provision::Owner, None, owner_nv_public)
+            .expect("Call to nv_define_space failed");
+
+        let nv_write_result =
+            context.nv_write(NvAuth::Owner, owner_nv_index_handle, data.clone(), 0);
+
+        let nv_read_public_result = context.nv_read_public(owner_nv_index_handle);
+
+        // Certify the written data using owner authorization
+        let nv_certify_result = context.execute_with_sessions(
+            (
+                Some(AuthSession::Password),
+                Some(AuthSession::Password),
+                None,
+            ),
+            |ctx| {
+                ctx.nv_certify(
+                    sign_key_handle,
+                    NvAuth::Owner,
+                    owner_nv_index_handle,
+                    qualifying_data.clone(),
+                    SignatureScheme::Null,
+                    7,
+                    0,
+                )
+            },
+        );
+
+        context
+            .nv_undefine_space(Provision::Owner, owner_nv_index_handle)
+            .expect("Call to nv_undefine_space failed");
+
+        // Process results
+        nv_write_result.expect("Call to nv_write failed");
+        let (_, expected_name) = nv_read_public_result.expect("Call to nv_read_public failed");
+        let (attest, _signature) = nv_certify_result.expect("Call to nv_certify failed");
+
+        // Check result.
+        assert_eq!(AttestationType::Nv, attest.attestation_type());
+        assert_eq!(&qualifying_data, attest.extra_data());
+        match attest.attested() {
+            AttestInfo::Nv { info } => {
+                assert_eq!(&expected_name, info.index_name());
+                assert_eq!(0, info.offset());
+                assert_eq!(&data, info.nv_contents());
+            }
+            _ => panic!("Attested did not contain the expected variant."),
+        }
+    }
+}
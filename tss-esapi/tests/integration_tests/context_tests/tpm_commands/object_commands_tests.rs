@@ -426,3 +426,184 @@ mod test_unseal {
         assert!(unsealed == testbytes);
     }
 }
+
+mod test_create_loaded {
+    use crate::common::{create_ctx_with_session, decryption_key_pub, signing_key_pub};
+    use tss_esapi::{
+        handles::ObjectHandle, interface_types::resource_handles::Hierarchy,
+        structures::TemplateInput,
+    };
+
+    #[test]
+    fn test_create_loaded_primary() {
+        let mut context = create_ctx_with_session();
+        let primary = context
+            .create_primary(
+                Hierarchy::Owner,
+                decryption_key_pub(),
+                None,
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+        let result = context
+            .create_loaded(
+                ObjectHandle::from(Hierarchy::Owner),
+                TemplateInput::Public(decryption_key_pub()),
+                None,
+                None,
+            )
+            .unwrap();
+
+        assert!(result.out_private.is_empty());
+        assert_eq!(result.out_public, primary.out_public);
+    }
+
+    #[test]
+    fn test_create_loaded_ordinary() {
+        let mut context = create_ctx_with_session();
+        let parent_handle = context
+            .create_primary(
+                Hierarchy::Owner,
+                decryption_key_pub(),
+                None,
+                None,
+                None,
+                None,
+            )
+            .unwrap()
+            .key_handle;
+        let result = context
+            .create_loaded(
+                parent_handle.into(),
+                TemplateInput::Public(signing_key_pub()),
+                None,
+                None,
+            )
+            .unwrap();
+
+        let (public, _, _) = context.read_public(result.key_handle).unwrap();
+        assert_eq!(public, result.out_public);
+        let _ = context
+            .load(parent_handle, result.out_private, result.out_public)
+            .unwrap();
+    }
+}
+
+#[cfg(has_tpm2b_label)]
+mod test_create_loaded_derived {
+    use crate::common::{create_ctx_with_session, decryption_key_pub};
+    use tss_esapi::{
+        attributes::ObjectAttributesBuilder,
+        interface_types::{
+            algorithm::{HashingAlgorithm, KeyDerivationFunction, PublicAlgorithm},
+            ecc::EccCurve,
+            resource_handles::Hierarchy,
+        },
+        structures::{
+            Digest, EccPoint, EccScheme, HashScheme, KeyedHashScheme, Label, Public, PublicBuilder,
+            PublicEccParametersBuilder, PublicKeyedHashParameters, TemplateInput, XorScheme,
+        },
+    };
+
+    fn derivation_parent_pub() -> Public {
+        let object_attributes = ObjectAttributesBuilder::new()
+            .with_fixed_tpm(true)
+            .with_fixed_parent(true)
+            .with_sensitive_data_origin(true)
+            .with_user_with_auth(true)
+            .with_decrypt(true)
+            .with_restricted(true)
+            .build()
+            .expect("Attributes to be valid");
+        PublicBuilder::new()
+            .with_public_algorithm(PublicAlgorithm::KeyedHash)
+            .with_name_hashing_algorithm(HashingAlgorithm::Sha256)
+            .with_object_attributes(object_attributes)
+            .with_keyed_hash_parameters(PublicKeyedHashParameters::new(KeyedHashScheme::Xor {
+                xor_scheme: XorScheme::new(
+                    HashingAlgorithm::Sha256,
+                    KeyDerivationFunction::Kdf1Sp800_108,
+                ),
+            }))
+            .with_keyed_hash_unique_identifier(Digest::default())
+            .build()
+            .expect("Public to be valid")
+    }
+
+    fn derived_key_template(label: &[u8], context: &[u8]) -> TemplateInput {
+        // The sensitive data of a derived object does not originate in the TPM.
+        let object_attributes = ObjectAttributesBuilder::new()
+            .with_fixed_tpm(true)
+            .with_fixed_parent(true)
+            .with_user_with_auth(true)
+            .with_sign_encrypt(true)
+            .build()
+            .expect("Attributes to be valid");
+        let public = PublicBuilder::new()
+            .with_public_algorithm(PublicAlgorithm::Ecc)
+            .with_name_hashing_algorithm(HashingAlgorithm::Sha256)
+            .with_object_attributes(object_attributes)
+            .with_ecc_parameters(
+                PublicEccParametersBuilder::new_unrestricted_signing_key(
+                    EccScheme::EcDsa(HashScheme::new(HashingAlgorithm::Sha256)),
+                    EccCurve::NistP256,
+                )
+                .build()
+                .expect("Params to be valid"),
+            )
+            .with_ecc_unique_identifier(EccPoint::default())
+            .build()
+            .expect("Public to be valid");
+        TemplateInput::Derive {
+            public,
+            label: Label::from_bytes(label).unwrap(),
+            context: Label::from_bytes(context).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_create_loaded_derived() {
+        let mut context = create_ctx_with_session();
+        let storage_parent_handle = context
+            .create_primary(
+                Hierarchy::Owner,
+                decryption_key_pub(),
+                None,
+                None,
+                None,
+                None,
+            )
+            .unwrap()
+            .key_handle;
+        let derivation_parent_handle = context
+            .create_loaded(
+                storage_parent_handle.into(),
+                TemplateInput::Public(derivation_parent_pub()),
+                None,
+                None,
+            )
+            .unwrap()
+            .key_handle;
+
+        let mut derive = |label: &[u8], derivation_context: &[u8]| {
+            let result = context
+                .create_loaded(
+                    derivation_parent_handle.into(),
+                    derived_key_template(label, derivation_context),
+                    None,
+                    None,
+                )
+                .unwrap();
+            context.flush_context(result.key_handle.into()).unwrap();
+            result.out_public
+        };
+
+        // Derived keys only depend on the derivation parent, label and context.
+        let tenant_a = derive(b"signing", b"tenant a");
+        assert_eq!(tenant_a, derive(b"signing", b"tenant a"));
+        assert_ne!(tenant_a, derive(b"signing", b"tenant b"));
+        assert_ne!(tenant_a, derive(b"other", b"tenant a"));
+    }
+}
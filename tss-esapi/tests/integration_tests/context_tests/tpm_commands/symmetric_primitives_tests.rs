@@ -1,5 +1,99 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
+mod test_encrypt_decrypt {
+    use crate::common::create_ctx_without_session;
+    use std::convert::{TryFrom, TryInto};
+    use tss_esapi::{
+        abstraction::cipher::Cipher,
+        attributes::ObjectAttributesBuilder,
+        interface_types::{
+            algorithm::{HashingAlgorithm, PublicAlgorithm, SymmetricMode},
+            resource_handles::Hierarchy,
+            session_handles::AuthSession,
+        },
+        structures::{InitialValue, MaxBuffer, PublicBuilder, SymmetricCipherParameters},
+    };
+
+    #[test]
+    fn test_encrypt_decrypt_with_aes_128_cfb_symmetric_key() {
+        let mut context = create_ctx_without_session();
+        // The first parameter of EncryptDecrypt is not a sized buffer
+        // so parameter encryption cannot be used.
+        context.set_sessions((Some(AuthSession::Password), None, None));
+
+        let object_attributes = ObjectAttributesBuilder::new()
+            .with_user_with_auth(true)
+            .with_sign_encrypt(true)
+            .with_decrypt(true)
+            .with_sensitive_data_origin(true)
+            .build()
+            .expect("Failed to create object attributes for symmetric key");
+        let symmetric_key_public = PublicBuilder::new()
+            .with_public_algorithm(PublicAlgorithm::SymCipher)
+            .with_name_hashing_algorithm(HashingAlgorithm::Sha256)
+            .with_object_attributes(object_attributes)
+            .with_symmetric_cipher_parameters(SymmetricCipherParameters::new(
+                Cipher::aes_128_cfb()
+                    .try_into()
+                    .expect("Failed to create symmteric cipher parameters from cipher"),
+            ))
+            .with_symmetric_cipher_unique_identifier(Default::default())
+            .build()
+            .expect("Failed to create public for symmetric key public");
+        let symmetric_key_handle = context
+            .create_primary(
+                Hierarchy::Owner,
+                symmetric_key_public,
+                None,
+                None,
+                None,
+                None,
+            )
+            .expect("Failed to create symmetric key")
+            .key_handle;
+
+        let initial_value =
+            InitialValue::try_from(vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16])
+                .expect("Failed to create InitialValue from data");
+        let data = MaxBuffer::try_from(vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 16])
+            .expect("Failed to create MaxBuffer from data");
+
+        let (encrypted_data, _) = context
+            .encrypt_decrypt(
+                symmetric_key_handle,
+                false,
+                SymmetricMode::Cfb,
+                data.clone(),
+                initial_value.clone(),
+            )
+            .expect("Call to encrypt_decrypt failed when encrypting data");
+        assert_ne!(data, encrypted_data);
+
+        // Both variants of the command produce the same result.
+        let (expected_encrypted_data, _) = context
+            .encrypt_decrypt_2(
+                symmetric_key_handle,
+                false,
+                SymmetricMode::Cfb,
+                data.clone(),
+                initial_value.clone(),
+            )
+            .expect("Call to encrypt_decrypt_2 failed when encrypting data");
+        assert_eq!(expected_encrypted_data, encrypted_data);
+
+        let (decrypted_data, _) = context
+            .encrypt_decrypt(
+                symmetric_key_handle,
+                true,
+                SymmetricMode::Cfb,
+                encrypted_data,
+                initial_value,
+            )
+            .expect("Call to encrypt_decrypt failed when decrypting data");
+        assert_eq!(data, decrypted_data);
+    }
+}
+
 mod test_encrypt_decrypt_2 {
     use crate::common::create_ctx_without_session;
     use std::convert::{TryFrom, TryInto};
@@ -235,3 +329,112 @@ mod test_hmac {
             .unwrap();
     }
 }
+
+#[cfg(has_esys_mac)]
+mod test_mac {
+    use crate::common::create_ctx_with_session;
+    use std::convert::{TryFrom, TryInto};
+    use tss_esapi::{
+        abstraction::cipher::Cipher,
+        attributes::ObjectAttributesBuilder,
+        interface_types::{
+            algorithm::{HashingAlgorithm, MacSchemeAlgorithm, PublicAlgorithm},
+            resource_handles::Hierarchy,
+        },
+        structures::{
+            KeyedHashScheme, MaxBuffer, PublicBuilder, PublicKeyedHashParameters, SensitiveData,
+            SymmetricCipherParameters,
+        },
+    };
+
+    #[test]
+    fn test_mac_with_hmac_scheme() {
+        let mut context = create_ctx_with_session();
+
+        let object_attributes = ObjectAttributesBuilder::new()
+            .with_sign_encrypt(true)
+            .with_sensitive_data_origin(true)
+            .with_user_with_auth(true)
+            .build()
+            .expect("Failed to build object attributes");
+        let key_pub = PublicBuilder::new()
+            .with_public_algorithm(PublicAlgorithm::KeyedHash)
+            .with_name_hashing_algorithm(HashingAlgorithm::Sha256)
+            .with_object_attributes(object_attributes)
+            .with_keyed_hash_parameters(PublicKeyedHashParameters::new(
+                KeyedHashScheme::HMAC_SHA_256,
+            ))
+            .with_keyed_hash_unique_identifier(Default::default())
+            .build()
+            .expect("Failed to build public structure for key.");
+        let key_handle = context
+            .create_primary(Hierarchy::Owner, key_pub, None, None, None, None)
+            .unwrap()
+            .key_handle;
+
+        let data = MaxBuffer::try_from(vec![1, 2, 3, 4]).unwrap();
+        let expected_hmac = context
+            .hmac(key_handle.into(), data.clone(), HashingAlgorithm::Sha256)
+            .unwrap();
+        let mac = context
+            .mac(key_handle.into(), data, MacSchemeAlgorithm::Sha256)
+            .unwrap();
+        assert_eq!(expected_hmac, mac);
+    }
+
+    #[test]
+    fn test_mac_with_cmac_scheme() {
+        let mut context = create_ctx_with_session();
+
+        // Test vector from RFC 4493, example 2.
+        let key = vec![
+            0x2b, 0x7e, 0x15, 0x16, 0x28, 0xae, 0xd2, 0xa6, 0xab, 0xf7, 0x15, 0x88, 0x09, 0xcf,
+            0x4f, 0x3c,
+        ];
+        let message = vec![
+            0x6b, 0xc1, 0xbe, 0xe2, 0x2e, 0x40, 0x9f, 0x96, 0xe9, 0x3d, 0x7e, 0x11, 0x73, 0x93,
+            0x17, 0x2a,
+        ];
+        let expected_cmac = [
+            0x07, 0x0a, 0x16, 0xb4, 0x6b, 0x4d, 0x41, 0x44, 0xf7, 0x9b, 0xdd, 0x9d, 0xd0, 0x4a,
+            0x28, 0x7c,
+        ];
+
+        let object_attributes = ObjectAttributesBuilder::new()
+            .with_user_with_auth(true)
+            .with_sign_encrypt(true)
+            .with_decrypt(true)
+            .build()
+            .expect("Failed to build object attributes");
+        let key_pub = PublicBuilder::new()
+            .with_public_algorithm(PublicAlgorithm::SymCipher)
+            .with_name_hashing_algorithm(HashingAlgorithm::Sha256)
+            .with_object_attributes(object_attributes)
+            .with_symmetric_cipher_parameters(SymmetricCipherParameters::new(
+                Cipher::aes_128_cfb().try_into().unwrap(),
+            ))
+            .with_symmetric_cipher_unique_identifier(Default::default())
+            .build()
+            .expect("Failed to build public structure for key.");
+        let key_handle = context
+            .create_primary(
+                Hierarchy::Owner,
+                key_pub,
+                None,
+                Some(SensitiveData::try_from(key).unwrap()),
+                None,
+                None,
+            )
+            .unwrap()
+            .key_handle;
+
+        let mac = context
+            .mac(
+                key_handle.into(),
+                MaxBuffer::try_from(message).unwrap(),
+                MacSchemeAlgorithm::Cmac,
+            )
+            .unwrap();
+        assert_eq!(mac.as_bytes(), expected_cmac);
+    }
+}
@@ -11,6 +11,23 @@ mod test_self_test {
     }
 }
 
+mod test_incremental_self_test {
+    use crate::common::create_ctx_without_session;
+    use std::convert::TryFrom;
+    use tss_esapi::{constants::AlgorithmIdentifier, structures::AlgorithmList};
+
+    #[test]
+    fn test_incremental_self_test() {
+        let mut context = create_ctx_without_session();
+        let to_test =
+            AlgorithmList::try_from(vec![AlgorithmIdentifier::Sha256, AlgorithmIdentifier::Rsa])
+                .unwrap();
+        let _ = context.incremental_self_test(to_test).unwrap();
+        // Testing an empty list only reports the algorithms left to test.
+        let _ = context.incremental_self_test(AlgorithmList::new()).unwrap();
+    }
+}
+
 mod test_get_test_result {
     use crate::common::create_ctx_without_session;
     #[test]
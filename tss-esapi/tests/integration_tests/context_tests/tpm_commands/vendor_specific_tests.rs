@@ -1,2 +1,67 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
+mod test_vendor_tcg_test {
+    use crate::common::create_ctx_without_session;
+    use std::convert::TryFrom;
+    use tss_esapi::structures::Data;
+
+    #[test]
+    fn test_vendor_tcg_test() {
+        let mut context = create_ctx_without_session();
+        let input_data = Data::try_from(vec![1, 2, 3, 4]).unwrap();
+        let output_data = context.vendor_tcg_test(input_data.clone()).unwrap();
+        assert_eq!(input_data, output_data);
+    }
+}
+
+mod test_vendor_command {
+    use crate::common::{create_ctx_with_session, create_ctx_without_session};
+    use tss_esapi::{
+        constants::tss::TPM2_CC_Vendor_TCG_Test, interface_types::session_handles::AuthSession,
+        structures::Auth, Error, WrapperErrorKind,
+    };
+
+    #[test]
+    fn test_vendor_command() {
+        let mut context = create_ctx_without_session();
+        // The parameter of Vendor_TCG_Test is a TPM2B_DATA which is returned unchanged.
+        let parameters = vec![0x00, 0x04, 1, 2, 3, 4];
+        let response = context
+            .vendor_command(TPM2_CC_Vendor_TCG_Test, &[], &[], &parameters)
+            .unwrap();
+        assert_eq!(parameters, response);
+    }
+
+    #[test]
+    fn test_vendor_command_invalid_parameters() {
+        let mut context = create_ctx_without_session();
+        // TPM2_CC_GetCapability is not vendor specific.
+        assert_eq!(
+            Err(Error::WrapperError(WrapperErrorKind::InvalidParam)),
+            context.vendor_command(0x0000_017A, &[], &[], &[])
+        );
+        assert_eq!(
+            Err(Error::WrapperError(WrapperErrorKind::InvalidParam)),
+            context.vendor_command(TPM2_CC_Vendor_TCG_Test, &[], &[Auth::default()], &[])
+        );
+    }
+
+    #[test]
+    fn test_vendor_command_with_sessions() {
+        // Password sessions of the context are ignored.
+        let mut context = create_ctx_without_session();
+        context.set_sessions((Some(AuthSession::Password), None, None));
+        let parameters = vec![0x00, 0x02, 1, 2];
+        let response = context
+            .vendor_command(TPM2_CC_Vendor_TCG_Test, &[], &[], &parameters)
+            .unwrap();
+        assert_eq!(parameters, response);
+
+        // HMAC sessions cannot be used.
+        let mut context = create_ctx_with_session();
+        assert_eq!(
+            Err(Error::WrapperError(WrapperErrorKind::UnsupportedParam)),
+            context.vendor_command(TPM2_CC_Vendor_TCG_Test, &[], &[], &parameters)
+        );
+    }
+}
@@ -389,3 +389,89 @@ mod symmetric_object_tests {
         )
     }
 }
+
+mod mac_scheme_tests {
+    use super::*;
+    use tss_esapi::{
+        constants::{
+            tss::{
+                TPM2_ALG_CMAC, TPM2_ALG_NULL, TPM2_ALG_SHA1, TPM2_ALG_SHA256, TPM2_ALG_SHA384,
+                TPM2_ALG_SHA3_256, TPM2_ALG_SHA3_384, TPM2_ALG_SHA3_512, TPM2_ALG_SHA512,
+                TPM2_ALG_SM3_256,
+            },
+            AlgorithmIdentifier,
+        },
+        interface_types::algorithm::{HashingAlgorithm, MacSchemeAlgorithm},
+    };
+    #[test]
+    fn test_mac_scheme_conversion() {
+        test_conversion!(TPM2_ALG_SHA1, MacSchemeAlgorithm::Sha1);
+        test_conversion!(TPM2_ALG_SHA256, MacSchemeAlgorithm::Sha256);
+        test_conversion!(TPM2_ALG_SHA384, MacSchemeAlgorithm::Sha384);
+        test_conversion!(TPM2_ALG_SHA512, MacSchemeAlgorithm::Sha512);
+        test_conversion!(TPM2_ALG_SM3_256, MacSchemeAlgorithm::Sm3_256);
+        test_conversion!(TPM2_ALG_SHA3_256, MacSchemeAlgorithm::Sha3_256);
+        test_conversion!(TPM2_ALG_SHA3_384, MacSchemeAlgorithm::Sha3_384);
+        test_conversion!(TPM2_ALG_SHA3_512, MacSchemeAlgorithm::Sha3_512);
+        test_conversion!(TPM2_ALG_CMAC, MacSchemeAlgorithm::Cmac);
+        test_conversion!(TPM2_ALG_NULL, MacSchemeAlgorithm::Null);
+    }
+
+    #[test]
+    fn test_conversion_from_hashing_algorithm() {
+        assert_eq!(
+            MacSchemeAlgorithm::Sha256,
+            MacSchemeAlgorithm::from(HashingAlgorithm::Sha256)
+        );
+        assert_eq!(
+            MacSchemeAlgorithm::Null,
+            MacSchemeAlgorithm::from(HashingAlgorithm::Null)
+        );
+    }
+
+    #[test]
+    fn test_conversion_of_incorrect_algorithm() {
+        test_invalid_tpm_alg_conversion!(
+            TPM2_ALG_AES,
+            MacSchemeAlgorithm,
+            WrapperErrorKind::InvalidParam
+        );
+        test_invalid_algorithm_conversion!(
+            AlgorithmIdentifier::Hmac,
+            MacSchemeAlgorithm,
+            WrapperErrorKind::InvalidParam
+        )
+    }
+}
+
+mod ecc_key_exchange_tests {
+    use super::*;
+    use tss_esapi::{
+        constants::{
+            tss::{TPM2_ALG_ECDH, TPM2_ALG_ECMQV, TPM2_ALG_NULL, TPM2_ALG_SM2},
+            AlgorithmIdentifier,
+        },
+        interface_types::algorithm::EccKeyExchangeAlgorithm,
+    };
+    #[test]
+    fn test_ecc_key_exchange_conversion() {
+        test_conversion!(TPM2_ALG_ECDH, EccKeyExchangeAlgorithm::EcDh);
+        test_conversion!(TPM2_ALG_ECMQV, EccKeyExchangeAlgorithm::EcMqv);
+        test_conversion!(TPM2_ALG_SM2, EccKeyExchangeAlgorithm::Sm2);
+        test_conversion!(TPM2_ALG_NULL, EccKeyExchangeAlgorithm::Null);
+    }
+
+    #[test]
+    fn test_conversion_of_incorrect_algorithm() {
+        test_invalid_tpm_alg_conversion!(
+            TPM2_ALG_ECDSA,
+            EccKeyExchangeAlgorithm,
+            WrapperErrorKind::InvalidParam
+        );
+        test_invalid_algorithm_conversion!(
+            AlgorithmIdentifier::EcDaa,
+            EccKeyExchangeAlgorithm,
+            WrapperErrorKind::InvalidParam
+        )
+    }
+}
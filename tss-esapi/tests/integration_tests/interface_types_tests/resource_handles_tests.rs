@@ -4,8 +4,8 @@ use std::convert::TryFrom;
 use tss_esapi::{
     handles::{AuthHandle, NvIndexHandle, ObjectHandle, PermanentTpmHandle, TpmHandle},
     interface_types::resource_handles::{
-        Clear, Enables, Endorsement, Hierarchy, HierarchyAuth, Lockout, NvAuth, Owner, Platform,
-        Provision,
+        Act, Clear, Enables, Endorsement, Hierarchy, HierarchyAuth, Lockout, NvAuth, Owner,
+        Platform, Provision,
     },
     tss2_esys::ESYS_TR,
 };
@@ -297,3 +297,53 @@ mod test_lockout {
         );
     }
 }
+
+mod test_act {
+    use super::*;
+    use tss_esapi::constants::tss::{TPM2_RH_ACT_0, TPM2_RH_ACT_F};
+
+    #[test]
+    fn test_conversions() {
+        assert_eq!(
+            TpmHandle::from(Act::Act0),
+            TpmHandle::Permanent(PermanentTpmHandle::AuthenticatedTimersFirst)
+        );
+        assert_eq!(
+            TpmHandle::from(Act::ActF),
+            TpmHandle::Permanent(PermanentTpmHandle::AuthenticatedTimersLast)
+        );
+        for tpm_rh in TPM2_RH_ACT_0..=TPM2_RH_ACT_F {
+            let tpm_handle = TpmHandle::try_from(tpm_rh).expect("Failed to create TpmHandle");
+            let act = Act::try_from(tpm_handle).expect("Failed to convert TpmHandle into Act");
+            assert_eq!(act.index(), tpm_rh - TPM2_RH_ACT_0);
+            assert_eq!(TpmHandle::from(act), tpm_handle);
+        }
+    }
+
+    #[test]
+    fn test_invalid_conversions() {
+        let _ = Act::try_from(TpmHandle::Permanent(PermanentTpmHandle::Owner))
+            .expect_err("Converting the owner handle into Act did not fail");
+        let _ = Act::try_from(
+            TpmHandle::try_from(TPM2_RH_ACT_F + 1).expect("Failed to create TpmHandle"),
+        )
+        .expect_err("Converting a handle after the last ACT into Act did not fail");
+    }
+
+    #[cfg(has_esys_act)]
+    #[test]
+    fn test_object_handle_conversions() {
+        use tss_esapi::tss2_esys::ESYS_TR_RH_ACT_FIRST;
+        assert_eq!(
+            ObjectHandle::from(Act::Act0),
+            ObjectHandle::from(ESYS_TR_RH_ACT_FIRST)
+        );
+        assert_eq!(
+            Act::try_from(ObjectHandle::from(ESYS_TR_RH_ACT_FIRST + 0xF))
+                .expect("Failed to convert ObjectHandle into Act"),
+            Act::ActF
+        );
+        let _ = Act::try_from(ObjectHandle::Owner)
+            .expect_err("Converting the owner handle into Act did not fail");
+    }
+}
@@ -0,0 +1,59 @@
+// Copyright 2023 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use std::convert::TryFrom;
+use tss_esapi::{
+    constants::tss::{TPM2_ALG_NULL, TPM2_ECC_NIST_P256},
+    interface_types::ecc::EccCurve,
+    structures::{AlgorithmDetailEcc, EccParameter, EccScheme, KeyDerivationFunctionScheme},
+    tss2_esys::{TPMS_ALGORITHM_DETAIL_ECC, TPMT_ECC_SCHEME, TPMT_KDF_SCHEME},
+};
+
+#[test]
+fn test_conversion() {
+    let parameter = |value: u8| EccParameter::try_from(vec![value; 32]).unwrap();
+    let expected_tpms_algorithm_detail_ecc = TPMS_ALGORITHM_DETAIL_ECC {
+        curveID: TPM2_ECC_NIST_P256,
+        keySize: 256,
+        kdf: TPMT_KDF_SCHEME {
+            scheme: TPM2_ALG_NULL,
+            details: Default::default(),
+        },
+        sign: TPMT_ECC_SCHEME {
+            scheme: TPM2_ALG_NULL,
+            details: Default::default(),
+        },
+        p: parameter(1).into(),
+        a: parameter(2).into(),
+        b: parameter(3).into(),
+        gX: parameter(4).into(),
+        gY: parameter(5).into(),
+        n: parameter(6).into(),
+        h: EccParameter::try_from(vec![1]).unwrap().into(),
+    };
+
+    let algorithm_detail_ecc = AlgorithmDetailEcc::try_from(expected_tpms_algorithm_detail_ecc)
+        .expect("Failed to convert TPMS_ALGORITHM_DETAIL_ECC into AlgorithmDetailEcc");
+
+    assert_eq!(algorithm_detail_ecc.curve_id(), EccCurve::NistP256);
+    assert_eq!(algorithm_detail_ecc.key_size(), 256);
+    assert_eq!(
+        algorithm_detail_ecc.kdf(),
+        KeyDerivationFunctionScheme::Null
+    );
+    assert_eq!(algorithm_detail_ecc.sign(), EccScheme::Null);
+    assert_eq!(algorithm_detail_ecc.p(), &parameter(1));
+    assert_eq!(algorithm_detail_ecc.a(), &parameter(2));
+    assert_eq!(algorithm_detail_ecc.b(), &parameter(3));
+    assert_eq!(algorithm_detail_ecc.g().x(), &parameter(4));
+    assert_eq!(algorithm_detail_ecc.g().y(), &parameter(5));
+    assert_eq!(algorithm_detail_ecc.n(), &parameter(6));
+    assert_eq!(algorithm_detail_ecc.h().as_bytes(), [1]);
+
+    let actual_tpms_algorithm_detail_ecc: TPMS_ALGORITHM_DETAIL_ECC =
+        algorithm_detail_ecc.clone().into();
+    assert_eq!(
+        AlgorithmDetailEcc::try_from(actual_tpms_algorithm_detail_ecc)
+            .expect("Failed to convert TPMS_ALGORITHM_DETAIL_ECC into AlgorithmDetailEcc"),
+        algorithm_detail_ecc
+    );
+}
@@ -211,3 +211,34 @@ fn test_nv_into_tpm_type_conversions() {
         actual_tpms_nv_certify_info,
     );
 }
+
+#[cfg(has_tpms_nv_digest_certify_info)]
+#[test]
+fn test_nv_digest_into_tpm_type_conversions() {
+    let expected_tpms_nv_digest_certify_info = tss_esapi::tss2_esys::TPMS_NV_DIGEST_CERTIFY_INFO {
+        indexName: Name::try_from(vec![0xf0u8; 34])
+            .expect("Failed to create index name")
+            .into(),
+        nvDigest: Digest::try_from(vec![0xfc; 32])
+            .expect("Failed to create nv digest")
+            .into(),
+    };
+
+    let tpmu_attest: TPMU_ATTEST = AttestInfo::NvDigest {
+        info: expected_tpms_nv_digest_certify_info
+            .try_into()
+            .expect("Failed to convert TPMS_NV_DIGEST_CERTIFY_INFO into NvDigestCertifyInfo"),
+    }
+    .into();
+
+    let actual_tpms_nv_digest_certify_info = unsafe { &tpmu_attest.nvDigest };
+
+    crate::common::ensure_tpm2b_name_equality(
+        &expected_tpms_nv_digest_certify_info.indexName,
+        &actual_tpms_nv_digest_certify_info.indexName,
+    );
+    crate::common::ensure_tpm2b_digest_equality(
+        &expected_tpms_nv_digest_certify_info.nvDigest,
+        &actual_tpms_nv_digest_certify_info.nvDigest,
+    );
+}
@@ -0,0 +1,30 @@
+// Copyright 2023 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use std::convert::TryFrom;
+use tss_esapi::{structures::Label, tss2_esys::TPM2B_LABEL, Error, WrapperErrorKind};
+
+const LABEL_MAX_SIZE: usize = 32;
+
+#[test]
+fn test_max_sized_data() {
+    let _ = Label::try_from(vec![0xffu8; LABEL_MAX_SIZE])
+        .expect("Failed to parse buffer of maximum size as Label");
+}
+
+#[test]
+fn test_to_large_data() {
+    assert_eq!(
+        Label::try_from(vec![0xffu8; LABEL_MAX_SIZE + 1])
+            .expect_err("Converting a buffer that is to large did not produce an error"),
+        Error::WrapperError(WrapperErrorKind::WrongParamSize),
+    );
+}
+
+#[test]
+fn test_tpm_types_conversion() {
+    let expected_label = Label::try_from(b"label".to_vec()).expect("Failed to create Label");
+    let tpm2b_label = TPM2B_LABEL::from(expected_label.clone());
+    assert_eq!(tpm2b_label.size as usize, expected_label.len());
+    let actual_label = Label::try_from(tpm2b_label).expect("Failed to convert TPM2B_LABEL");
+    assert_eq!(expected_label, actual_label);
+}
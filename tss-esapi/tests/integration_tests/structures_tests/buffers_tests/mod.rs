@@ -4,8 +4,11 @@ mod attest_buffer_tests;
 mod auth_tests;
 mod data_tests;
 mod digest_tests;
+#[cfg(has_tpm2b_label)]
+mod label_tests;
 mod max_buffer_tests;
 mod nonce_tests;
 mod public;
 mod sensitive;
 mod sensitive_create_buffer_tests;
+mod template_tests;
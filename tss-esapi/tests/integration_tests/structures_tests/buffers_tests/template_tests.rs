@@ -0,0 +1,51 @@
+// Copyright 2023 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use std::convert::TryFrom;
+#[cfg(has_tpm2b_label)]
+use tss_esapi::structures::{Label, Public};
+use tss_esapi::{
+    structures::{Template, TemplateInput},
+    traits::Marshall,
+};
+
+#[test]
+fn test_public_template() {
+    crate::common::publics().iter().for_each(|public| {
+        let template = Template::try_from(TemplateInput::from(public.clone()))
+            .expect("Failed to convert Public to Template");
+        assert_eq!(
+            template.as_bytes(),
+            public.marshall().expect("Failed to marshall Public")
+        );
+    });
+}
+
+#[cfg(has_tpm2b_label)]
+#[test]
+fn test_derive_template() {
+    let label = Label::try_from(b"label".to_vec()).unwrap();
+    let context = Label::try_from(b"context".to_vec()).unwrap();
+    crate::common::publics().iter().for_each(|public| {
+        let template = Template::try_from(TemplateInput::Derive {
+            public: public.clone(),
+            label: label.clone(),
+            context: context.clone(),
+        })
+        .expect("Failed to convert derive template to Template");
+
+        let unique_size = match public {
+            Public::Rsa { unique, .. } => 2 + unique.len(),
+            Public::KeyedHash { unique, .. } | Public::SymCipher { unique, .. } => 2 + unique.len(),
+            Public::Ecc { unique, .. } => 4 + unique.x().len() + unique.y().len(),
+        };
+        let marshalled_public = public.marshall().unwrap();
+        let (common, derive) = template
+            .as_bytes()
+            .split_at(marshalled_public.len() - unique_size);
+        assert_eq!(common, &marshalled_public[..common.len()]);
+        assert_eq!(
+            derive,
+            [&[0x00, 0x05][..], b"label", &[0x00, 0x07], b"context"].concat()
+        );
+    });
+}
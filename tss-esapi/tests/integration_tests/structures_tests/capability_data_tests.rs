@@ -1,6 +1,10 @@
 // Copyright 2020 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
 
+#[cfg(has_tpml_tagged_policy)]
+use tss_esapi::constants::tss::TPM2_PERMANENT_FIRST;
+#[cfg(has_esys_act)]
+use tss_esapi::constants::tss::TPM2_RH_ACT_0;
 use tss_esapi::constants::CapabilityType;
 use tss_esapi::structures::CapabilityData;
 
@@ -141,34 +145,34 @@ fn test_ecc_curves() {
     }
 }
 
-// For these tests to work the tpm2-tss library need to have the
-// authPolicies field in the TPMU_CAPABILITIES union.
-// #[test]
-// fn test_auth_policies() {
-//     let mut context = create_ctx_without_session();
-
-//     let (capabs, _more) = context
-//         .get_capability(CapabilityType::AuthPolicies, 0, 80)
-//         .unwrap();
-
-//     if let CapabilityData::AuthPolicies(list) = capabs {
-//         assert!(!list.is_empty());
-//     } else {
-//         panic!("Got wrong type of capability data: {:?}", capabs);
-//     }
-// }
-
-// For these tests to work the tpm2-tss library need to have the
-// actData field in the TPMU_CAPABILITIES union.
-// #[test]
-// fn test_act() {
-//     let mut context = create_ctx_without_session();
-
-//     let (capabs, _more) = context.get_capability(CapabilityType::Act, 0, 80).unwrap();
-
-//     if let CapabilityData::ActData(list) = capabs {
-//         assert!(!list.is_empty());
-//     } else {
-//         panic!("Got wrong type of capability data: {:?}", capabs);
-//     }
-// }
+#[cfg(has_tpml_tagged_policy)]
+#[test]
+fn test_auth_policies() {
+    let mut context = create_ctx_without_session();
+
+    let (capabs, _more) = context
+        .get_capability(CapabilityType::AuthPolicies, TPM2_PERMANENT_FIRST, 80)
+        .unwrap();
+
+    if let CapabilityData::AuthPolicies(list) = capabs {
+        assert!(!list.is_empty());
+    } else {
+        panic!("Got wrong type of capability data: {:?}", capabs);
+    }
+}
+
+#[cfg(has_esys_act)]
+#[test]
+fn test_act() {
+    let mut context = create_ctx_without_session();
+
+    let (capabs, _more) = context
+        .get_capability(CapabilityType::Act, TPM2_RH_ACT_0, 80)
+        .unwrap();
+
+    if let CapabilityData::ActData(list) = capabs {
+        assert!(!list.is_empty());
+    } else {
+        panic!("Got wrong type of capability data: {:?}", capabs);
+    }
+}
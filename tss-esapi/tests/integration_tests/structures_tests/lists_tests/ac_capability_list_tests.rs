@@ -0,0 +1,73 @@
+// Copyright 2023 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use std::convert::{TryFrom, TryInto};
+use tss_esapi::{
+    constants::AttachedComponentTag,
+    structures::{AcCapabilityList, AcOutput},
+    tss2_esys::{TPML_AC_CAPABILITIES, TPMS_AC_OUTPUT},
+    Error, WrapperErrorKind,
+};
+
+#[test]
+fn test_valid_conversions() {
+    let expected_ac_outputs = vec![
+        AcOutput::new(AttachedComponentTag::Pv1, 0x1234_5678),
+        AcOutput::new(AttachedComponentTag::Vendor(0x8000_0010), 7),
+    ];
+
+    let expected_tpml_ac_capabilities: TPML_AC_CAPABILITIES =
+        expected_ac_outputs
+            .iter()
+            .fold(Default::default(), |mut acc, v| {
+                acc.acCapabilities[acc.count as usize] = TPMS_AC_OUTPUT::from(*v);
+                acc.count += 1;
+                acc
+            });
+
+    let ac_capability_list_from_vec: AcCapabilityList = expected_ac_outputs
+        .clone()
+        .try_into()
+        .expect("Failed to convert Vec<AcOutput> into AcCapabilityList");
+    assert_eq!(
+        expected_ac_outputs.as_slice(),
+        ac_capability_list_from_vec.as_ref()
+    );
+
+    let ac_capability_list_from_tss: AcCapabilityList = expected_tpml_ac_capabilities
+        .try_into()
+        .expect("Failed to convert TPML_AC_CAPABILITIES into AcCapabilityList");
+    assert_eq!(ac_capability_list_from_vec, ac_capability_list_from_tss);
+    assert_eq!(
+        ac_capability_list_from_tss
+            .find(AttachedComponentTag::Pv1)
+            .map(AcOutput::data),
+        Some(0x1234_5678)
+    );
+
+    let actual_tpml_ac_capabilities = TPML_AC_CAPABILITIES::from(ac_capability_list_from_tss);
+    assert_eq!(
+        expected_tpml_ac_capabilities.count,
+        actual_tpml_ac_capabilities.count
+    );
+}
+
+#[test]
+fn test_invalid_conversions() {
+    let ac_outputs =
+        vec![AcOutput::new(AttachedComponentTag::Any, 0); AcCapabilityList::MAX_SIZE + 1];
+    assert_eq!(
+        Error::WrapperError(WrapperErrorKind::InvalidParam),
+        AcCapabilityList::try_from(ac_outputs).unwrap_err(),
+        "Converting a Vec<AcOutput> with too many items did not produce the expected error"
+    );
+
+    let tpml_ac_capabilities = TPML_AC_CAPABILITIES {
+        count: AcCapabilityList::MAX_SIZE as u32 + 1,
+        ..Default::default()
+    };
+    assert_eq!(
+        Error::WrapperError(WrapperErrorKind::InvalidParam),
+        AcCapabilityList::try_from(tpml_ac_capabilities).unwrap_err(),
+        "Converting a TPML_AC_CAPABILITIES with an invalid count did not produce the expected error"
+    );
+}
@@ -0,0 +1,109 @@
+// Copyright 2023 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+
+use tss_esapi::{
+    constants::AlgorithmIdentifier,
+    structures::AlgorithmList,
+    tss2_esys::{TPM2_ALG_ID, TPML_ALG},
+    Error, WrapperErrorKind,
+};
+
+use std::convert::TryFrom;
+
+#[test]
+fn test_conversions() {
+    let expected_algorithms = [
+        AlgorithmIdentifier::Rsa,
+        AlgorithmIdentifier::Sha256,
+        AlgorithmIdentifier::Aes,
+    ];
+    let mut algorithm_list = AlgorithmList::new();
+    for algorithm in expected_algorithms.iter() {
+        algorithm_list
+            .add(*algorithm)
+            .expect("Failed to add algorithm to algorithm list");
+    }
+
+    assert_eq!(
+        expected_algorithms.as_slice(),
+        algorithm_list.as_ref(),
+        "The created algorithm list did not contain the expected values"
+    );
+
+    let tpml_alg = TPML_ALG::from(algorithm_list.clone());
+
+    assert_eq!(
+        expected_algorithms.len(),
+        tpml_alg.count as usize,
+        "The count field in TPML_ALG did not contain the expected value"
+    );
+
+    expected_algorithms
+        .iter()
+        .zip(tpml_alg.algorithms[..tpml_alg.count as usize].iter())
+        .for_each(|(expected, actual)| {
+            assert_eq!(
+                TPM2_ALG_ID::from(*expected),
+                *actual,
+                "Algorithm mismatch between algorithms in AlgorithmList and converted AlgorithmList"
+            )
+        });
+
+    let converted_algorithm_list =
+        AlgorithmList::try_from(tpml_alg).expect("Failed to convert TPML_ALG to AlgorithmList");
+
+    assert_eq!(
+        algorithm_list, converted_algorithm_list,
+        "The algorithm list converted from TPML_ALG did not match the original"
+    );
+
+    assert_eq!(
+        expected_algorithms.to_vec(),
+        Vec::<AlgorithmIdentifier>::from(converted_algorithm_list),
+        "The Vec<AlgorithmIdentifier> converted from AlgorithmList did not contain the expected values"
+    );
+}
+
+#[test]
+fn test_invalid_add() {
+    let mut algorithm_list = AlgorithmList::new();
+    for _ in 0..AlgorithmList::MAX_SIZE {
+        algorithm_list
+            .add(AlgorithmIdentifier::Sha256)
+            .expect("Failed to add algorithm to list");
+    }
+
+    assert_eq!(
+        Err(Error::WrapperError(WrapperErrorKind::WrongParamSize)),
+        algorithm_list.add(AlgorithmIdentifier::Sha256),
+        "Adding more algorithms to algorithm list then it supports did not produce the expected error"
+    );
+}
+
+#[test]
+fn test_invalid_conversion_from_tpml_alg() {
+    let invalid_value = TPML_ALG {
+        count: AlgorithmList::MAX_SIZE as u32 + 1u32,
+        algorithms: [0; 128],
+    };
+
+    assert_eq!(
+        Error::WrapperError(WrapperErrorKind::InvalidParam),
+        AlgorithmList::try_from(invalid_value).expect_err(
+            "Converting a TPML_ALG with invalid count to AlgorithmList did not produce an error"
+        ),
+        "Converting invalid TPML_ALG did not produce the expected error",
+    );
+}
+
+#[test]
+fn test_invalid_conversion_from_vector() {
+    assert_eq!(
+        Error::WrapperError(WrapperErrorKind::InvalidParam),
+        AlgorithmList::try_from(vec![AlgorithmIdentifier::Sha256; AlgorithmList::MAX_SIZE + 1])
+            .expect_err(
+                "Converting Vec<AlgorithmIdentifier> of invalid length to AlgorithmList did not produce an error"
+            ),
+        "Converting invalid Vec<AlgorithmIdentifier> did not produce the expected error",
+    );
+}
@@ -1,5 +1,7 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
+mod ac_capability_list_tests;
+mod algorithm_list_tests;
 mod algorithm_property_list_tests;
 mod command_code_attributes_list_tests;
 mod command_code_list_tests;
@@ -1,5 +1,6 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
+mod algorithm_detail_ecc_tests;
 mod algorithm_property_tests;
 mod algorithm_tests;
 mod attest_info_test;
@@ -16,6 +17,7 @@ mod pcr_tests;
 mod quote_info_tests;
 mod session_audit_info_tests;
 mod tagged_pcr_select_tests;
+mod tagged_policy_tests;
 mod tagged_property_tests;
 mod tagged_tests;
 mod time_attest_info_tests;
@@ -0,0 +1,64 @@
+// Copyright 2023 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use std::convert::{TryFrom, TryInto};
+use tss_esapi::{
+    constants::tss::TPM2_ALG_NULL,
+    handles::{PermanentTpmHandle, TpmHandle},
+    interface_types::algorithm::HashingAlgorithm,
+    structures::{Digest, HashAgile, TaggedPolicy},
+    tss2_esys::TPMS_TAGGED_POLICY,
+};
+
+#[test]
+fn test_conversions() {
+    let expected_handle = TpmHandle::Permanent(PermanentTpmHandle::Owner);
+    let expected_policy_hash = HashAgile::new(
+        HashingAlgorithm::Sha256,
+        Digest::try_from(vec![0x55; 32]).expect("Failed to create digest"),
+    );
+
+    let tagged_policy = TaggedPolicy::new(expected_handle, Some(expected_policy_hash.clone()))
+        .expect("Failed to create TaggedPolicy");
+    let tpms_tagged_policy: TPMS_TAGGED_POLICY = tagged_policy.into();
+    assert_eq!(
+        tpms_tagged_policy.handle,
+        u32::from(expected_handle),
+        "TPMS_TAGGED_POLICY converted from TaggedPolicy did not contain the expected handle"
+    );
+
+    let actual_tagged_policy: TaggedPolicy = tpms_tagged_policy
+        .try_into()
+        .expect("Failed to convert TPMS_TAGGED_POLICY into TaggedPolicy");
+    assert_eq!(actual_tagged_policy.handle(), expected_handle);
+    assert_eq!(
+        actual_tagged_policy.policy_hash(),
+        Some(&expected_policy_hash),
+        "TaggedPolicy converted from TPMS_TAGGED_POLICY did not contain the expected policy hash"
+    );
+}
+
+#[test]
+fn test_conversions_without_policy() {
+    let expected_handle = TpmHandle::Permanent(PermanentTpmHandle::Lockout);
+    let tagged_policy =
+        TaggedPolicy::new(expected_handle, None).expect("Failed to create TaggedPolicy");
+    let tpms_tagged_policy: TPMS_TAGGED_POLICY = tagged_policy.clone().into();
+    assert_eq!(tpms_tagged_policy.policyHash.hashAlg, TPM2_ALG_NULL);
+
+    let actual_tagged_policy = TaggedPolicy::try_from(tpms_tagged_policy)
+        .expect("Failed to convert TPMS_TAGGED_POLICY into TaggedPolicy");
+    assert_eq!(actual_tagged_policy, tagged_policy);
+    assert!(actual_tagged_policy.policy_hash().is_none());
+}
+
+#[test]
+fn test_invalid_policy_hash() {
+    let _ = TaggedPolicy::new(
+        TpmHandle::Permanent(PermanentTpmHandle::Owner),
+        Some(HashAgile::new(
+            HashingAlgorithm::Sha3_256,
+            Digest::default(),
+        )),
+    )
+    .expect_err("Creating a TaggedPolicy with an unsupported hashing algorithm did not fail");
+}
@@ -0,0 +1,80 @@
+// Copyright 2023 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests that need a serde format.
+//!
+//! These tests are kept out of the integration tests binary because
+//! `serde_json` implements `PartialEq<Value>` for the primitive types,
+//! which makes many `into()` comparisons in there ambiguous.
+
+use std::{env, str::FromStr};
+use tss_esapi::{
+    attributes::NvIndexAttributesBuilder,
+    handles::{NvIndexHandle, NvIndexTpmHandle},
+    interface_types::{
+        algorithm::HashingAlgorithm, resource_handles::Provision, session_handles::AuthSession,
+    },
+    structures::NvPublicBuilder,
+    tcti_ldr::TctiNameConf,
+    utils::SerializedEsysTr,
+    Context,
+};
+
+fn create_ctx_without_session() -> Context {
+    let tcti = match env::var("TEST_TCTI") {
+        Err(_) => TctiNameConf::Mssim(Default::default()),
+        Ok(tctistr) => TctiNameConf::from_str(&tctistr).expect("Error parsing TEST_TCTI"),
+    };
+    Context::new(tcti).unwrap()
+}
+
+#[test]
+fn test_serialized_esys_tr_serde_round_trip() {
+    let nv_index_tpm_handle = NvIndexTpmHandle::new(0x01500306).unwrap();
+
+    let nv_index_attributes = NvIndexAttributesBuilder::new()
+        .with_owner_write(true)
+        .with_owner_read(true)
+        .build()
+        .expect("Failed to create owner nv index attributes");
+    let nv_public = NvPublicBuilder::new()
+        .with_nv_index(nv_index_tpm_handle)
+        .with_index_name_algorithm(HashingAlgorithm::Sha256)
+        .with_index_attributes(nv_index_attributes)
+        .with_data_area_size(32)
+        .build()
+        .unwrap();
+
+    let mut context = create_ctx_without_session();
+    context.set_sessions((Some(AuthSession::Password), None, None));
+    let nv_index_handle = context
+        .nv_define_space(Provision::Owner, None, nv_public)
+        .expect("Failed to call nv_define_space");
+    let expected_name = context.tr_get_name(nv_index_handle.into()).unwrap();
+    let serialized = context.tr_serialize(nv_index_handle.into());
+    context
+        .tr_close(&mut nv_index_handle.into())
+        .expect("Failed to call tr_close");
+    let serialized = serialized.expect("Failed to call tr_serialize");
+
+    let json = serde_json::to_string(&serialized).expect("Failed to serialize to JSON");
+    let deserialized: SerializedEsysTr =
+        serde_json::from_str(&json).expect("Failed to deserialize from JSON");
+    assert_eq!(serialized, deserialized);
+
+    // Restore the handle in a new context.
+    let mut context = create_ctx_without_session();
+    let object_handle = context
+        .tr_deserialize(&deserialized)
+        .expect("Failed to call tr_deserialize");
+    let actual_name = context.tr_get_name(object_handle);
+
+    context.set_sessions((Some(AuthSession::Password), None, None));
+    context
+        .nv_undefine_space(Provision::Owner, NvIndexHandle::from(object_handle))
+        .expect("Failed to call nv_undefine_space");
+    assert_eq!(
+        expected_name,
+        actual_name.expect("Failed to call tr_get_name")
+    );
+}